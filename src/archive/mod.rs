@@ -0,0 +1,668 @@
+//! Local SQLite archive of subreddit posts and comments
+//!
+//! `archive sync` mirrors a subreddit into a SQLite file. Each post and comment
+//! keeps its typed columns and the JSON object Reddit sent for it (comments
+//! without their nested `replies`, which get rows of their own). The latest
+//! state is upserted on every sync, and changes are kept alongside it:
+//!
+//! - `score_history` gets a row whenever a score or comment count changes
+//! - `revisions` gets a row when text is edited or an item is removed or deleted
+//! - `sync_state` remembers the newest post per subreddit, so the next sync
+//!   stops paging once it reaches posts it has already stored
+//!
+//! `posts.comments_synced` is the comment count of the last thread fetch that
+//! was stored completely, so threads that failed to load are fetched again.
+
+use crate::client::RedditClientError;
+use crate::models::comment::CommentData;
+use crate::models::RedditPostData;
+use crate::rules::RuleItem;
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::Value;
+use std::path::Path;
+
+/// Schema version stored in `PRAGMA user_version`
+const SCHEMA_VERSION: i32 = 2;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS posts (
+    fullname TEXT PRIMARY KEY,
+    id TEXT NOT NULL,
+    subreddit TEXT NOT NULL,
+    author TEXT NOT NULL,
+    title TEXT NOT NULL,
+    selftext TEXT NOT NULL,
+    selftext_html TEXT,
+    url TEXT NOT NULL,
+    domain TEXT,
+    permalink TEXT NOT NULL,
+    link_flair_text TEXT,
+    is_self INTEGER NOT NULL,
+    is_video INTEGER NOT NULL,
+    over_18 INTEGER NOT NULL,
+    spoiler INTEGER NOT NULL,
+    stickied INTEGER NOT NULL,
+    locked INTEGER NOT NULL,
+    created_utc REAL NOT NULL,
+    edited_utc REAL,
+    score INTEGER NOT NULL,
+    upvote_ratio REAL NOT NULL,
+    num_comments INTEGER NOT NULL,
+    comments_synced INTEGER,
+    removed_by_category TEXT,
+    removed_at TEXT,
+    first_seen TEXT NOT NULL,
+    last_seen TEXT NOT NULL,
+    raw_json TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS posts_subreddit_created ON posts (subreddit, created_utc);
+
+CREATE TABLE IF NOT EXISTS comments (
+    fullname TEXT PRIMARY KEY,
+    id TEXT NOT NULL,
+    post_fullname TEXT NOT NULL,
+    parent_fullname TEXT NOT NULL,
+    subreddit TEXT NOT NULL,
+    author TEXT NOT NULL,
+    body TEXT NOT NULL,
+    body_html TEXT,
+    permalink TEXT NOT NULL,
+    distinguished TEXT,
+    stickied INTEGER NOT NULL,
+    created_utc REAL NOT NULL,
+    edited_utc REAL,
+    score INTEGER NOT NULL,
+    removed_at TEXT,
+    first_seen TEXT NOT NULL,
+    last_seen TEXT NOT NULL,
+    raw_json TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS comments_post ON comments (post_fullname);
+
+CREATE TABLE IF NOT EXISTS score_history (
+    fullname TEXT NOT NULL,
+    observed_at TEXT NOT NULL,
+    score INTEGER NOT NULL,
+    num_comments INTEGER,
+    PRIMARY KEY (fullname, observed_at)
+);
+
+CREATE TABLE IF NOT EXISTS revisions (
+    fullname TEXT NOT NULL,
+    observed_at TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    previous_text TEXT,
+    new_text TEXT,
+    PRIMARY KEY (fullname, observed_at, kind)
+);
+
+CREATE TABLE IF NOT EXISTS sync_state (
+    subreddit TEXT PRIMARY KEY,
+    newest_fullname TEXT,
+    newest_created_utc REAL,
+    last_sync TEXT NOT NULL
+);
+";
+
+/// What an upsert changed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Upsert {
+    /// The item wasn't in the archive before
+    pub inserted: bool,
+    /// The score or comment count changed
+    pub score_changed: bool,
+    /// The text changed since the last sync
+    pub edited: bool,
+    /// The item was removed or deleted since the last sync
+    pub removed: bool,
+}
+
+/// Where the last sync of a subreddit stopped
+#[derive(Debug, Clone)]
+pub struct SyncState {
+    pub newest_fullname: Option<String>,
+    pub newest_created_utc: Option<f64>,
+    pub last_sync: String,
+}
+
+/// A SQLite archive file
+pub struct Archive {
+    conn: Connection,
+}
+
+impl Archive {
+    /// Open an archive, creating the file and tables if needed
+    pub fn open(path: &Path) -> Result<Self, RedditClientError> {
+        let conn = Connection::open(path).map_err(db_error)?;
+
+        let version: i32 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(db_error)?;
+        if version > SCHEMA_VERSION {
+            return Err(RedditClientError::ApiError(format!(
+                "{} was written by a newer redrust (schema version {})",
+                path.display(),
+                version
+            )));
+        }
+
+        // Version 1 had no record of which comment threads were stored
+        if version == 1 {
+            conn.execute_batch("ALTER TABLE posts ADD COLUMN comments_synced INTEGER")
+                .map_err(db_error)?;
+        }
+        conn.execute_batch(SCHEMA).map_err(db_error)?;
+        conn.pragma_update(None, "user_version", SCHEMA_VERSION)
+            .map_err(db_error)?;
+
+        Ok(Self { conn })
+    }
+
+    /// Where the last sync of a subreddit stopped, if it has been synced before
+    pub fn sync_state(&self, subreddit: &str) -> Result<Option<SyncState>, RedditClientError> {
+        self.conn
+            .query_row(
+                "SELECT newest_fullname, newest_created_utc, last_sync FROM sync_state WHERE subreddit = ?1",
+                params![subreddit.to_lowercase()],
+                |row| {
+                    Ok(SyncState {
+                        newest_fullname: row.get(0)?,
+                        newest_created_utc: row.get(1)?,
+                        last_sync: row.get(2)?,
+                    })
+                },
+            )
+            .optional()
+            .map_err(db_error)
+    }
+
+    /// Record the newest post stored for a subreddit
+    pub fn save_sync_state(
+        &self,
+        subreddit: &str,
+        newest: Option<&RedditPostData>,
+    ) -> Result<(), RedditClientError> {
+        self.conn
+            .execute(
+                "INSERT INTO sync_state (subreddit, newest_fullname, newest_created_utc, last_sync)
+                 VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT (subreddit) DO UPDATE SET
+                     newest_fullname = COALESCE(excluded.newest_fullname, newest_fullname),
+                     newest_created_utc = COALESCE(excluded.newest_created_utc, newest_created_utc),
+                     last_sync = excluded.last_sync",
+                params![
+                    subreddit.to_lowercase(),
+                    newest.map(|post| format!("t3_{}", post.id)),
+                    newest.map(|post| post.created_utc),
+                    Utc::now().to_rfc3339(),
+                ],
+            )
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    /// The post's comment count when its thread was last stored, if it has been
+    pub fn synced_comment_count(&self, fullname: &str) -> Result<Option<i32>, RedditClientError> {
+        self.conn
+            .query_row(
+                "SELECT comments_synced FROM posts WHERE fullname = ?1",
+                params![fullname],
+                |row| row.get(0),
+            )
+            .optional()
+            .map(Option::flatten)
+            .map_err(db_error)
+    }
+
+    /// Record that a post's thread was stored when it had `num_comments` comments
+    pub fn mark_comments_synced(
+        &self,
+        fullname: &str,
+        num_comments: i32,
+    ) -> Result<(), RedditClientError> {
+        self.conn
+            .execute(
+                "UPDATE posts SET comments_synced = ?2 WHERE fullname = ?1",
+                params![fullname, num_comments],
+            )
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    /// Insert or update a post, recording score changes, edits and removal.
+    ///
+    /// `raw` is the post's `data` object as Reddit sent it.
+    pub fn upsert_post(
+        &mut self,
+        post: &RedditPostData,
+        raw: &Value,
+    ) -> Result<Upsert, RedditClientError> {
+        let fullname = format!("t3_{}", post.id);
+        let now = Utc::now().to_rfc3339();
+        let raw_json = raw.to_string();
+        let removed = post.removed_by_category.is_some()
+            || post.author == "[deleted]"
+            || is_removed_text(&post.selftext);
+
+        let tx = self.conn.transaction().map_err(db_error)?;
+        let previous: Option<(i32, i32, String, Option<String>)> = tx
+            .query_row(
+                "SELECT score, num_comments, selftext, removed_at FROM posts WHERE fullname = ?1",
+                params![fullname],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .optional()
+            .map_err(db_error)?;
+
+        let mut upsert = Upsert {
+            inserted: previous.is_none(),
+            ..Upsert::default()
+        };
+        let mut removed_at = None;
+        match &previous {
+            None => {
+                upsert.score_changed = true;
+                if removed {
+                    removed_at = Some(now.clone());
+                }
+            }
+            Some((score, num_comments, selftext, previous_removed_at)) => {
+                upsert.score_changed = *score != post.score || *num_comments != post.num_comments;
+                removed_at = previous_removed_at.clone();
+                if removed && previous_removed_at.is_none() {
+                    upsert.removed = true;
+                    removed_at = Some(now.clone());
+                    record_revision(&tx, &fullname, &now, "removal", selftext, &post.selftext)?;
+                } else if *selftext != post.selftext && !is_removed_text(&post.selftext) {
+                    upsert.edited = true;
+                    record_revision(&tx, &fullname, &now, "edit", selftext, &post.selftext)?;
+                }
+            }
+        }
+
+        tx.execute(
+            "INSERT INTO posts (
+                fullname, id, subreddit, author, title, selftext, selftext_html, url, domain,
+                permalink, link_flair_text, is_self, is_video, over_18, spoiler, stickied, locked,
+                created_utc, edited_utc, score, upvote_ratio, num_comments, removed_by_category,
+                removed_at, first_seen, last_seen, raw_json
+            ) VALUES (
+                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17,
+                ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?25, ?26
+            )
+            ON CONFLICT (fullname) DO UPDATE SET
+                author = excluded.author,
+                title = excluded.title,
+                selftext = excluded.selftext,
+                selftext_html = excluded.selftext_html,
+                link_flair_text = excluded.link_flair_text,
+                over_18 = excluded.over_18,
+                spoiler = excluded.spoiler,
+                stickied = excluded.stickied,
+                locked = excluded.locked,
+                edited_utc = excluded.edited_utc,
+                score = excluded.score,
+                upvote_ratio = excluded.upvote_ratio,
+                num_comments = excluded.num_comments,
+                removed_by_category = excluded.removed_by_category,
+                removed_at = excluded.removed_at,
+                last_seen = excluded.last_seen,
+                raw_json = excluded.raw_json",
+            params![
+                fullname,
+                post.id,
+                post.subreddit,
+                post.author,
+                post.title,
+                post.selftext,
+                post.selftext_html,
+                post.url,
+                RuleItem::Submission(post).domain(),
+                post.permalink,
+                post.link_flair_text,
+                post.is_self,
+                post.is_video,
+                post.over_18,
+                post.spoiler,
+                post.stickied,
+                post.locked,
+                post.created_utc,
+                post.edited.as_f64(),
+                post.score,
+                post.upvote_ratio,
+                post.num_comments,
+                post.removed_by_category,
+                removed_at,
+                now,
+                raw_json,
+            ],
+        )
+        .map_err(db_error)?;
+
+        if upsert.score_changed {
+            tx.execute(
+                "INSERT OR REPLACE INTO score_history (fullname, observed_at, score, num_comments)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![fullname, now, post.score, post.num_comments],
+            )
+            .map_err(db_error)?;
+        }
+
+        tx.commit().map_err(db_error)?;
+        Ok(upsert)
+    }
+
+    /// Insert or update a comment, recording score changes, edits and removal.
+    ///
+    /// `raw` is the comment's `data` object as Reddit sent it.
+    pub fn upsert_comment(
+        &mut self,
+        comment: &CommentData,
+        raw: &Value,
+    ) -> Result<Upsert, RedditClientError> {
+        let now = Utc::now().to_rfc3339();
+        let raw_json = raw.to_string();
+        let removed = comment.author == "[deleted]" || is_removed_text(&comment.body);
+
+        let tx = self.conn.transaction().map_err(db_error)?;
+        let previous: Option<(i32, String, Option<String>)> = tx
+            .query_row(
+                "SELECT score, body, removed_at FROM comments WHERE fullname = ?1",
+                params![comment.name],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()
+            .map_err(db_error)?;
+
+        let mut upsert = Upsert {
+            inserted: previous.is_none(),
+            ..Upsert::default()
+        };
+        let mut removed_at = None;
+        match &previous {
+            None => {
+                upsert.score_changed = true;
+                if removed {
+                    removed_at = Some(now.clone());
+                }
+            }
+            Some((score, body, previous_removed_at)) => {
+                upsert.score_changed = *score != comment.score;
+                removed_at = previous_removed_at.clone();
+                if removed && previous_removed_at.is_none() {
+                    upsert.removed = true;
+                    removed_at = Some(now.clone());
+                    record_revision(&tx, &comment.name, &now, "removal", body, &comment.body)?;
+                } else if *body != comment.body && !is_removed_text(&comment.body) {
+                    upsert.edited = true;
+                    record_revision(&tx, &comment.name, &now, "edit", body, &comment.body)?;
+                }
+            }
+        }
+
+        tx.execute(
+            "INSERT INTO comments (
+                fullname, id, post_fullname, parent_fullname, subreddit, author, body, body_html,
+                permalink, distinguished, stickied, created_utc, edited_utc, score, removed_at,
+                first_seen, last_seen, raw_json
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?16, ?17)
+            ON CONFLICT (fullname) DO UPDATE SET
+                author = excluded.author,
+                body = excluded.body,
+                body_html = excluded.body_html,
+                distinguished = excluded.distinguished,
+                stickied = excluded.stickied,
+                edited_utc = excluded.edited_utc,
+                score = excluded.score,
+                removed_at = excluded.removed_at,
+                last_seen = excluded.last_seen,
+                raw_json = excluded.raw_json",
+            params![
+                comment.name,
+                comment.id,
+                comment.link_id,
+                comment.parent_id,
+                comment.subreddit,
+                comment.author,
+                comment.body,
+                comment.body_html,
+                comment.permalink,
+                comment.distinguished,
+                comment.stickied,
+                comment.created_utc,
+                comment.edited.as_f64(),
+                comment.score,
+                removed_at,
+                now,
+                raw_json,
+            ],
+        )
+        .map_err(db_error)?;
+
+        if upsert.score_changed {
+            tx.execute(
+                "INSERT OR REPLACE INTO score_history (fullname, observed_at, score, num_comments)
+                 VALUES (?1, ?2, ?3, NULL)",
+                params![comment.name, now, comment.score],
+            )
+            .map_err(db_error)?;
+        }
+
+        tx.commit().map_err(db_error)?;
+        Ok(upsert)
+    }
+}
+
+/// Whether text is Reddit's placeholder for removed or deleted content
+fn is_removed_text(text: &str) -> bool {
+    matches!(text.trim(), "[removed]" | "[deleted]")
+}
+
+fn record_revision(
+    conn: &Connection,
+    fullname: &str,
+    observed_at: &str,
+    kind: &str,
+    previous_text: &str,
+    new_text: &str,
+) -> Result<(), RedditClientError> {
+    conn.execute(
+        "INSERT OR REPLACE INTO revisions (fullname, observed_at, kind, previous_text, new_text)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![fullname, observed_at, kind, previous_text, new_text],
+    )
+    .map_err(db_error)?;
+    Ok(())
+}
+
+fn db_error(err: rusqlite::Error) -> RedditClientError {
+    RedditClientError::ApiError(format!("Archive database error: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn archive() -> Archive {
+        Archive::open(Path::new(":memory:")).unwrap()
+    }
+
+    /// A post as Reddit sends it, from the recorded listing fixture
+    fn post(selftext: &str, score: i32, num_comments: i32) -> (RedditPostData, Value) {
+        let cassette: Value =
+            serde_json::from_str(include_str!("../../tests/cassettes/new-posts.json")).unwrap();
+        let body = cassette["interactions"][0]["response"]["body"]
+            .as_str()
+            .unwrap();
+        let listing: Value = serde_json::from_str(body).unwrap();
+
+        let mut raw = listing["data"]["children"][0]["data"].clone();
+        raw["selftext"] = json!(selftext);
+        raw["score"] = json!(score);
+        raw["num_comments"] = json!(num_comments);
+        (serde_json::from_value(raw.clone()).unwrap(), raw)
+    }
+
+    fn comment(author: &str, body: &str, score: i32) -> (CommentData, Value) {
+        let raw = json!({
+            "id": "c1",
+            "name": "t1_c1",
+            "author": author,
+            "body": body,
+            "link_id": "t3_p1",
+            "parent_id": "t3_p1",
+            "subreddit": "rust",
+            "permalink": "/r/rust/comments/p1/_/c1/",
+            "score": score,
+            "created_utc": 1_700_000_000.0,
+            "edited": false,
+        });
+        (serde_json::from_value(raw.clone()).unwrap(), raw)
+    }
+
+    /// (kind, previous_text, new_text) for each revision of an item, oldest first
+    fn revisions(archive: &Archive, fullname: &str) -> Vec<(String, String, String)> {
+        let mut statement = archive
+            .conn
+            .prepare(
+                "SELECT kind, previous_text, new_text FROM revisions
+                 WHERE fullname = ?1 ORDER BY observed_at",
+            )
+            .unwrap();
+        statement
+            .query_map(params![fullname], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    /// (score, num_comments) for each score history row of an item, oldest first
+    fn score_history(archive: &Archive, fullname: &str) -> Vec<(i32, Option<i32>)> {
+        let mut statement = archive
+            .conn
+            .prepare(
+                "SELECT score, num_comments FROM score_history
+                 WHERE fullname = ?1 ORDER BY observed_at",
+            )
+            .unwrap();
+        statement
+            .query_map(params![fullname], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn post_insert_edit_and_removal() {
+        let mut archive = archive();
+        let (original, raw) = post("First draft", 1, 0);
+        let fullname = format!("t3_{}", original.id);
+
+        let upsert = archive.upsert_post(&original, &raw).unwrap();
+        assert!(upsert.inserted && upsert.score_changed);
+        assert!(!upsert.edited && !upsert.removed);
+        let stored: String = archive
+            .conn
+            .query_row(
+                "SELECT raw_json FROM posts WHERE fullname = ?1",
+                params![fullname],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(serde_json::from_str::<Value>(&stored).unwrap(), raw);
+
+        // Same post again: nothing to record
+        let upsert = archive.upsert_post(&original, &raw).unwrap();
+        assert_eq!(upsert, Upsert::default());
+
+        let (edited, raw) = post("Second draft", 5, 2);
+        let upsert = archive.upsert_post(&edited, &raw).unwrap();
+        assert!(!upsert.inserted && upsert.score_changed && upsert.edited);
+
+        let (removed, raw) = post("[removed]", 5, 2);
+        let upsert = archive.upsert_post(&removed, &raw).unwrap();
+        assert!(upsert.removed && !upsert.edited && !upsert.score_changed);
+
+        // Removal is only recorded once
+        let upsert = archive.upsert_post(&removed, &raw).unwrap();
+        assert!(!upsert.removed);
+
+        assert_eq!(
+            revisions(&archive, &fullname),
+            [
+                ("edit".into(), "First draft".into(), "Second draft".into()),
+                ("removal".into(), "Second draft".into(), "[removed]".into()),
+            ]
+        );
+        assert_eq!(
+            score_history(&archive, &fullname),
+            [(1, Some(0)), (5, Some(2))]
+        );
+    }
+
+    #[test]
+    fn comment_insert_edit_and_deletion() {
+        let mut archive = archive();
+        let (original, raw) = comment("alice", "Hello", 1);
+        assert!(archive.upsert_comment(&original, &raw).unwrap().inserted);
+
+        let (edited, raw) = comment("alice", "Hello, edited", 3);
+        let upsert = archive.upsert_comment(&edited, &raw).unwrap();
+        assert!(upsert.edited && upsert.score_changed && !upsert.removed);
+
+        let (deleted, raw) = comment("[deleted]", "[deleted]", 3);
+        let upsert = archive.upsert_comment(&deleted, &raw).unwrap();
+        assert!(upsert.removed && !upsert.edited && !upsert.score_changed);
+
+        assert_eq!(
+            revisions(&archive, "t1_c1"),
+            [
+                ("edit".into(), "Hello".into(), "Hello, edited".into()),
+                ("removal".into(), "Hello, edited".into(), "[deleted]".into()),
+            ]
+        );
+        assert_eq!(score_history(&archive, "t1_c1"), [(1, None), (3, None)]);
+    }
+
+    #[test]
+    fn thread_is_fetched_again_until_it_is_stored() {
+        let mut archive = archive();
+        let (post, raw) = post("Text", 1, 2);
+        let fullname = format!("t3_{}", post.id);
+
+        archive.upsert_post(&post, &raw).unwrap();
+        assert_eq!(archive.synced_comment_count(&fullname).unwrap(), None);
+
+        // The comment fetch failed, so the thread was never marked; the next
+        // sync upserts the post again and still sees it as unsynced
+        archive.upsert_post(&post, &raw).unwrap();
+        assert_eq!(archive.synced_comment_count(&fullname).unwrap(), None);
+
+        archive.mark_comments_synced(&fullname, 2).unwrap();
+        archive.upsert_post(&post, &raw).unwrap();
+        assert_eq!(archive.synced_comment_count(&fullname).unwrap(), Some(2));
+
+        // New comments don't move the synced count until they're stored
+        let (busier, raw) = self::post("Text", 1, 4);
+        archive.upsert_post(&busier, &raw).unwrap();
+        assert_eq!(archive.synced_comment_count(&fullname).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn sync_state_keeps_the_newest_post_when_none_is_given() {
+        let archive = archive();
+        assert!(archive.sync_state("rust").unwrap().is_none());
+
+        let (post, _) = post("Text", 1, 0);
+        archive.save_sync_state("Rust", Some(&post)).unwrap();
+        archive.save_sync_state("rust", None).unwrap();
+
+        let state = archive.sync_state("RUST").unwrap().unwrap();
+        assert_eq!(state.newest_fullname, Some(format!("t3_{}", post.id)));
+        assert_eq!(state.newest_created_utc, Some(post.created_utc));
+    }
+}
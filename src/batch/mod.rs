@@ -0,0 +1,287 @@
+//! Batch plans of posts and comments, and the record of running them
+//!
+//! A plan is a JSON, YAML or CSV list of rows. Each row is either a `submit`
+//! (a text post to a subreddit) or a `comment` (a reply to a post, comment or
+//! message). In CSV the columns are the field names below:
+//!
+//! ```csv
+//! id,action,subreddit,title,text,text_file,thing_id
+//! rust,submit,rust,Release 1.0,,announcement.md,
+//! thread,comment,,,Thanks for the feedback!,,t3_abc123
+//! ```
+//!
+//! Results are written after every row, so a run can be resumed and only the
+//! rows that haven't succeeded yet are retried.
+
+use crate::client::RedditClientError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A plan row as written in the plan file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchRow {
+    /// Stable name for the row, used to match results when resuming; defaults to the row number
+    pub id: Option<String>,
+    pub action: BatchActionKind,
+    pub subreddit: Option<String>,
+    pub title: Option<String>,
+    pub text: Option<String>,
+    /// File holding the text, relative to the plan file
+    pub text_file: Option<PathBuf>,
+    /// The fullname of the post, comment or message to reply to
+    pub thing_id: Option<String>,
+}
+
+/// The kinds of action a plan row can take
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchActionKind {
+    Submit,
+    Comment,
+}
+
+impl fmt::Display for BatchActionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BatchActionKind::Submit => write!(f, "submit"),
+            BatchActionKind::Comment => write!(f, "comment"),
+        }
+    }
+}
+
+/// A validated plan row, ready to run
+#[derive(Debug, Clone)]
+pub struct BatchItem {
+    /// The row's `id`, or its 1-based row number
+    pub key: String,
+    pub action: BatchAction,
+}
+
+/// What a plan row does
+#[derive(Debug, Clone)]
+pub enum BatchAction {
+    /// Submit a text post
+    Submit {
+        subreddit: String,
+        title: String,
+        text: String,
+    },
+    /// Reply to a post, comment or message
+    Comment { thing_id: String, text: String },
+}
+
+impl BatchAction {
+    pub fn kind(&self) -> BatchActionKind {
+        match self {
+            BatchAction::Submit { .. } => BatchActionKind::Submit,
+            BatchAction::Comment { .. } => BatchActionKind::Comment,
+        }
+    }
+
+    /// Where the action posts to, e.g. "r/rust" or "t3_abc123"
+    pub fn target(&self) -> String {
+        match self {
+            BatchAction::Submit { subreddit, .. } => {
+                format!("r/{}", subreddit.trim_start_matches("r/"))
+            }
+            BatchAction::Comment { thing_id, .. } => thing_id.clone(),
+        }
+    }
+}
+
+/// The outcome of one row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RowStatus {
+    /// Posted successfully
+    Ok,
+    /// Posting failed; the row is retried when the run is resumed
+    Failed,
+    /// Dry run only; nothing was posted
+    DryRun,
+}
+
+impl fmt::Display for RowStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RowStatus::Ok => write!(f, "ok"),
+            RowStatus::Failed => write!(f, "failed"),
+            RowStatus::DryRun => write!(f, "dry_run"),
+        }
+    }
+}
+
+/// One line of the results file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchRecord {
+    /// The row's `id`, or its 1-based row number
+    pub key: String,
+    pub action: BatchActionKind,
+    /// Where the row posted to
+    pub target: String,
+    pub status: RowStatus,
+    /// URL of the post, or fullname of the comment
+    pub url: Option<String>,
+    pub error: Option<String>,
+    pub attempts: u32,
+    pub finished_at: DateTime<Utc>,
+}
+
+/// Read and validate a plan file (`.json`, `.yaml`, `.yml` or `.csv`).
+///
+/// JSON and YAML plans are a list of rows, or a map with the rows under `actions`.
+pub fn load_plan(path: &Path) -> Result<Vec<BatchItem>, RedditClientError> {
+    let contents = fs::read_to_string(path).map_err(|e| {
+        RedditClientError::ApiError(format!("Failed to read {}: {}", path.display(), e))
+    })?;
+    let invalid =
+        |e: String| RedditClientError::ApiError(format!("Invalid plan {}: {}", path.display(), e));
+
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    let json = match extension.as_str() {
+        "json" => serde_json::from_str(&contents).map_err(|e| invalid(e.to_string()))?,
+        "yaml" | "yml" => serde_yaml::from_str(&contents).map_err(|e| invalid(e.to_string()))?,
+        "csv" => {
+            let mut rows = Vec::new();
+            for row in csv::Reader::from_reader(contents.as_bytes()).deserialize() {
+                let row: BatchRow = row.map_err(|e| invalid(e.to_string()))?;
+                rows.push(row);
+            }
+            return rows
+                .into_iter()
+                .enumerate()
+                .map(|(index, row)| validate_row(path, index, row).map_err(invalid))
+                .collect();
+        }
+        _ => {
+            return Err(invalid(
+                "expected a .json, .yaml, .yml or .csv file".to_string(),
+            ))
+        }
+    };
+
+    // Go through JSON for YAML too, so both accept the same shapes
+    let rows: Vec<BatchRow> = match json {
+        serde_json::Value::Object(mut plan) if plan.contains_key("actions") => {
+            serde_json::from_value(plan["actions"].take())
+        }
+        json => serde_json::from_value(json),
+    }
+    .map_err(|e| invalid(e.to_string()))?;
+
+    rows.into_iter()
+        .enumerate()
+        .map(|(index, row)| validate_row(path, index, row).map_err(invalid))
+        .collect()
+}
+
+/// Check that a row has the fields its action needs, and read its text file
+fn validate_row(plan_path: &Path, index: usize, row: BatchRow) -> Result<BatchItem, String> {
+    let key = non_empty(row.id).unwrap_or_else(|| (index + 1).to_string());
+    let required = |field: Option<String>, name: &str| {
+        non_empty(field)
+            .ok_or_else(|| format!("row {} ({}) is missing '{}'", key, row.action, name))
+    };
+
+    let text = match non_empty(row.text) {
+        Some(text) => text,
+        None => {
+            let file = row
+                .text_file
+                .filter(|file| !file.as_os_str().is_empty())
+                .ok_or_else(|| format!("row {} needs 'text' or 'text_file'", key))?;
+            let file = match plan_path.parent() {
+                Some(dir) if file.is_relative() => dir.join(file),
+                _ => file,
+            };
+            fs::read_to_string(&file)
+                .map_err(|e| format!("row {}: failed to read {}: {}", key, file.display(), e))?
+        }
+    };
+
+    let action = match row.action {
+        BatchActionKind::Submit => BatchAction::Submit {
+            subreddit: required(row.subreddit, "subreddit")?,
+            title: required(row.title, "title")?,
+            text,
+        },
+        BatchActionKind::Comment => BatchAction::Comment {
+            thing_id: required(row.thing_id, "thing_id")?,
+            text,
+        },
+    };
+
+    Ok(BatchItem { key, action })
+}
+
+/// Treat empty strings (e.g. blank CSV cells) as missing
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.trim().is_empty())
+}
+
+/// Read a results file written by an earlier run, or nothing if it doesn't exist
+pub fn load_results(path: &Path) -> Result<Vec<BatchRecord>, RedditClientError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let invalid = |e: String| {
+        RedditClientError::ApiError(format!("Invalid results file {}: {}", path.display(), e))
+    };
+    if is_csv(path) {
+        csv::Reader::from_path(path)
+            .map_err(|e| invalid(e.to_string()))?
+            .deserialize()
+            .map(|record| record.map_err(|e| invalid(e.to_string())))
+            .collect()
+    } else {
+        let contents = fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+        serde_json::from_str(&contents).map_err(|e| invalid(e.to_string()))
+    }
+}
+
+/// Write the results file as CSV or JSON depending on its extension
+pub fn save_results(path: &Path, records: &[BatchRecord]) -> Result<(), RedditClientError> {
+    let failed = |e: String| {
+        RedditClientError::ApiError(format!("Failed to write {}: {}", path.display(), e))
+    };
+
+    let temp_path = path.with_extension("tmp");
+    if is_csv(path) {
+        let mut writer = csv::Writer::from_path(&temp_path).map_err(|e| failed(e.to_string()))?;
+        for record in records {
+            writer
+                .serialize(record)
+                .map_err(|e| failed(e.to_string()))?;
+        }
+        writer.flush().map_err(|e| failed(e.to_string()))?;
+    } else {
+        let json = serde_json::to_string_pretty(records)?;
+        fs::write(&temp_path, json).map_err(|e| failed(e.to_string()))?;
+    }
+
+    fs::rename(&temp_path, path).map_err(|e| failed(e.to_string()))
+}
+
+/// The default results file for a plan, e.g. `plan.results.json` next to `plan.yaml`
+pub fn default_results_path(plan_path: &Path) -> PathBuf {
+    let stem = plan_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("plan");
+    plan_path.with_file_name(format!("{}.results.json", stem))
+}
+
+fn is_csv(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"))
+}
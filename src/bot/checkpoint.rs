@@ -0,0 +1,117 @@
+use crate::client::RedditClientError;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+
+/// How many recent fullnames each source remembers
+const RECENT_CAPACITY: usize = 1000;
+
+/// How far behind the newest item an unseen item may be and still count as new.
+///
+/// Listings are eventually consistent, so an item can show up a little after
+/// newer ones have already been processed.
+const LATE_ARRIVAL_SECS: f64 = 600.0;
+
+/// What a bot has already processed, persisted between runs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Checkpoint {
+    #[serde(default)]
+    pub submissions: SourceCheckpoint,
+    #[serde(default)]
+    pub comments: SourceCheckpoint,
+    #[serde(default)]
+    pub inbox: SourceCheckpoint,
+}
+
+/// The last-seen position in one stream
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SourceCheckpoint {
+    /// Creation time of the newest item processed
+    pub last_created_utc: f64,
+    /// Fullnames of the most recently processed items
+    #[serde(default)]
+    pub recent: VecDeque<String>,
+}
+
+impl SourceCheckpoint {
+    /// Start from the given time, ignoring anything created before it
+    fn starting_at(created_utc: f64) -> Self {
+        Self {
+            last_created_utc: created_utc,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Whether an item has not been processed yet
+    pub fn is_new(&self, fullname: &str, created_utc: f64) -> bool {
+        created_utc > self.last_created_utc - LATE_ARRIVAL_SECS
+            && !self.recent.iter().any(|seen| seen == fullname)
+    }
+
+    /// Record an item as processed
+    pub fn record(&mut self, fullname: &str, created_utc: f64) {
+        self.last_created_utc = self.last_created_utc.max(created_utc);
+        self.recent.push_back(fullname.to_string());
+        while self.recent.len() > RECENT_CAPACITY {
+            self.recent.pop_front();
+        }
+    }
+}
+
+impl Checkpoint {
+    /// A checkpoint for a bot's first run, which only handles items created from now on
+    pub fn starting_now() -> Self {
+        let now = chrono::Utc::now().timestamp() as f64;
+        Self {
+            submissions: SourceCheckpoint::starting_at(now),
+            comments: SourceCheckpoint::starting_at(now),
+            inbox: SourceCheckpoint::starting_at(now),
+        }
+    }
+
+    /// Load a checkpoint from disk, or start from now if the file doesn't exist yet
+    pub fn load_or_new(path: &Path) -> Result<Self, RedditClientError> {
+        if !path.exists() {
+            debug!(
+                "No checkpoint at {}, starting from the current time",
+                path.display()
+            );
+            return Ok(Self::starting_now());
+        }
+
+        let contents = fs::read_to_string(path).map_err(|e| {
+            RedditClientError::ApiError(format!(
+                "Failed to read checkpoint {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Write the checkpoint to disk, replacing the previous file atomically
+    pub fn save(&self, path: &Path) -> Result<(), RedditClientError> {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent).ok();
+            }
+        }
+
+        let json = serde_json::to_string_pretty(self)?;
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, json)
+            .and_then(|_| fs::rename(&temp_path, path))
+            .map_err(|e| {
+                RedditClientError::ApiError(format!(
+                    "Failed to write checkpoint {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+
+        debug!("Saved checkpoint to {}", path.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use regex::Regex;
+
+/// Decides which events a handler sees.
+///
+/// An empty filter matches everything. Subreddit and author names are compared
+/// case-insensitively, with or without their `r/` and `u/` prefixes.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    subreddits: Vec<String>,
+    authors: Vec<String>,
+    excluded_authors: Vec<String>,
+    pattern: Option<Regex>,
+}
+
+impl Filter {
+    /// A filter that matches every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match events in this subreddit; may be given more than once
+    pub fn subreddit(mut self, name: &str) -> Self {
+        self.subreddits.push(normalize(name, "r/"));
+        self
+    }
+
+    /// Only match events from this author; may be given more than once
+    pub fn author(mut self, name: &str) -> Self {
+        self.authors.push(normalize(name, "u/"));
+        self
+    }
+
+    /// Never match events from this author, e.g. the bot's own account
+    pub fn exclude_author(mut self, name: &str) -> Self {
+        self.excluded_authors.push(normalize(name, "u/"));
+        self
+    }
+
+    /// Only match events whose text matches a regular expression.
+    ///
+    /// The text is the title and body for posts, the body for comments and
+    /// mentions, and the subject and body for private messages.
+    pub fn matching(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.pattern = Some(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Check an event's subreddit, author and text against the filter
+    pub fn matches(&self, subreddit: Option<&str>, author: &str, text: &str) -> bool {
+        if !self.subreddits.is_empty() {
+            let subreddit = subreddit.map(|name| normalize(name, "r/"));
+            if !subreddit.is_some_and(|name| self.subreddits.contains(&name)) {
+                return false;
+            }
+        }
+
+        let author = normalize(author, "u/");
+        if !self.authors.is_empty() && !self.authors.contains(&author) {
+            return false;
+        }
+        if self.excluded_authors.contains(&author) {
+            return false;
+        }
+
+        self.pattern
+            .as_ref()
+            .is_none_or(|pattern| pattern.is_match(text))
+    }
+}
+
+/// Lowercase a name and strip its prefix, with or without a leading slash
+fn normalize(name: &str, prefix: &str) -> String {
+    let name = name.trim().trim_start_matches('/');
+    name.strip_prefix(prefix).unwrap_or(name).to_lowercase()
+}
@@ -0,0 +1,442 @@
+//! Event-driven bots over submission, comment and inbox streams
+//!
+//! A `Bot` polls the configured subreddits and the account inbox, and calls the
+//! registered async handlers for each new item that passes their `Filter`:
+//!
+//! ```no_run
+//! use redrust::bot::{Bot, BotConfig, Filter};
+//! use redrust::RedditClient;
+//!
+//! # async fn run() -> Result<(), redrust::RedditClientError> {
+//! let client = RedditClient::with_stored_tokens("my-client-id");
+//! let mut config = BotConfig::new("greeter");
+//! config.subreddits.push("rust".to_string());
+//!
+//! let mut bot = Bot::new(client, config);
+//! bot.on_comment(
+//!     Filter::new().matching(r"(?i)\bhello bot\b").unwrap(),
+//!     |client, comment| async move {
+//!         client.create_comment(&comment.name, "Hello!").await?;
+//!         Ok(())
+//!     },
+//! );
+//! bot.run().await
+//! # }
+//! ```
+//!
+//! Handlers run one at a time in the order items were created. The bot
+//! records what it has handled in a checkpoint file after every item, so a
+//! restarted bot picks up where it stopped. On its first run it only handles
+//! items created after it started. SIGTERM and Ctrl-C let the current handler
+//! finish, save the checkpoint and return from `run`.
+
+pub mod checkpoint;
+pub mod filter;
+
+pub use checkpoint::{Checkpoint, SourceCheckpoint};
+pub use filter::Filter;
+
+use crate::client::{RedditClient, RedditClientError, StreamConfig};
+use crate::models::comment::CommentData;
+use crate::models::listing::Thing;
+use crate::models::message::MessageData;
+use crate::models::RedditPostData;
+use log::{debug, error, info};
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Requests per minute used when the client has no rate limit of its own
+const DEFAULT_REQUESTS_PER_MINUTE: u32 = 60;
+
+/// The future returned by a handler
+pub type HandlerFuture = Pin<Box<dyn Future<Output = Result<(), RedditClientError>> + Send>>;
+
+/// A type-erased handler callback
+type Callback<T> = Arc<dyn Fn(RedditClient, Arc<T>) -> HandlerFuture + Send + Sync>;
+
+/// A handler together with the filter that selects its events
+struct Handler<T> {
+    filter: Filter,
+    callback: Callback<T>,
+}
+
+/// An item delivered by one of the bot's streams
+#[derive(Debug)]
+enum Event {
+    Submission(Box<RedditPostData>),
+    Comment(Box<CommentData>),
+    Inbox(Thing),
+}
+
+/// Configuration for a bot
+#[derive(Debug, Clone)]
+pub struct BotConfig {
+    /// Subreddits watched by the submission and comment handlers
+    pub subreddits: Vec<String>,
+    /// Where the last-seen checkpoint is stored
+    pub checkpoint_path: PathBuf,
+    /// Polling behaviour for the submission and comment streams
+    pub stream: StreamConfig,
+    /// Polling behaviour for the inbox stream
+    pub inbox_stream: StreamConfig,
+}
+
+impl BotConfig {
+    /// Configuration for a bot named `name`, checkpointed to `~/.redrust/bots/{name}.json`
+    pub fn new(name: &str) -> Self {
+        let mut checkpoint_path = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        checkpoint_path.push(".redrust");
+        checkpoint_path.push("bots");
+        checkpoint_path.push(format!("{}.json", name));
+
+        Self {
+            subreddits: Vec::new(),
+            checkpoint_path,
+            stream: StreamConfig::default(),
+            inbox_stream: StreamConfig {
+                min_interval: Duration::from_secs(15),
+                limit: 25,
+                ..StreamConfig::default()
+            },
+        }
+    }
+}
+
+/// A bot dispatching new posts, comments, mentions and messages to handlers
+pub struct Bot {
+    client: RedditClient,
+    config: BotConfig,
+    submission_handlers: Vec<Handler<RedditPostData>>,
+    comment_handlers: Vec<Handler<CommentData>>,
+    mention_handlers: Vec<Handler<CommentData>>,
+    message_handlers: Vec<Handler<MessageData>>,
+}
+
+impl Bot {
+    /// Create a bot sharing one client between all streams and handlers.
+    ///
+    /// If the client has no rate limit yet, it is limited to 60 requests per minute.
+    /// Handlers always see live responses, never the response cache.
+    pub fn new(client: RedditClient, config: BotConfig) -> Self {
+        let client = client.bypassing_cache();
+        let client = if client.rate_limiter.is_none() {
+            client.with_rate_limit(DEFAULT_REQUESTS_PER_MINUTE)
+        } else {
+            client
+        };
+
+        Self {
+            client,
+            config,
+            submission_handlers: Vec::new(),
+            comment_handlers: Vec::new(),
+            mention_handlers: Vec::new(),
+            message_handlers: Vec::new(),
+        }
+    }
+
+    /// Handle new posts in the watched subreddits
+    pub fn on_submission<F, Fut>(&mut self, filter: Filter, handler: F) -> &mut Self
+    where
+        F: Fn(RedditClient, Arc<RedditPostData>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), RedditClientError>> + Send + 'static,
+    {
+        self.submission_handlers.push(handler_for(filter, handler));
+        self
+    }
+
+    /// Handle new comments in the watched subreddits
+    pub fn on_comment<F, Fut>(&mut self, filter: Filter, handler: F) -> &mut Self
+    where
+        F: Fn(RedditClient, Arc<CommentData>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), RedditClientError>> + Send + 'static,
+    {
+        self.comment_handlers.push(handler_for(filter, handler));
+        self
+    }
+
+    /// Handle comments anywhere on Reddit that mention the bot's username
+    pub fn on_mention<F, Fut>(&mut self, filter: Filter, handler: F) -> &mut Self
+    where
+        F: Fn(RedditClient, Arc<CommentData>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), RedditClientError>> + Send + 'static,
+    {
+        self.mention_handlers.push(handler_for(filter, handler));
+        self
+    }
+
+    /// Handle private messages sent to the bot's account
+    pub fn on_message<F, Fut>(&mut self, filter: Filter, handler: F) -> &mut Self
+    where
+        F: Fn(RedditClient, Arc<MessageData>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), RedditClientError>> + Send + 'static,
+    {
+        self.message_handlers.push(handler_for(filter, handler));
+        self
+    }
+
+    /// Run the bot until SIGTERM or Ctrl-C
+    pub async fn run(mut self) -> Result<(), RedditClientError> {
+        let watches_subreddits =
+            !self.submission_handlers.is_empty() || !self.comment_handlers.is_empty();
+        let watches_inbox = !self.mention_handlers.is_empty() || !self.message_handlers.is_empty();
+
+        if !watches_subreddits && !watches_inbox {
+            return Err(RedditClientError::ApiError(
+                "The bot has no handlers registered".to_string(),
+            ));
+        }
+        if watches_subreddits && self.config.subreddits.is_empty() {
+            return Err(RedditClientError::ApiError(
+                "Submission and comment handlers need at least one subreddit to watch".to_string(),
+            ));
+        }
+        if watches_inbox || self.client.access_token.is_some() {
+            self.client.ensure_access_token().await?;
+        }
+
+        let mut checkpoint = Checkpoint::load_or_new(&self.config.checkpoint_path)?;
+        let (sender, mut receiver) = mpsc::channel(100);
+        let mut tasks = Vec::new();
+
+        // Reddit accepts several subreddits joined with '+' in one listing
+        let subreddits = self.config.subreddits.join("+");
+        if !self.submission_handlers.is_empty() {
+            let mut stream = self
+                .client
+                .stream_submissions(&subreddits, self.config.stream.clone());
+            let sender = sender.clone();
+            tasks.push(tokio::spawn(async move {
+                loop {
+                    for post in stream.next_batch().await {
+                        if sender
+                            .send(Event::Submission(Box::new(post)))
+                            .await
+                            .is_err()
+                        {
+                            return;
+                        }
+                    }
+                }
+            }));
+        }
+        if !self.comment_handlers.is_empty() {
+            let mut stream = self
+                .client
+                .stream_comments(&subreddits, self.config.stream.clone());
+            let sender = sender.clone();
+            tasks.push(tokio::spawn(async move {
+                loop {
+                    for comment in stream.next_batch().await {
+                        if sender
+                            .send(Event::Comment(Box::new(comment)))
+                            .await
+                            .is_err()
+                        {
+                            return;
+                        }
+                    }
+                }
+            }));
+        }
+        if watches_inbox {
+            let mut stream = self.client.stream_inbox(self.config.inbox_stream.clone());
+            let sender = sender.clone();
+            tasks.push(tokio::spawn(async move {
+                loop {
+                    for item in stream.next_batch().await {
+                        if sender.send(Event::Inbox(item)).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            }));
+        }
+        drop(sender);
+
+        info!(
+            "Bot started with checkpoint {}",
+            self.config.checkpoint_path.display()
+        );
+
+        let shutdown = shutdown_signal();
+        tokio::pin!(shutdown);
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = &mut shutdown => {
+                    info!("Shutdown requested, stopping bot");
+                    break;
+                }
+                event = receiver.recv() => {
+                    let Some(event) = event else { break };
+                    self.dispatch(event, &mut checkpoint).await;
+                    checkpoint.save(&self.config.checkpoint_path)?;
+                }
+            }
+        }
+
+        for task in tasks {
+            task.abort();
+        }
+        checkpoint.save(&self.config.checkpoint_path)?;
+        info!("Bot stopped");
+
+        Ok(())
+    }
+
+    /// Pass one event to every matching handler and record it in the checkpoint
+    async fn dispatch(&mut self, event: Event, checkpoint: &mut Checkpoint) {
+        match event {
+            Event::Submission(post) => {
+                let fullname = format!("t3_{}", post.id);
+                if !checkpoint.submissions.is_new(&fullname, post.created_utc) {
+                    return;
+                }
+                checkpoint.submissions.record(&fullname, post.created_utc);
+
+                let text = format!("{}\n{}", post.title, post.selftext);
+                let matches =
+                    |filter: &Filter| filter.matches(Some(&post.subreddit), &post.author, &text);
+                let handlers = matching(&self.submission_handlers, matches);
+                self.run_handlers(&fullname, handlers, Arc::new(*post))
+                    .await;
+            }
+            Event::Comment(comment) => {
+                if !checkpoint
+                    .comments
+                    .is_new(&comment.name, comment.created_utc)
+                {
+                    return;
+                }
+                checkpoint
+                    .comments
+                    .record(&comment.name, comment.created_utc);
+
+                let handlers = matching(&self.comment_handlers, |filter| {
+                    filter.matches(Some(&comment.subreddit), &comment.author, &comment.body)
+                });
+                self.run_handlers(&comment.name.clone(), handlers, Arc::new(*comment))
+                    .await;
+            }
+            Event::Inbox(Thing::Comment(comment)) => {
+                if !checkpoint.inbox.is_new(&comment.name, comment.created_utc) {
+                    return;
+                }
+                checkpoint.inbox.record(&comment.name, comment.created_utc);
+
+                // Replies to our posts and comments also arrive as comments; only
+                // username mentions are dispatched
+                if !is_mention(&comment) {
+                    debug!("Ignoring inbox reply {}", comment.name);
+                    return;
+                }
+
+                let handlers = matching(&self.mention_handlers, |filter| {
+                    filter.matches(Some(&comment.subreddit), &comment.author, &comment.body)
+                });
+                self.run_handlers(&comment.name.clone(), handlers, Arc::new(*comment))
+                    .await;
+            }
+            Event::Inbox(Thing::Message(message)) => {
+                if !checkpoint.inbox.is_new(&message.name, message.created_utc) {
+                    return;
+                }
+                checkpoint.inbox.record(&message.name, message.created_utc);
+
+                let author = message.author.clone().unwrap_or_default();
+                let text = format!("{}\n{}", message.subject, message.body);
+                let handlers = matching(&self.message_handlers, |filter| {
+                    filter.matches(message.subreddit.as_deref(), &author, &text)
+                });
+                self.run_handlers(&message.name.clone(), handlers, Arc::new(*message))
+                    .await;
+            }
+            Event::Inbox(other) => {
+                debug!("Ignoring inbox item of kind {}", other.kind());
+            }
+        }
+    }
+
+    /// Call each handler in turn, refreshing the token first and logging failures
+    async fn run_handlers<T>(&mut self, fullname: &str, handlers: Vec<Callback<T>>, item: Arc<T>) {
+        for handler in handlers {
+            if let Err(err) = self.refresh_token().await {
+                error!("Skipping handler for {}: {}", fullname, err);
+                continue;
+            }
+            if let Err(err) = handler(self.client.clone(), item.clone()).await {
+                error!("Handler failed for {}: {}", fullname, err);
+            }
+        }
+    }
+
+    /// Refresh the access token if the client is authenticated
+    async fn refresh_token(&mut self) -> Result<(), RedditClientError> {
+        if self.client.access_token.is_some() {
+            self.client.ensure_access_token().await?;
+        }
+        Ok(())
+    }
+}
+
+/// Box a handler so handlers with different future types can share a list
+fn handler_for<T, F, Fut>(filter: Filter, handler: F) -> Handler<T>
+where
+    F: Fn(RedditClient, Arc<T>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), RedditClientError>> + Send + 'static,
+{
+    Handler {
+        filter,
+        callback: Arc::new(move |client, item| Box::pin(handler(client, item))),
+    }
+}
+
+/// The callbacks of the handlers whose filter accepts an event
+fn matching<T>(handlers: &[Handler<T>], accepts: impl Fn(&Filter) -> bool) -> Vec<Callback<T>> {
+    handlers
+        .iter()
+        .filter(|handler| accepts(&handler.filter))
+        .map(|handler| handler.callback.clone())
+        .collect()
+}
+
+/// Whether an inbox comment is a username mention rather than a reply
+fn is_mention(comment: &CommentData) -> bool {
+    comment
+        .additional_fields
+        .get("type")
+        .and_then(|value| value.as_str())
+        .is_some_and(|kind| kind == "username_mention")
+}
+
+/// Resolve when the process receives SIGTERM or Ctrl-C
+pub(crate) async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+            }
+            Err(err) => {
+                error!("Failed to listen for SIGTERM: {}", err);
+                tokio::signal::ctrl_c().await.ok();
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await.ok();
+    }
+}
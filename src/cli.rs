@@ -1,4 +1,11 @@
 use clap::Parser;
+use redrust::client::{
+    DistinguishKind, InboxFolder, ModListing, ModmailState, RelationshipKind, Sort, TimeFilter,
+    UserListing, VoteDirection,
+};
+use redrust::feed::FeedFormat;
+use redrust::output::OutputFormat;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -8,6 +15,58 @@ use clap::Parser;
     about = "Rust wrapper for the Reddit API."
 )]
 pub struct Cli {
+    /// Print results as text, or as JSON, NDJSON or CSV for scripts.
+    /// Logs always go to stderr.
+    #[arg(
+        long,
+        global = true,
+        default_value = "text",
+        help = "Output format (text, json, ndjson, csv)"
+    )]
+    pub output: OutputFormat,
+
+    /// Timezone for displayed times, e.g. "Europe/Berlin", "UTC" or "local".
+    /// Defaults to REDDIT_TIMEZONE, then local time.
+    #[arg(long, global = true, help = "Timezone for displayed times")]
+    pub tz: Option<String>,
+
+    /// strftime pattern for displayed times, e.g. "%d %b %H:%M".
+    /// Defaults to REDDIT_TIME_FORMAT, then each view's own pattern.
+    #[arg(long, global = true, help = "strftime pattern for displayed times")]
+    pub time_format: Option<String>,
+
+    /// Show times relative to now, e.g. "3h ago".
+    #[arg(long, global = true, help = "Show times as \"3h ago\"")]
+    pub relative_time: bool,
+
+    /// Cache GET responses on disk, separately for each account.
+    /// Also enabled by REDDIT_CACHE=1.
+    #[arg(long, global = true, help = "Cache GET responses on disk")]
+    pub cache: bool,
+
+    /// How long cached responses are served before refetching, e.g. "90s",
+    /// "5m" or "1h". Defaults to REDDIT_CACHE_TTL, then 5m.
+    #[arg(long, global = true, help = "How long cached responses stay fresh")]
+    pub cache_ttl: Option<String>,
+
+    /// Cache directory. Defaults to REDDIT_CACHE_DIR, then ~/.redrust/cache.
+    #[arg(long, global = true, help = "Response cache directory")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Serve reads only from the cache, however old, and fail on a miss.
+    /// Writes, token refreshes and live commands like --follow are refused.
+    #[arg(
+        long,
+        global = true,
+        conflicts_with = "refresh",
+        help = "Read only from the response cache"
+    )]
+    pub offline: bool,
+
+    /// Fetch everything from Reddit and update the cache.
+    #[arg(long, global = true, help = "Bypass and refresh the response cache")]
+    pub refresh: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -33,6 +92,44 @@ pub enum Commands {
             required = false
         )]
         brief: bool,
+
+        /// Keep running and print new posts as they arrive.
+        #[arg(
+            long,
+            short,
+            help = "Follow the subreddit for new posts",
+            requires = "subreddit"
+        )]
+        follow: bool,
+
+        /// Only show posts with at least this score.
+        #[arg(long, help = "Minimum post score", allow_negative_numbers = true)]
+        min_score: Option<i32>,
+
+        /// Only show posts newer than this, e.g. "30m", "2h", "3d" or "1w".
+        #[arg(long, help = "Maximum post age, e.g. 2h or 3d")]
+        max_age: Option<String>,
+
+        /// Only show posts whose flair matches this case-insensitive regex.
+        #[arg(long, help = "Regex matched against the post flair")]
+        flair: Option<String>,
+
+        /// Only show posts linking to this domain or its subdomains.
+        /// Self posts have the domain "self.<subreddit>". Can be repeated.
+        #[arg(long = "domain", help = "Link domain to keep (repeatable)")]
+        domains: Vec<String>,
+
+        /// Hide posts marked NSFW.
+        #[arg(long, help = "Hide NSFW posts")]
+        exclude_nsfw: bool,
+
+        /// Only show posts whose title or text matches this case-insensitive regex.
+        #[arg(long = "match", help = "Regex matched against the title and text")]
+        pattern: Option<String>,
+
+        /// Only show posts by this author. Can be repeated.
+        #[arg(long = "author", help = "Author to keep (repeatable)")]
+        authors: Vec<String>,
     },
 
     /// Command to create a new post in a subreddit.
@@ -50,6 +147,14 @@ pub enum Commands {
         /// Text content of the post.
         #[arg(help = "Post text content", required = true)]
         text: String,
+
+        /// Check the post against the subreddit's rules and requirements before submitting.
+        #[arg(
+            long,
+            help = "Validate against subreddit post requirements before submitting",
+            required = false
+        )]
+        validate: bool,
     },
 
     /// Create a post using user authentication (username/password).
@@ -68,6 +173,14 @@ pub enum Commands {
         /// Text content of the post.
         #[arg(help = "Post text content", required = true)]
         text: String,
+
+        /// Check the post against the subreddit's rules and requirements before submitting.
+        #[arg(
+            long,
+            help = "Validate against subreddit post requirements before submitting",
+            required = false
+        )]
+        validate: bool,
     },
 
     /// Create a post using browser-based OAuth authentication.
@@ -90,6 +203,14 @@ pub enum Commands {
         /// Port to use for the localhost callback (default: 8080).
         #[arg(help = "Port to use for the OAuth callback", required = false)]
         port: Option<u16>,
+
+        /// Check the post against the subreddit's rules and requirements before submitting.
+        #[arg(
+            long,
+            help = "Validate against subreddit post requirements before submitting",
+            required = false
+        )]
+        validate: bool,
     },
 
     /// Create a post using manual tokens (for headless environments).
@@ -113,6 +234,14 @@ pub enum Commands {
         /// Time in seconds until the access token expires.
         #[arg(help = "Token expiration time in seconds", default_value = "3600")]
         expires_in: u64,
+
+        /// Check the post against the subreddit's rules and requirements before submitting.
+        #[arg(
+            long,
+            help = "Validate against subreddit post requirements before submitting",
+            required = false
+        )]
+        validate: bool,
     },
 
     /// Create a post using a script application's API credentials.
@@ -132,6 +261,14 @@ pub enum Commands {
         /// Text content of the post.
         #[arg(help = "Post text content", required = true)]
         text: String,
+
+        /// Check the post against the subreddit's rules and requirements before submitting.
+        #[arg(
+            long,
+            help = "Validate against subreddit post requirements before submitting",
+            required = false
+        )]
+        validate: bool,
     },
 
     /// Create a comment on a post or another comment.
@@ -189,4 +326,1009 @@ pub enum Commands {
         #[arg(help = "Comment text content", required = true)]
         text: String,
     },
+
+    /// Command to show a user's profile and history.
+    /// The saved, upvoted, downvoted and hidden listings are private and require
+    /// authenticating as that user.
+    User {
+        /// The username to look up (with or without the "u/" prefix).
+        #[arg(help = "Reddit username", required = true)]
+        username: String,
+
+        /// Which listing to show: overview, submitted, comments, saved, upvoted, downvoted or hidden.
+        #[arg(
+            long,
+            short,
+            help = "Listing to show (overview, submitted, comments, saved, upvoted, downvoted, hidden)",
+            default_value = "overview"
+        )]
+        what: UserListing,
+
+        /// The number of items to retrieve.
+        #[arg(
+            long,
+            short,
+            help = "Number of items to retrieve",
+            default_value = "10"
+        )]
+        count: i32,
+
+        /// Sort order for the listing.
+        #[arg(
+            long,
+            help = "Sort order (hot, new, top, controversial)",
+            required = false
+        )]
+        sort: Option<Sort>,
+
+        /// Time window for top and controversial sorts.
+        #[arg(
+            long,
+            short,
+            help = "Time filter (hour, day, week, month, year, all)",
+            required = false
+        )]
+        time: Option<TimeFilter>,
+    },
+
+    /// Vote on one or more posts or comments.
+    /// Requires OAuth authentication with the 'vote' scope.
+    Vote {
+        /// Vote direction: up, down or clear.
+        #[arg(help = "Vote direction (up, down, clear)", required = true)]
+        direction: VoteDirection,
+
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Save one or more posts or comments.
+    /// Requires OAuth authentication with the 'save' scope.
+    Save {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+
+        /// Category to save into (Reddit Premium only).
+        #[arg(long, help = "Save category (Reddit Premium only)", required = false)]
+        category: Option<String>,
+    },
+
+    /// Remove one or more posts or comments from the saved list.
+    Unsave {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Hide one or more posts from listings.
+    /// Requires OAuth authentication with the 'report' scope.
+    Hide {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Unhide one or more previously hidden posts.
+    Unhide {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Report one or more posts or comments to the subreddit moderators.
+    /// Requires OAuth authentication with the 'report' scope.
+    Report {
+        /// The reason for the report (at most 100 characters).
+        #[arg(long, short, help = "Report reason", required = true)]
+        reason: String,
+
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Edit the text of one of your own self posts or comments.
+    /// The new text is read from --file, or from $EDITOR seeded with the current text.
+    /// A diff of the current and new text is shown before sending.
+    /// Requires OAuth authentication with the 'edit' scope.
+    Edit {
+        /// The fullname of the post ("t3_...") or comment ("t1_...") to edit.
+        #[arg(
+            help = "Reddit thing ID to edit (e.g., 't3_abcdef' or 't1_ghijkl')",
+            required = true
+        )]
+        thing_id: String,
+
+        /// File containing the new markdown text ("-" for stdin, which needs --yes
+        /// since the confirmation prompt also reads stdin).
+        #[arg(
+            long,
+            short,
+            help = "Read new text from a file ('-' for stdin, with --yes)"
+        )]
+        file: Option<PathBuf>,
+
+        /// Apply the edit without asking for confirmation.
+        #[arg(long, short, help = "Skip the confirmation prompt")]
+        yes: bool,
+    },
+
+    /// Delete one or more of your own posts or comments.
+    /// Requires OAuth authentication with the 'edit' scope.
+    Delete {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Show private messages, comment replies and mentions from your inbox.
+    /// Requires OAuth authentication with the 'privatemessages' scope.
+    Inbox {
+        /// Which inbox folder to show
+        #[arg(
+            short,
+            long,
+            help = "Folder: inbox, unread, sent, mentions",
+            default_value = "inbox"
+        )]
+        folder: InboxFolder,
+
+        /// Number of items to fetch
+        #[arg(short, long, help = "Number of items to fetch", default_value_t = 25)]
+        count: i32,
+
+        /// Mark the unread items shown as read
+        #[arg(long, help = "Mark fetched unread items as read")]
+        mark_read: bool,
+    },
+
+    /// Send, reply to and mark private messages.
+    /// Requires OAuth authentication with the 'privatemessages' scope.
+    Message {
+        #[command(subcommand)]
+        command: MessageCommands,
+    },
+
+    /// Moderator actions on posts and comments, applied in bulk, and on subreddit users.
+    /// Requires moderator permissions and OAuth authentication with the 'modposts'
+    /// (posts and comments) or 'modcontributors' (bans, mutes, approved users) scope.
+    Mod {
+        #[command(subcommand)]
+        command: ModCommands,
+    },
+
+    /// Read and answer new modmail conversations.
+    /// Requires moderator mail permissions and OAuth authentication with the 'modmail' scope.
+    Modmail {
+        #[command(subcommand)]
+        command: ModmailCommands,
+    },
+
+    /// Show a subreddit's modqueue, reports, spam, edited or unmoderated listing.
+    /// Requires moderator permissions and OAuth authentication.
+    Modqueue {
+        /// Subreddit to moderate
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// Which moderation listing to show
+        #[arg(
+            short,
+            long,
+            help = "Listing: modqueue, reports, spam, edited, unmoderated",
+            default_value = "modqueue"
+        )]
+        listing: ModListing,
+
+        /// Number of items to fetch
+        #[arg(short, long, help = "Number of items to fetch", default_value_t = 25)]
+        count: i32,
+
+        /// Print only fullnames, one per line, for piping into `mod` actions
+        #[arg(long, help = "Print only item IDs, one per line")]
+        ids_only: bool,
+    },
+
+    /// Read, edit and inspect the history of subreddit wiki pages.
+    Wiki {
+        #[command(subcommand)]
+        command: WikiCommands,
+    },
+
+    /// Apply YAML or TOML auto-moderation and auto-reply rules to new posts and comments.
+    Rules {
+        #[command(subcommand)]
+        command: RulesCommands,
+    },
+
+    /// Queue text posts to be published later by the `schedule run` daemon.
+    Schedule {
+        /// Queue file to use instead of ~/.redrust/schedule.json
+        #[arg(long, global = true, help = "Schedule queue file")]
+        store: Option<PathBuf>,
+
+        #[command(subcommand)]
+        command: ScheduleCommands,
+    },
+
+    /// Submit posts and comments in bulk from a JSON, YAML or CSV plan.
+    Batch {
+        #[command(subcommand)]
+        command: BatchCommands,
+    },
+
+    /// Browse a subreddit, read comment threads, vote and reply in a terminal UI.
+    /// Voting and replying use the stored tokens from browser-create.
+    Tui {
+        /// The subreddit to open; the public frontpage if omitted.
+        #[arg(long, short, help = "Subreddit name (optional)")]
+        subreddit: Option<String>,
+
+        /// The number of posts to load.
+        #[arg(long, short, help = "Number of posts to load", default_value = "50")]
+        count: i32,
+    },
+
+    /// Mirror subreddits into a local SQLite archive.
+    Archive {
+        #[command(subcommand)]
+        command: ArchiveCommands,
+    },
+
+    /// Print an Atom or RSS feed of a subreddit's new posts, a user's submissions or a search.
+    Feed {
+        /// The subreddit to follow; with --search, restricts the search to it.
+        #[arg(
+            help = "Subreddit name",
+            required_unless_present_any = ["user", "search"],
+            conflicts_with = "user"
+        )]
+        subreddit: Option<String>,
+
+        /// Follow a user's submitted posts instead of a subreddit.
+        #[arg(long, short, help = "Username", conflicts_with = "search")]
+        user: Option<String>,
+
+        /// Follow the newest posts matching a search, using Reddit's search syntax.
+        #[arg(long, help = "Search query")]
+        search: Option<String>,
+
+        /// Feed format.
+        #[arg(long, short, help = "Feed format (atom, rss)", default_value = "atom")]
+        format: FeedFormat,
+
+        /// The number of posts to include, fetched 100 at a time.
+        #[arg(long, short, help = "Number of posts to include", default_value_t = 25)]
+        limit: u32,
+    },
+
+    /// Run a local REST gateway so other programs can read and write through this client.
+    /// Callers send the API key from REDDIT_SERVE_API_KEY, or the one printed at startup.
+    Serve {
+        /// Address to listen on; keep it on localhost unless you mean to share your account.
+        #[arg(long, default_value = "127.0.0.1:7777", help = "Address to listen on")]
+        bind: String,
+
+        /// JSON lines file every submit, comment and vote is recorded in.
+        /// Defaults to ~/.redrust/audit.jsonl.
+        #[arg(long, help = "Audit log of writes")]
+        audit_log: Option<PathBuf>,
+
+        /// API requests per minute across all callers, to stay under Reddit's limits.
+        #[arg(long, default_value_t = 60, help = "Maximum requests per minute")]
+        requests_per_minute: u32,
+    },
+
+    /// Commands for inspecting subreddits.
+    Subreddit {
+        #[command(subcommand)]
+        command: SubredditCommands,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum SubredditCommands {
+    /// Show a subreddit's metadata, rules, post requirements and moderators.
+    /// Post requirements are only available when authenticated.
+    Info {
+        /// The name of the subreddit to describe.
+        #[arg(help = "Subreddit name", required = true)]
+        subreddit: String,
+
+        /// Include the full sidebar text.
+        #[arg(long, help = "Show the full sidebar", required = false)]
+        sidebar: bool,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum MessageCommands {
+    /// Send a new private message.
+    Send {
+        /// Recipient username, or "/r/{sub}" to message a subreddit's moderators.
+        #[arg(help = "Recipient (e.g., 'spez' or '/r/rust')", required = true)]
+        to: String,
+
+        /// Subject line of the message.
+        #[arg(help = "Message subject", required = true)]
+        subject: String,
+
+        /// Body of the message in markdown.
+        #[arg(
+            help = "Message body (or use --file)",
+            required_unless_present = "file"
+        )]
+        text: Option<String>,
+
+        /// Read the message body from a file ("-" for stdin).
+        #[arg(
+            short,
+            long,
+            help = "File containing the message body",
+            conflicts_with = "text"
+        )]
+        file: Option<PathBuf>,
+    },
+
+    /// Reply to a private message or comment reply.
+    Reply {
+        /// The fullname of the message ("t4_...") or comment ("t1_...") to reply to.
+        #[arg(
+            help = "Inbox item ID to reply to (e.g., 't4_abcdef')",
+            required = true
+        )]
+        thing_id: String,
+
+        /// Text content of the reply.
+        #[arg(help = "Reply text content", required = true)]
+        text: String,
+    },
+
+    /// Mark inbox items as read.
+    Read {
+        /// Fullnames of messages ("t4_...") or comment replies ("t1_...").
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Inbox item IDs (e.g., 't4_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Mark inbox items as unread.
+    Unread {
+        /// Fullnames of messages ("t4_...") or comment replies ("t1_...").
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Inbox item IDs (e.g., 't4_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum ModmailCommands {
+    /// List conversations, newest activity first.
+    List {
+        /// Subreddits to include (default: every subreddit you moderate).
+        #[arg(
+            short,
+            long = "subreddit",
+            help = "Only include this subreddit (repeatable)"
+        )]
+        subreddits: Vec<String>,
+
+        /// Which conversations to include.
+        #[arg(
+            long,
+            help = "State: all, new, inprogress, archived, highlighted, mod, notifications, join_requests, appeals",
+            default_value = "all"
+        )]
+        state: ModmailState,
+
+        /// Number of conversations to fetch.
+        #[arg(
+            short,
+            long,
+            help = "Number of conversations to fetch",
+            default_value_t = 25
+        )]
+        count: u32,
+    },
+
+    /// Show a conversation with all of its messages and mark it read.
+    Show {
+        /// Modmail conversation ID, as shown by `modmail list`.
+        #[arg(help = "Conversation ID (e.g., '1a2b3c')", required = true)]
+        id: String,
+    },
+
+    /// Reply to a conversation.
+    Reply {
+        /// Modmail conversation ID, as shown by `modmail list`.
+        #[arg(help = "Conversation ID (e.g., '1a2b3c')", required = true)]
+        id: String,
+
+        /// Reply text in markdown.
+        #[arg(help = "Reply text content", required = true)]
+        text: String,
+
+        /// Reply as the subreddit instead of under your own username.
+        #[arg(long, help = "Hide your username and reply as the subreddit")]
+        as_subreddit: bool,
+
+        /// Leave a private note visible only to moderators.
+        #[arg(
+            long,
+            help = "Send as a private moderator note",
+            conflicts_with = "as_subreddit"
+        )]
+        private: bool,
+    },
+
+    /// Archive conversations.
+    Archive {
+        /// Modmail conversation IDs, as shown by `modmail list`.
+        #[arg(help = "Conversation IDs (e.g., '1a2b3c')", required = true, num_args = 1..)]
+        ids: Vec<String>,
+    },
+
+    /// Move archived conversations back to the inbox.
+    Unarchive {
+        /// Modmail conversation IDs, as shown by `modmail list`.
+        #[arg(help = "Conversation IDs (e.g., '1a2b3c')", required = true, num_args = 1..)]
+        ids: Vec<String>,
+    },
+
+    /// Highlight conversations.
+    Highlight {
+        /// Modmail conversation IDs, as shown by `modmail list`.
+        #[arg(help = "Conversation IDs (e.g., '1a2b3c')", required = true, num_args = 1..)]
+        ids: Vec<String>,
+    },
+
+    /// Remove the highlight from conversations.
+    Unhighlight {
+        /// Modmail conversation IDs, as shown by `modmail list`.
+        #[arg(help = "Conversation IDs (e.g., '1a2b3c')", required = true, num_args = 1..)]
+        ids: Vec<String>,
+    },
+
+    /// Mute the user in a conversation.
+    Mute {
+        /// Modmail conversation ID, as shown by `modmail list`.
+        #[arg(help = "Conversation ID (e.g., '1a2b3c')", required = true)]
+        id: String,
+
+        /// How many days to mute for: 3, 7 or 28.
+        #[arg(
+            long,
+            help = "Mute duration in days (3, 7 or 28)",
+            default_value_t = 3,
+            value_parser = clap::builder::TypedValueParser::map(
+                clap::builder::PossibleValuesParser::new(["3", "7", "28"]),
+                |days| days.parse::<u32>().unwrap_or(3)
+            )
+        )]
+        days: u32,
+    },
+
+    /// Unmute the user in a conversation.
+    Unmute {
+        /// Modmail conversation ID, as shown by `modmail list`.
+        #[arg(help = "Conversation ID (e.g., '1a2b3c')", required = true)]
+        id: String,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum ModCommands {
+    /// Approve posts or comments, restoring them if removed or filtered.
+    Approve {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Remove posts or comments, optionally as spam and with a removal reason.
+    Remove {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+
+        /// Mark the items as spam, training the subreddit's spam filter.
+        #[arg(long, help = "Remove as spam")]
+        spam: bool,
+
+        /// ID of one of the subreddit's configured removal reasons.
+        #[arg(long, help = "Removal reason ID")]
+        reason_id: Option<String>,
+
+        /// Private note for other moderators.
+        #[arg(long, help = "Mod note explaining the removal")]
+        note: Option<String>,
+    },
+
+    /// Lock posts or comments so no new replies can be made.
+    Lock {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Unlock locked posts or comments.
+    Unlock {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+    },
+
+    /// Sticky posts at the top of the subreddit.
+    Sticky {
+        /// Fullnames of the posts ("t3_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit post IDs (e.g., 't3_abcdef'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+
+        /// Sticky slot to use (1-4).
+        #[arg(long, help = "Sticky slot (1-4)", value_parser = clap::value_parser!(u8).range(1..=4))]
+        slot: Option<u8>,
+
+        /// Remove the sticky instead of setting it.
+        #[arg(long, help = "Unsticky the posts")]
+        unset: bool,
+    },
+
+    /// Distinguish posts or comments as a moderator.
+    Distinguish {
+        /// Fullnames of the posts ("t3_...") or comments ("t1_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit thing IDs (e.g., 't3_abcdef', 't1_ghijkl'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+
+        /// How to distinguish: moderator, none, admin or special.
+        #[arg(
+            long,
+            help = "Distinguish kind (moderator, none, admin, special)",
+            default_value = "moderator"
+        )]
+        how: DistinguishKind,
+
+        /// Also sticky top-level comments to the top of the thread.
+        #[arg(long, help = "Sticky distinguished top-level comments")]
+        sticky: bool,
+    },
+
+    /// Mark posts as NSFW.
+    Nsfw {
+        /// Fullnames of the posts ("t3_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit post IDs (e.g., 't3_abcdef'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+
+        /// Remove the NSFW mark instead of setting it.
+        #[arg(long, help = "Unmark the posts as NSFW")]
+        unset: bool,
+    },
+
+    /// Mark posts as spoilers.
+    Spoiler {
+        /// Fullnames of the posts ("t3_...") to act on.
+        /// Pass "-" to read whitespace-separated IDs from stdin.
+        #[arg(
+            help = "Reddit post IDs (e.g., 't3_abcdef'), or '-' for stdin",
+            required = true,
+            num_args = 1..
+        )]
+        ids: Vec<String>,
+
+        /// Remove the spoiler mark instead of setting it.
+        #[arg(long, help = "Unmark the posts as spoilers")]
+        unset: bool,
+    },
+
+    /// Ban a user from a subreddit.
+    Ban {
+        /// Subreddit to manage
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// User to manage
+        #[arg(help = "Reddit username", required = true)]
+        username: String,
+
+        /// Ban length in days (1-999); permanent when omitted.
+        #[arg(short, long, help = "Ban duration in days (omit for permanent)", value_parser = clap::value_parser!(u32).range(1..=999))]
+        days: Option<u32>,
+
+        /// Short reason shown in the ban list.
+        #[arg(short, long, help = "Ban reason (up to 100 characters)")]
+        reason: Option<String>,
+
+        /// Message sent to the user with the ban notification.
+        #[arg(short, long, help = "Message to the banned user")]
+        message: Option<String>,
+
+        /// Private note for other moderators.
+        #[arg(long, help = "Mod note (up to 300 characters)")]
+        note: Option<String>,
+    },
+
+    /// Unban a user from a subreddit.
+    Unban {
+        /// Subreddit to manage
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// User to manage
+        #[arg(help = "Reddit username", required = true)]
+        username: String,
+    },
+
+    /// Mute a user so they can't message the subreddit's moderators.
+    Mute {
+        /// Subreddit to manage
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// User to manage
+        #[arg(help = "Reddit username", required = true)]
+        username: String,
+
+        /// Private note for other moderators.
+        #[arg(long, help = "Mod note (up to 300 characters)")]
+        note: Option<String>,
+    },
+
+    /// Unmute a muted user.
+    Unmute {
+        /// Subreddit to manage
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// User to manage
+        #[arg(help = "Reddit username", required = true)]
+        username: String,
+    },
+
+    /// Add a user as an approved submitter.
+    ApproveUser {
+        /// Subreddit to manage
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// User to manage
+        #[arg(help = "Reddit username", required = true)]
+        username: String,
+    },
+
+    /// Remove a user from the approved submitters.
+    UnapproveUser {
+        /// Subreddit to manage
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// User to manage
+        #[arg(help = "Reddit username", required = true)]
+        username: String,
+    },
+
+    /// List a subreddit's banned, muted, approved or wiki-banned users.
+    Users {
+        /// Subreddit to inspect
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// Which user list to show
+        #[arg(
+            short,
+            long,
+            help = "List: banned, muted, contributor, wikibanned",
+            default_value = "banned"
+        )]
+        kind: RelationshipKind,
+
+        /// Number of users to fetch
+        #[arg(short, long, help = "Number of users to fetch", default_value_t = 25)]
+        count: i32,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum WikiCommands {
+    /// List a subreddit's wiki pages.
+    List {
+        /// Subreddit whose wiki to use
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+    },
+
+    /// Print a wiki page's markdown, or save it to a file.
+    Get {
+        /// Subreddit whose wiki to use
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// Wiki page name, e.g. "index" or "config/automoderator"
+        #[arg(help = "Wiki page name (e.g., 'index')", required = true)]
+        page: String,
+
+        /// Revision ID to fetch instead of the latest.
+        #[arg(short, long, help = "Revision ID (from 'wiki history')")]
+        revision: Option<String>,
+
+        /// Write the page content to a file instead of printing it.
+        #[arg(short, long, help = "File to write the page content to")]
+        file: Option<PathBuf>,
+    },
+
+    /// Replace a wiki page's content, showing a diff first.
+    /// Requires OAuth authentication with the 'wikiedit' scope.
+    Edit {
+        /// Subreddit whose wiki to use
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// Wiki page name, e.g. "index" or "config/automoderator"
+        #[arg(help = "Wiki page name (e.g., 'index')", required = true)]
+        page: String,
+
+        /// Read the new content from a file ("-" for stdin) instead of opening $EDITOR.
+        /// Reading from stdin requires --yes, since stdin can't also answer the prompt.
+        #[arg(short, long, help = "File containing the new page content")]
+        file: Option<PathBuf>,
+
+        /// Reason for the edit, shown in the page history.
+        #[arg(short, long, help = "Edit reason")]
+        reason: Option<String>,
+
+        /// Apply the edit without asking for confirmation.
+        #[arg(short, long, help = "Skip the confirmation prompt")]
+        yes: bool,
+    },
+
+    /// Show a wiki page's revision history.
+    History {
+        /// Subreddit whose wiki to use
+        #[arg(help = "Subreddit name (e.g., 'rust' or 'r/rust')", required = true)]
+        subreddit: String,
+
+        /// Wiki page name, e.g. "index" or "config/automoderator"
+        #[arg(help = "Wiki page name (e.g., 'index')", required = true)]
+        page: String,
+
+        /// Number of revisions to fetch.
+        #[arg(
+            short,
+            long,
+            help = "Number of revisions to fetch",
+            default_value_t = 25
+        )]
+        count: i32,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum RulesCommands {
+    /// Watch subreddits and apply the rules to each new post and comment until stopped.
+    /// Moderator actions need moderator permissions and the matching OAuth scopes.
+    Run {
+        /// Rules file (.yaml, .yml or .toml)
+        #[arg(help = "Rules file", required = true)]
+        rules: PathBuf,
+
+        /// Subreddit to watch; may be repeated. Defaults to the rules file's `subreddits`.
+        #[arg(short, long, help = "Subreddit to watch (repeatable)")]
+        subreddit: Vec<String>,
+
+        /// Print what each matching rule would do without doing it.
+        #[arg(long, help = "Log actions instead of performing them")]
+        dry_run: bool,
+    },
+
+    /// Check which rules match posts and comments saved as JSON, without calling Reddit.
+    /// Fixtures may include an `author` account and an `expect` list of rule names.
+    Test {
+        /// Rules file (.yaml, .yml or .toml)
+        #[arg(help = "Rules file", required = true)]
+        rules: PathBuf,
+
+        /// JSON files holding a post or comment, an array of them, or a listing
+        #[arg(help = "Fixture files", required = true)]
+        fixtures: Vec<PathBuf>,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum ScheduleCommands {
+    /// Queue a text post for a given time.
+    Add {
+        /// Subreddit to post to
+        #[arg(help = "Subreddit name", required = true)]
+        subreddit: String,
+
+        /// Title of the post
+        #[arg(help = "Post title", required = true)]
+        title: String,
+
+        /// When to publish, e.g. "2026-11-01T09:00"
+        #[arg(long, help = "Publish time (YYYY-MM-DDTHH:MM)", required = true)]
+        at: String,
+
+        /// IANA timezone for --at, e.g. "Europe/Berlin". Defaults to local time.
+        #[arg(long, help = "Timezone for --at (default: local time)")]
+        tz: Option<String>,
+
+        /// Text content of the post
+        #[arg(long, help = "Post text content", conflicts_with = "text_file")]
+        text: Option<String>,
+
+        /// Read the post text from a file ("-" for stdin)
+        #[arg(long, help = "File containing the post text")]
+        text_file: Option<PathBuf>,
+    },
+
+    /// List scheduled posts that are still waiting to be published.
+    List {
+        /// Also list published, failed and cancelled posts.
+        #[arg(short, long, help = "Include finished and cancelled posts")]
+        all: bool,
+    },
+
+    /// Show a scheduled post with its status history.
+    Show {
+        /// Job number from 'schedule list'
+        #[arg(help = "Scheduled post number", required = true)]
+        id: u64,
+    },
+
+    /// Cancel a scheduled post that hasn't been published yet.
+    Cancel {
+        /// Job number from 'schedule list'
+        #[arg(help = "Scheduled post number", required = true)]
+        id: u64,
+    },
+
+    /// Publish scheduled posts as they become due, using the stored OAuth tokens.
+    /// Run only one daemon per queue file.
+    Run {
+        /// Publish whatever is due now and exit, for use from cron.
+        #[arg(long, help = "Publish due posts once and exit")]
+        once: bool,
+
+        /// Attempts before a post is marked as failed.
+        #[arg(long, default_value_t = 5, help = "Maximum publish attempts per post")]
+        max_attempts: u32,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum BatchCommands {
+    /// Run every row of a plan, pacing requests and recording the outcome of each row.
+    /// Rows are `submit` (subreddit, title, text or text_file) or `comment` (thing_id, text).
+    Run {
+        /// Plan file (.json, .yaml, .yml or .csv)
+        #[arg(help = "Plan file", required = true)]
+        plan: PathBuf,
+
+        /// Results file (.json or .csv). Defaults to <plan>.results.json next to the plan.
+        #[arg(long, help = "Results file")]
+        results: Option<PathBuf>,
+
+        /// Validate the plan and print what would be posted without posting.
+        #[arg(long, help = "Print rows instead of posting them")]
+        dry_run: bool,
+
+        /// Continue an earlier run, retrying only rows that haven't succeeded.
+        #[arg(long, help = "Skip rows the results file records as posted")]
+        resume: bool,
+
+        /// API requests per minute, to stay under Reddit's limits.
+        #[arg(long, default_value_t = 30, help = "Maximum requests per minute")]
+        requests_per_minute: u32,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum ArchiveCommands {
+    /// Fetch new posts and their comment trees into the archive.
+    /// Later syncs stop at the newest stored post, update scores, and record
+    /// edits and removals.
+    Sync {
+        /// The subreddit to mirror
+        #[arg(help = "Subreddit name", required = true)]
+        subreddit: String,
+
+        /// SQLite archive file, created if it doesn't exist.
+        #[arg(long, default_value = "archive.sqlite", help = "Archive database file")]
+        db: PathBuf,
+
+        /// Page through the whole listing instead of stopping at the newest stored post.
+        #[arg(long, help = "Ignore the resume point from the last sync")]
+        full: bool,
+
+        /// Most posts to fetch in one sync; Reddit stops listings at about 1000.
+        #[arg(long, default_value_t = 1000, help = "Maximum posts to fetch")]
+        max_posts: usize,
+
+        /// Only archive posts, without fetching comment trees.
+        #[arg(long, help = "Don't fetch comments")]
+        no_comments: bool,
+
+        /// API requests per minute, to stay under Reddit's limits.
+        #[arg(long, default_value_t = 60, help = "Maximum requests per minute")]
+        requests_per_minute: u32,
+    },
 }
@@ -0,0 +1,170 @@
+use super::{check_fullname, RedditClient, RedditClientError};
+use log::debug;
+use std::fmt;
+use std::str::FromStr;
+
+/// Direction of a vote
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteDirection {
+    Up,
+    Down,
+    Clear,
+}
+
+impl VoteDirection {
+    /// The value Reddit expects in the `dir` parameter
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            VoteDirection::Up => "1",
+            VoteDirection::Down => "-1",
+            VoteDirection::Clear => "0",
+        }
+    }
+}
+
+impl fmt::Display for VoteDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            VoteDirection::Up => "up",
+            VoteDirection::Down => "down",
+            VoteDirection::Clear => "clear",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for VoteDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "up" | "upvote" | "1" => Ok(VoteDirection::Up),
+            "down" | "downvote" | "-1" => Ok(VoteDirection::Down),
+            "clear" | "none" | "0" => Ok(VoteDirection::Clear),
+            other => Err(format!(
+                "Unknown vote direction '{}', expected one of: up, down, clear",
+                other
+            )),
+        }
+    }
+}
+
+impl RedditClient {
+    /// Vote on a post or comment.
+    ///
+    /// # Arguments
+    /// * `fullname` - The fullname of the post ("t3_...") or comment ("t1_...")
+    /// * `direction` - Upvote, downvote, or clear an existing vote
+    ///
+    /// # Note
+    /// Requires user authentication with the 'vote' scope.
+    pub async fn vote(
+        &self,
+        fullname: &str,
+        direction: VoteDirection,
+    ) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        self.post_form(
+            "/api/vote",
+            &[("id", fullname), ("dir", direction.as_param())],
+        )
+        .await?;
+        debug!("Voted {} on {}", direction, fullname);
+
+        Ok(())
+    }
+
+    /// Save a post or comment, optionally into a named category.
+    ///
+    /// # Note
+    /// Categories are only available to Reddit Premium accounts.
+    /// Requires user authentication with the 'save' scope.
+    pub async fn save(
+        &self,
+        fullname: &str,
+        category: Option<&str>,
+    ) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        let mut params = vec![("id", fullname)];
+        if let Some(category) = category {
+            params.push(("category", category));
+        }
+
+        self.post_form("/api/save", &params).await?;
+        debug!("Saved {}", fullname);
+
+        Ok(())
+    }
+
+    /// Remove a post or comment from the saved list.
+    ///
+    /// # Note
+    /// Requires user authentication with the 'save' scope.
+    pub async fn unsave(&self, fullname: &str) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        self.post_form("/api/unsave", &[("id", fullname)]).await?;
+        debug!("Unsaved {}", fullname);
+
+        Ok(())
+    }
+
+    /// Hide a post from listings.
+    ///
+    /// # Note
+    /// Only posts can be hidden. Requires user authentication with the 'report' scope.
+    pub async fn hide(&self, fullname: &str) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t3"])?;
+
+        self.post_form("/api/hide", &[("id", fullname)]).await?;
+        debug!("Hid {}", fullname);
+
+        Ok(())
+    }
+
+    /// Unhide a previously hidden post.
+    ///
+    /// # Note
+    /// Requires user authentication with the 'report' scope.
+    pub async fn unhide(&self, fullname: &str) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t3"])?;
+
+        self.post_form("/api/unhide", &[("id", fullname)]).await?;
+        debug!("Unhid {}", fullname);
+
+        Ok(())
+    }
+
+    /// Report a post or comment to the subreddit moderators.
+    ///
+    /// # Arguments
+    /// * `fullname` - The fullname of the post ("t3_...") or comment ("t1_...")
+    /// * `reason` - The report reason (at most 100 characters)
+    ///
+    /// # Note
+    /// Requires user authentication with the 'report' scope.
+    pub async fn report(&self, fullname: &str, reason: &str) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        if reason.chars().count() > 100 {
+            return Err(RedditClientError::ApiError(
+                "Report reason must be at most 100 characters".to_string(),
+            ));
+        }
+
+        self.post_form(
+            "/api/report",
+            &[
+                ("api_type", "json"),
+                ("thing_id", fullname),
+                ("reason", reason),
+            ],
+        )
+        .await?;
+        debug!("Reported {}", fullname);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use super::{RedditClient, RedditClientError};
+use chrono::{DateTime, Utc};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How the response cache is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheMode {
+    /// Serve fresh cached responses and cache new ones
+    #[default]
+    ReadWrite,
+    /// Only serve cached responses, however old; never touch the network
+    Offline,
+    /// Always fetch, replacing cached responses
+    Refresh,
+}
+
+/// A cached GET response
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    fetched_at: DateTime<Utc>,
+    body: String,
+}
+
+/// On-disk cache of successful GET responses.
+///
+/// Entries live under a directory per auth identity, so responses fetched
+/// with one account are never served to another.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    ttl: Duration,
+    mode: CacheMode,
+}
+
+impl ResponseCache {
+    /// Create a cache in `dir` whose entries are fresh for `ttl`
+    pub fn new(dir: PathBuf, ttl: Duration, mode: CacheMode) -> Self {
+        Self { dir, ttl, mode }
+    }
+
+    /// The default cache directory, ~/.redrust/cache
+    pub fn default_dir() -> PathBuf {
+        let mut dir = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        dir.push(".redrust");
+        dir.push("cache");
+        dir
+    }
+
+    pub fn mode(&self) -> CacheMode {
+        self.mode
+    }
+
+    fn entry_path(&self, identity: &str, url: &str) -> PathBuf {
+        self.dir
+            .join(identity)
+            .join(format!("{}.json", hex_digest(url)))
+    }
+
+    /// Look up a response.
+    ///
+    /// Returns `None` when the request should go to the network. In offline
+    /// mode a missing entry is an error instead.
+    pub fn lookup(&self, identity: &str, url: &str) -> Result<Option<String>, RedditClientError> {
+        if self.mode == CacheMode::Refresh {
+            return Ok(None);
+        }
+
+        let entry = read_entry(&self.entry_path(identity, url)).filter(|entry| entry.url == url);
+        match (entry, self.mode) {
+            (Some(entry), CacheMode::Offline) => {
+                debug!("Serving {} from cache (offline)", url);
+                Ok(Some(entry.body))
+            }
+            (None, CacheMode::Offline) => Err(RedditClientError::ApiError(format!(
+                "Offline and no cached response for {}",
+                url
+            ))),
+            (Some(entry), _) => {
+                let age = (Utc::now() - entry.fetched_at).to_std().unwrap_or_default();
+                if age < self.ttl {
+                    debug!("Serving {} from cache ({}s old)", url, age.as_secs());
+                    Ok(Some(entry.body))
+                } else {
+                    Ok(None)
+                }
+            }
+            (None, _) => Ok(None),
+        }
+    }
+
+    /// Cache a successful response; failures to write are logged and ignored
+    pub fn store(&self, identity: &str, url: &str, body: &str) {
+        let path = self.entry_path(identity, url);
+        let entry = CacheEntry {
+            url: url.to_string(),
+            fetched_at: Utc::now(),
+            body: body.to_string(),
+        };
+        if let Err(err) = write_entry(&path, &entry) {
+            debug!("Failed to cache {} in {}: {}", url, path.display(), err);
+        }
+    }
+}
+
+impl RedditClient {
+    /// Cache GET responses on disk
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(std::sync::Arc::new(cache));
+        self
+    }
+
+    /// Fetch every GET from Reddit, still saving responses to the cache.
+    ///
+    /// For streams, bots and other long-running loops, which would otherwise
+    /// get the same cached listing back for the whole TTL and miss new items.
+    /// Offline, their requests fail instead.
+    pub fn bypassing_cache(mut self) -> Self {
+        self.bypass_cache = true;
+        self
+    }
+
+    /// The auth identity used to separate cached responses.
+    ///
+    /// Derived from the bearer token the request actually carries. When that is
+    /// the access token stored alongside a refresh token, the refresh token is
+    /// hashed instead, so the identity survives access token refreshes; a token
+    /// from elsewhere, e.g. REDDIT_ACCESS_TOKEN, is hashed itself. Requests
+    /// without one are "anonymous". Only a hash is used, never the token.
+    pub(crate) fn cache_identity(&self, request: &reqwest::Request) -> String {
+        let Some(token) = request
+            .headers()
+            .get(reqwest::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+        else {
+            return "anonymous".to_string();
+        };
+
+        let stored = self.token_storage.as_ref().and_then(|storage| {
+            storage
+                .refresh_token
+                .as_ref()
+                .filter(|_| storage.access_token.as_deref() == Some(token))
+                .map(|refresh_token| (storage, refresh_token))
+        });
+
+        match stored {
+            Some((storage, refresh_token)) => format!(
+                "user-{}",
+                &hex_digest(&format!("{}:{}", storage.client_id, refresh_token))[..16]
+            ),
+            None => format!("token-{}", &hex_digest(token)[..16]),
+        }
+    }
+}
+
+fn read_entry(path: &Path) -> Option<CacheEntry> {
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Write an entry through a temporary file so readers never see half of it
+fn write_entry(path: &Path, entry: &CacheEntry) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, serde_json::to_vec(entry)?)?;
+    fs::rename(&tmp, path)
+}
+
+fn hex_digest(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -0,0 +1,552 @@
+use super::{RedditClient, RedditClientError};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Replaces secrets in recorded requests and responses
+const REDACTED: &str = "REDACTED";
+
+/// Headers whose values are never written to a cassette
+const SECRET_HEADERS: &[&str] = &["authorization", "cookie", "set-cookie"];
+
+/// Form fields and query parameters whose values are never written to a cassette
+const SECRET_FIELDS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "code",
+    "password",
+    "passwd",
+    "client_secret",
+];
+
+/// JSON keys whose values are never written to a cassette
+const SECRET_KEYS: &[&str] = &["access_token", "refresh_token", "id_token", "modhash"];
+
+/// Whether a cassette captures live traffic or stands in for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests to Reddit and save each request/response pair
+    Record,
+    /// Answer requests from the saved pairs without touching the network
+    Replay,
+}
+
+impl std::str::FromStr for CassetteMode {
+    type Err = RedditClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "record" => Ok(CassetteMode::Record),
+            "replay" => Ok(CassetteMode::Replay),
+            _ => Err(RedditClientError::ApiError(format!(
+                "Invalid cassette mode '{}', expected record or replay",
+                s
+            ))),
+        }
+    }
+}
+
+/// A recorded request, with secrets scrubbed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    #[serde(default)]
+    pub body: String,
+}
+
+/// A recorded response, with secrets scrubbed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    #[serde(default)]
+    pub body: String,
+}
+
+/// One request and the response Reddit gave to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// The JSON file a cassette is stored in
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// Recorded HTTP traffic for deterministic tests.
+///
+/// In record mode every request the client sends is passed through to Reddit
+/// and the pair is appended to a JSON file. In replay mode requests are
+/// matched, in order, against the recorded ones by method, URL and body, and
+/// the recorded response is returned instead.
+///
+/// Authorization and cookie headers, and token, password and code fields in
+/// URLs, form bodies and JSON bodies, are replaced with "REDACTED" before
+/// anything is written. Requests are scrubbed the same way before matching,
+/// so a replayed run doesn't need the credentials it was recorded with.
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    interactions: Mutex<Vec<Interaction>>,
+    /// Which recorded interactions have already been replayed
+    used: Mutex<Vec<bool>>,
+}
+
+impl Cassette {
+    /// Open a cassette file.
+    ///
+    /// Recording starts a fresh file; replaying requires the file to exist.
+    pub fn open(path: impl Into<PathBuf>, mode: CassetteMode) -> Result<Self, RedditClientError> {
+        let path = path.into();
+        let interactions = match mode {
+            CassetteMode::Record => Vec::new(),
+            CassetteMode::Replay => {
+                let contents = fs::read_to_string(&path).map_err(|e| {
+                    RedditClientError::ApiError(format!(
+                        "Failed to read cassette {}: {}",
+                        path.display(),
+                        e
+                    ))
+                })?;
+                serde_json::from_str::<CassetteFile>(&contents)?.interactions
+            }
+        };
+        debug!(
+            "Opened cassette {} for {:?} with {} interactions",
+            path.display(),
+            mode,
+            interactions.len()
+        );
+
+        Ok(Self {
+            path,
+            mode,
+            used: Mutex::new(vec![false; interactions.len()]),
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The interactions recorded or loaded so far
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions.lock().unwrap().clone()
+    }
+
+    /// Send a request through the cassette
+    pub(crate) async fn execute(
+        &self,
+        client: &reqwest::Client,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, RedditClientError> {
+        let recorded = record_request(&request);
+        match self.mode {
+            CassetteMode::Replay => {
+                let response = self.replay(&recorded)?;
+                into_response(response)
+            }
+            CassetteMode::Record => {
+                let response = client.execute(request).await?;
+                let status = response.status().as_u16();
+                let headers = response
+                    .headers()
+                    .iter()
+                    .map(|(name, value)| {
+                        (
+                            name.to_string(),
+                            value.to_str().unwrap_or_default().to_string(),
+                        )
+                    })
+                    .collect();
+                let body = response.text().await?;
+
+                // The caller gets the real response; only the saved copy is scrubbed
+                let live = RecordedResponse {
+                    status,
+                    headers,
+                    body,
+                };
+                self.record(Interaction {
+                    request: recorded,
+                    response: scrub_response(&live),
+                })?;
+                into_response(live)
+            }
+        }
+    }
+
+    /// Find the first unused interaction matching a request
+    fn replay(&self, request: &RecordedRequest) -> Result<RecordedResponse, RedditClientError> {
+        let interactions = self.interactions.lock().unwrap();
+        let mut used = self.used.lock().unwrap();
+
+        let index = interactions
+            .iter()
+            .enumerate()
+            .position(|(i, interaction)| {
+                !used[i]
+                    && interaction.request.method == request.method
+                    && interaction.request.url == request.url
+                    && interaction.request.body == request.body
+            })
+            .ok_or_else(|| {
+                RedditClientError::ApiError(format!(
+                    "No recorded response for {} {} in cassette {}",
+                    request.method,
+                    request.url,
+                    self.path.display()
+                ))
+            })?;
+
+        used[index] = true;
+        debug!("Replaying {} {} from cassette", request.method, request.url);
+        Ok(interactions[index].response.clone())
+    }
+
+    /// Append an interaction and rewrite the cassette file
+    fn record(&self, interaction: Interaction) -> Result<(), RedditClientError> {
+        let mut interactions = self.interactions.lock().unwrap();
+        debug!(
+            "Recording {} {} to cassette",
+            interaction.request.method, interaction.request.url
+        );
+        interactions.push(interaction);
+
+        let file = CassetteFile {
+            interactions: interactions.clone(),
+        };
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| cassette_error(&self.path, e))?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&file)?)
+            .map_err(|e| cassette_error(&self.path, e))
+    }
+}
+
+impl RedditClient {
+    /// Record or replay all HTTP traffic with a cassette
+    pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(std::sync::Arc::new(cassette));
+        self
+    }
+}
+
+/// Capture the parts of a request we match on, scrubbed
+fn record_request(request: &reqwest::Request) -> RecordedRequest {
+    let mut url = request.url().clone();
+    let query: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| (key.to_string(), scrub_field(&key, &value)))
+        .collect();
+    if !query.is_empty() {
+        url.query_pairs_mut().clear().extend_pairs(query);
+    }
+
+    let headers = request
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            let value = if SECRET_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                value.to_str().unwrap_or_default().to_string()
+            };
+            (name.to_string(), value)
+        })
+        .collect();
+
+    let body = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+        .unwrap_or_default();
+    let is_form = request
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"));
+
+    RecordedRequest {
+        method: request.method().to_string(),
+        url: url.to_string(),
+        headers,
+        body: if is_form {
+            scrub_form(&body)
+        } else {
+            scrub_json(&body)
+        },
+    }
+}
+
+fn scrub_response(response: &RecordedResponse) -> RecordedResponse {
+    RecordedResponse {
+        status: response.status,
+        headers: response
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if SECRET_HEADERS.contains(&name.to_lowercase().as_str()) {
+                    REDACTED.to_string()
+                } else {
+                    value.clone()
+                };
+                (name.clone(), value)
+            })
+            .collect(),
+        body: scrub_json(&response.body),
+    }
+}
+
+fn scrub_field(key: &str, value: &str) -> String {
+    if SECRET_FIELDS.contains(&key) {
+        REDACTED.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Scrub a form body, sorting its fields so bodies built from maps match between runs
+fn scrub_form(body: &str) -> String {
+    let mut pairs: Vec<(String, String)> = url::form_urlencoded::parse(body.as_bytes())
+        .map(|(key, value)| (key.to_string(), scrub_field(&key, &value)))
+        .collect();
+    pairs.sort();
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish()
+}
+
+/// Scrub secret keys anywhere in a JSON body; other bodies are kept as they are
+fn scrub_json(body: &str) -> String {
+    fn scrub(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if SECRET_KEYS.contains(&key.as_str()) && !value.is_null() {
+                        *value = serde_json::Value::String(REDACTED.to_string());
+                    } else {
+                        scrub(value);
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(scrub),
+            _ => {}
+        }
+    }
+
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(mut json) => {
+            scrub(&mut json);
+            json.to_string()
+        }
+        Err(_) => body.to_string(),
+    }
+}
+
+/// Turn a recorded response back into one the client can read
+fn into_response(recorded: RecordedResponse) -> Result<reqwest::Response, RedditClientError> {
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+        // The body is stored decoded, so its original framing no longer applies
+        if matches!(
+            name.to_lowercase().as_str(),
+            "content-length" | "content-encoding" | "transfer-encoding"
+        ) {
+            continue;
+        }
+        builder = builder.header(name, value);
+    }
+    let response = builder
+        .body(recorded.body)
+        .map_err(|e| RedditClientError::ApiError(format!("Invalid recorded response: {}", e)))?;
+    Ok(reqwest::Response::from(response))
+}
+
+fn cassette_error(path: &Path, err: std::io::Error) -> RedditClientError {
+    RedditClientError::ApiError(format!(
+        "Failed to write cassette {}: {}",
+        path.display(),
+        err
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// A cassette from tests/cassettes, opened for replay
+    fn fixture(name: &str) -> Cassette {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/cassettes")
+            .join(format!("{}.json", name));
+        Cassette::open(path, CassetteMode::Replay).unwrap()
+    }
+
+    /// A client replaying a fixture; its token is never compared, since it is scrubbed
+    fn replaying(name: &str) -> RedditClient {
+        let mut client = RedditClient::new().with_cassette(fixture(name));
+        client.access_token = Some("not-the-recorded-token".to_string());
+        client
+    }
+
+    #[tokio::test]
+    async fn replays_new_posts() {
+        let listing = replaying("new-posts")
+            .fetch_new_posts("rust", 2)
+            .await
+            .unwrap();
+
+        let titles: Vec<_> = listing
+            .data
+            .children
+            .iter()
+            .map(|post| post.data.title.as_str())
+            .collect();
+        assert_eq!(
+            titles,
+            ["What are you working on this week?", "Announcing Rust 1.90"]
+        );
+    }
+
+    #[tokio::test]
+    async fn replays_create_post() {
+        let url = replaying("create-post")
+            .create_post("rust", "Cassette test post", "Recorded for replay tests")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            url,
+            "https://www.reddit.com/r/rust/comments/100003/cassette_test_post/"
+        );
+    }
+
+    #[tokio::test]
+    async fn replays_token_exchange() {
+        let mut client = RedditClient::new().with_cassette(fixture("access-token"));
+        let token = client.get_access_token("another-client-id").await.unwrap();
+
+        assert_eq!(token, REDACTED);
+        assert_eq!(client.access_token.as_deref(), Some(REDACTED));
+    }
+
+    #[tokio::test]
+    async fn replay_fails_on_unrecorded_request() {
+        let result = replaying("new-posts").fetch_new_posts("golang", 2).await;
+
+        assert!(
+            matches!(result, Err(RedditClientError::ApiError(msg)) if msg.contains("No recorded response"))
+        );
+    }
+
+    #[tokio::test]
+    async fn record_scrubs_tokens() {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        let handle = std::thread::spawn(move || {
+            for _ in 0..2 {
+                let request = server.recv().unwrap();
+                let body = json!({
+                    "access_token": "live-access-token",
+                    "refresh_token": "live-refresh-token",
+                    "expires_in": 3600,
+                });
+                let header =
+                    tiny_http::Header::from_bytes("Content-Type", "application/json").unwrap();
+                request
+                    .respond(tiny_http::Response::from_string(body.to_string()).with_header(header))
+                    .unwrap();
+            }
+        });
+
+        let path = std::env::temp_dir().join(format!(
+            "redrust-cassette-{}-{:08x}.json",
+            std::process::id(),
+            rand::random::<u32>()
+        ));
+        let client = RedditClient::new()
+            .with_base_url(url::Url::parse(&format!("http://127.0.0.1:{}", port)).unwrap())
+            .with_cassette(Cassette::open(&path, CassetteMode::Record).unwrap());
+
+        let form = client
+            .send(
+                client
+                    .client
+                    .post("https://www.reddit.com/api/v1/access_token")
+                    .header("Authorization", "Basic bGl2ZS1jbGllbnQ6")
+                    .form(&[
+                        ("grant_type", "refresh_token"),
+                        ("refresh_token", "live-refresh-token"),
+                    ]),
+            )
+            .await
+            .unwrap();
+        // The caller still sees the real tokens
+        let live: serde_json::Value = form.json().await.unwrap();
+        assert_eq!(live["access_token"], "live-access-token");
+
+        client
+            .send(
+                client
+                    .client
+                    .post("https://oauth.reddit.com/api/example")
+                    .header("Authorization", "Bearer live-access-token")
+                    .json(&json!({
+                        "access_token": "live-access-token",
+                        "nested": { "refresh_token": "live-refresh-token" },
+                    })),
+            )
+            .await
+            .unwrap();
+        handle.join().unwrap();
+
+        let saved = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        for secret in [
+            "live-access-token",
+            "live-refresh-token",
+            "bGl2ZS1jbGllbnQ6",
+        ] {
+            assert!(!saved.contains(secret), "cassette contains {}", secret);
+        }
+
+        let file: CassetteFile = serde_json::from_str(&saved).unwrap();
+        let [form, json] = &file.interactions[..] else {
+            panic!("expected two interactions, got {}", file.interactions.len());
+        };
+        for interaction in [form, json] {
+            assert!(interaction
+                .request
+                .headers
+                .contains(&("authorization".to_string(), REDACTED.to_string())));
+            let response: serde_json::Value =
+                serde_json::from_str(&interaction.response.body).unwrap();
+            assert_eq!(response["access_token"], REDACTED);
+            assert_eq!(response["refresh_token"], REDACTED);
+        }
+        assert_eq!(
+            form.request.body,
+            "grant_type=refresh_token&refresh_token=REDACTED"
+        );
+        let body: serde_json::Value = serde_json::from_str(&json.request.body).unwrap();
+        assert_eq!(body["access_token"], REDACTED);
+        assert_eq!(body["nested"]["refresh_token"], REDACTED);
+    }
+}
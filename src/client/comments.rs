@@ -0,0 +1,120 @@
+use super::listing::Sort;
+use super::{RedditClient, RedditClientError};
+use crate::models::comment::{CommentNode, CommentThread};
+use crate::models::listing::{Listing, Thing};
+use log::debug;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+impl RedditClient {
+    /// Fetch a post and its comment tree.
+    ///
+    /// # Arguments
+    /// * `post_id` - The post ID, with or without the "t3_" prefix
+    /// * `sort` - Comment sort order (Reddit's default when `None`)
+    /// * `limit` - Maximum number of comments to load (Reddit's default when `None`)
+    pub async fn fetch_comments(
+        &self,
+        post_id: &str,
+        sort: Option<Sort>,
+        limit: Option<u32>,
+    ) -> Result<CommentThread, RedditClientError> {
+        let post_id = post_id.trim_start_matches("t3_");
+        let (post_listing, comment_listing): (Listing<Thing>, Listing<Thing>) =
+            self.get_comments_json(post_id, sort, limit).await?;
+
+        let post = post_listing
+            .data
+            .children
+            .into_iter()
+            .find_map(|thing| match thing {
+                Thing::Link(post) => Some(post),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                RedditClientError::ApiError(format!("Could not find post t3_{}", post_id))
+            })?;
+
+        let (comments, more) = build_nodes(comment_listing.data.children);
+        debug!(
+            "Fetched {} top-level comments for t3_{}",
+            comments.len(),
+            post_id
+        );
+
+        Ok(CommentThread {
+            post,
+            comments,
+            more,
+        })
+    }
+
+    /// Fetch a post's top-level comments as Reddit sent them, replies nested
+    /// under each comment's `replies`, for callers that keep the original JSON
+    ///
+    /// # Arguments
+    /// * `post_id` - The post ID, with or without the "t3_" prefix
+    /// * `sort` - Comment sort order (Reddit's default when `None`)
+    /// * `limit` - Maximum number of comments to load (Reddit's default when `None`)
+    pub async fn fetch_comments_json(
+        &self,
+        post_id: &str,
+        sort: Option<Sort>,
+        limit: Option<u32>,
+    ) -> Result<Listing<Value>, RedditClientError> {
+        let post_id = post_id.trim_start_matches("t3_");
+        let (_, comment_listing): (Value, Listing<Value>) =
+            self.get_comments_json(post_id, sort, limit).await?;
+        Ok(comment_listing)
+    }
+
+    /// Fetch `/comments/{id}`, which is two listings: the post, then its top-level comments
+    async fn get_comments_json<T: DeserializeOwned>(
+        &self,
+        post_id: &str,
+        sort: Option<Sort>,
+        limit: Option<u32>,
+    ) -> Result<T, RedditClientError> {
+        let mut query = vec![("raw_json", "1".to_string())];
+        if let Some(sort) = sort {
+            query.push(("sort", sort.as_str().to_string()));
+        }
+        if let Some(limit) = limit {
+            query.push(("limit", limit.to_string()));
+        }
+
+        let path = format!("/comments/{}.json", post_id);
+        self.get_json(&path, &query).await
+    }
+}
+
+/// Turn a listing's children into comment nodes, recursing into each comment's
+/// replies, and count the comments left behind "more" stubs
+fn build_nodes(children: Vec<Thing>) -> (Vec<CommentNode>, usize) {
+    let mut nodes = Vec::new();
+    let mut more = 0;
+
+    for thing in children {
+        match thing {
+            Thing::Comment(mut comment) => {
+                // Replies are an empty string when there are none, otherwise a listing
+                let (replies, more_replies) =
+                    match serde_json::from_value::<Listing<Thing>>(comment.replies.take()) {
+                        Ok(listing) => build_nodes(listing.data.children),
+                        Err(_) => (Vec::new(), 0),
+                    };
+                nodes.push(CommentNode {
+                    comment: *comment,
+                    replies,
+                    more: more_replies,
+                });
+            }
+            Thing::Other { kind, data } if kind == "more" => {
+                more += data["count"].as_u64().unwrap_or(0) as usize;
+            }
+            _ => {}
+        }
+    }
+
+    (nodes, more)
+}
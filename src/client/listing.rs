@@ -0,0 +1,149 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Sort order for listings that support it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sort {
+    Hot,
+    New,
+    Top,
+    Controversial,
+}
+
+impl Sort {
+    /// The value Reddit expects in the `sort` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Sort::Hot => "hot",
+            Sort::New => "new",
+            Sort::Top => "top",
+            Sort::Controversial => "controversial",
+        }
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Sort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hot" => Ok(Sort::Hot),
+            "new" => Ok(Sort::New),
+            "top" => Ok(Sort::Top),
+            "controversial" => Ok(Sort::Controversial),
+            other => Err(format!(
+                "Unknown sort '{}', expected one of: hot, new, top, controversial",
+                other
+            )),
+        }
+    }
+}
+
+/// Time window for `top` and `controversial` listings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeFilter {
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+    All,
+}
+
+impl TimeFilter {
+    /// The value Reddit expects in the `t` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeFilter::Hour => "hour",
+            TimeFilter::Day => "day",
+            TimeFilter::Week => "week",
+            TimeFilter::Month => "month",
+            TimeFilter::Year => "year",
+            TimeFilter::All => "all",
+        }
+    }
+}
+
+impl fmt::Display for TimeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TimeFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hour" => Ok(TimeFilter::Hour),
+            "day" => Ok(TimeFilter::Day),
+            "week" => Ok(TimeFilter::Week),
+            "month" => Ok(TimeFilter::Month),
+            "year" => Ok(TimeFilter::Year),
+            "all" => Ok(TimeFilter::All),
+            other => Err(format!(
+                "Unknown time filter '{}', expected one of: hour, day, week, month, year, all",
+                other
+            )),
+        }
+    }
+}
+
+/// Pagination and ordering parameters shared by Reddit listing endpoints
+#[derive(Debug, Clone, Default)]
+pub struct ListingParams {
+    /// Maximum number of items to return (Reddit caps this at 100)
+    pub limit: Option<u32>,
+    /// Fullname of the item to fetch results after
+    pub after: Option<String>,
+    /// Fullname of the item to fetch results before
+    pub before: Option<String>,
+    /// Number of items already seen in this listing
+    pub count: Option<u32>,
+    /// Sort order, for listings that support it
+    pub sort: Option<Sort>,
+    /// Time window, for `top` and `controversial` sorts
+    pub time: Option<TimeFilter>,
+}
+
+impl ListingParams {
+    /// Create parameters requesting a page of `limit` items
+    pub fn with_limit(limit: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    /// Convert the parameters into query string pairs
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("raw_json", "1".to_string())];
+
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.min(100).to_string()));
+        }
+        if let Some(after) = &self.after {
+            query.push(("after", after.clone()));
+        }
+        if let Some(before) = &self.before {
+            query.push(("before", before.clone()));
+        }
+        if let Some(count) = self.count {
+            query.push(("count", count.to_string()));
+        }
+        if let Some(sort) = self.sort {
+            query.push(("sort", sort.as_str().to_string()));
+        }
+        if let Some(time) = self.time {
+            query.push(("t", time.as_str().to_string()));
+        }
+
+        query
+    }
+}
@@ -0,0 +1,168 @@
+use super::listing::ListingParams;
+use super::{check_fullname, RedditClient, RedditClientError};
+use crate::models::listing::{Listing, Thing};
+use log::debug;
+use std::fmt;
+use std::str::FromStr;
+
+/// The folders available in the account inbox under `/message/`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InboxFolder {
+    /// All private messages, comment replies and mentions
+    Inbox,
+    /// Only items not yet marked as read
+    Unread,
+    /// Private messages we have sent
+    Sent,
+    /// Comments mentioning our username
+    Mentions,
+}
+
+impl InboxFolder {
+    /// The path segment for this folder under `/message/`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InboxFolder::Inbox => "inbox",
+            InboxFolder::Unread => "unread",
+            InboxFolder::Sent => "sent",
+            InboxFolder::Mentions => "mentions",
+        }
+    }
+}
+
+impl fmt::Display for InboxFolder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for InboxFolder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "inbox" | "all" => Ok(InboxFolder::Inbox),
+            "unread" => Ok(InboxFolder::Unread),
+            "sent" => Ok(InboxFolder::Sent),
+            "mentions" => Ok(InboxFolder::Mentions),
+            other => Err(format!(
+                "Unknown inbox folder '{}', expected one of: inbox, unread, sent, mentions",
+                other
+            )),
+        }
+    }
+}
+
+/// Inbox and private messaging.
+///
+/// All of these require user authentication with the 'privatemessages' scope.
+/// Replies to messages go through `create_comment` with the message's `t4_` fullname.
+impl RedditClient {
+    /// Fetch one page of an inbox folder
+    ///
+    /// # Arguments
+    /// * `folder` - Which folder to fetch (inbox, unread, sent, mentions)
+    /// * `params` - Pagination parameters
+    ///
+    /// # Returns
+    /// A listing of private messages (`t4`) and comment replies or mentions (`t1`)
+    pub async fn fetch_inbox(
+        &self,
+        folder: InboxFolder,
+        params: &ListingParams,
+    ) -> Result<Listing<Thing>, RedditClientError> {
+        if self.access_token.is_none() {
+            return Err(RedditClientError::ApiError(
+                "The inbox is private and requires user authentication".to_string(),
+            ));
+        }
+
+        let path = format!("/message/{}.json", folder);
+        let response: Listing<Thing> = self.get_json(&path, &params.to_query()).await?;
+        debug!(
+            "Fetched {} items from /message/{}",
+            response.data.children.len(),
+            folder
+        );
+
+        Ok(response)
+    }
+
+    /// Send a new private message
+    ///
+    /// # Arguments
+    /// * `to` - The recipient username (with or without `u/`), or `/r/{sub}` to message its moderators
+    /// * `subject` - The message subject (up to 100 characters)
+    /// * `body` - The message body in markdown
+    pub async fn compose(
+        &self,
+        to: &str,
+        subject: &str,
+        body: &str,
+    ) -> Result<(), RedditClientError> {
+        if subject.trim().is_empty() {
+            return Err(RedditClientError::ApiError(
+                "Message subject must not be empty".to_string(),
+            ));
+        }
+        if subject.chars().count() > 100 {
+            return Err(RedditClientError::ApiError(
+                "Message subject must be 100 characters or fewer".to_string(),
+            ));
+        }
+
+        let to = to
+            .strip_prefix("u/")
+            .or_else(|| to.strip_prefix("/u/"))
+            .unwrap_or(to);
+
+        self.post_form(
+            "/api/compose",
+            &[
+                ("api_type", "json"),
+                ("to", to),
+                ("subject", subject),
+                ("text", body),
+            ],
+        )
+        .await?;
+        debug!("Sent message to {}", to);
+
+        Ok(())
+    }
+
+    /// Mark messages or comment replies as read
+    ///
+    /// # Arguments
+    /// * `fullnames` - Fullnames of messages ("t4_...") or comment replies ("t1_...")
+    pub async fn read_message(&self, fullnames: &[&str]) -> Result<(), RedditClientError> {
+        self.set_message_read_state("/api/read_message", fullnames)
+            .await
+    }
+
+    /// Mark messages or comment replies as unread
+    ///
+    /// # Arguments
+    /// * `fullnames` - Fullnames of messages ("t4_...") or comment replies ("t1_...")
+    pub async fn unread_message(&self, fullnames: &[&str]) -> Result<(), RedditClientError> {
+        self.set_message_read_state("/api/unread_message", fullnames)
+            .await
+    }
+
+    // Internal helper method shared by read_message and unread_message
+    async fn set_message_read_state(
+        &self,
+        path: &str,
+        fullnames: &[&str],
+    ) -> Result<(), RedditClientError> {
+        for fullname in fullnames {
+            check_fullname(fullname, &["t1", "t4"])?;
+        }
+
+        let ids = fullnames.join(",");
+        self.post_form(path, &[("id", &ids)]).await?;
+        debug!("Called {} for {}", path, ids);
+
+        Ok(())
+    }
+}
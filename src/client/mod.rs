@@ -1,9 +1,40 @@
+pub mod actions;
+pub mod cache;
+pub mod cassette;
+pub mod comments;
+pub mod listing;
+pub mod messages;
+pub mod moderation;
+pub mod modmail;
+pub mod ratelimit;
+pub mod relationships;
+pub mod search;
+pub mod stream;
+pub mod subreddits;
+pub mod things;
+pub mod users;
+pub mod wiki;
+
+pub use actions::VoteDirection;
+pub use cache::{CacheMode, ResponseCache};
+pub use cassette::{Cassette, CassetteMode};
+pub use listing::{ListingParams, Sort, TimeFilter};
+pub use messages::InboxFolder;
+pub use moderation::{DistinguishKind, ModListing};
+pub use modmail::ModmailState;
+pub use ratelimit::RateLimiter;
+pub use relationships::{RelationshipDetails, RelationshipKind};
+pub use stream::{CommentStream, InboxStream, StreamConfig, SubmissionStream};
+pub use users::UserListing;
+
+use crate::models::listing::Listing;
 use crate::models::public_feed::PublicFeedResponse;
 use crate::models::subreddit_posts::SubredditPostsResponse;
 use crate::models::RedditRNewResponse;
 use log::{debug, info};
 use rand::{distributions::Alphanumeric, Rng};
 use reqwest::{Client, Error as ReqwestError};
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use std::fmt;
 use std::fs::{self, File};
@@ -91,16 +122,40 @@ pub struct RedditClient {
     pub access_token: Option<String>,
     pub user_agent: String,
     pub token_storage: Option<TokenStorage>,
+    /// Shared request pacing, set with `with_rate_limit`
+    pub rate_limiter: Option<std::sync::Arc<RateLimiter>>,
+    /// On-disk cache of GET responses, set with `with_cache`
+    pub cache: Option<std::sync::Arc<ResponseCache>>,
+    /// Fetch every GET from Reddit instead of serving cached responses, set with `bypassing_cache`
+    pub bypass_cache: bool,
+    /// Recorded HTTP traffic to record into or replay from, set with `with_cassette`
+    pub cassette: Option<std::sync::Arc<Cassette>>,
+    /// Server to send requests to instead of Reddit, set with `with_base_url`
+    pub base_url: Option<Url>,
+    /// Directory refreshed tokens are saved in instead of ~/.redrust, set with `with_token_dir`
+    pub token_dir: Option<PathBuf>,
+}
+
+impl Default for RedditClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl RedditClient {
     pub fn new() -> Self {
-        let user_agent = format!("redrust/1.0 (by /u/Aggravating-Fix-3871)");
+        let user_agent = "redrust/1.0 (by /u/Aggravating-Fix-3871)".to_string();
         Self {
             client: Self::get_client(&user_agent).unwrap(),
             access_token: None,
             user_agent,
             token_storage: None,
+            rate_limiter: None,
+            cache: None,
+            bypass_cache: false,
+            cassette: None,
+            base_url: None,
+            token_dir: None,
         }
     }
 
@@ -110,12 +165,21 @@ impl RedditClient {
             access_token: None,
             user_agent,
             token_storage: None,
+            rate_limiter: None,
+            cache: None,
+            bypass_cache: false,
+            cassette: None,
+            base_url: None,
+            token_dir: None,
         }
     }
 
     /// Create a client from a configuration object
     pub fn from_config(config: &crate::config::AppConfig) -> Self {
-        debug!("Creating RedditClient with user_agent: {}", config.user_agent);
+        debug!(
+            "Creating RedditClient with user_agent: {}",
+            config.user_agent
+        );
         let mut client = Self::with_user_agent(config.user_agent.clone());
 
         // Use client_id to load token storage if available
@@ -245,7 +309,13 @@ impl RedditClient {
     /// Save token storage to the filesystem
     fn save_token_storage(&self) -> Result<(), RedditClientError> {
         if let Some(storage) = &self.token_storage {
-            let token_path = Self::get_token_path(&storage.client_id);
+            let token_path = match &self.token_dir {
+                Some(dir) => {
+                    fs::create_dir_all(dir).ok();
+                    dir.join(format!("{}.json", storage.client_id))
+                }
+                None => Self::get_token_path(&storage.client_id),
+            };
 
             let json = serde_json::to_string_pretty(storage).map_err(|e| {
                 RedditClientError::ApiError(format!("Failed to serialize token storage: {}", e))
@@ -269,6 +339,256 @@ impl RedditClient {
         Ok(Client::builder().user_agent(user_agent).build()?)
     }
 
+    /// Send every request for www.reddit.com or oauth.reddit.com to another
+    /// server instead, e.g. a local fake Reddit in tests
+    pub fn with_base_url(mut self, base_url: Url) -> Self {
+        self.base_url = Some(base_url);
+        self
+    }
+
+    /// Save refreshed tokens in this directory instead of ~/.redrust
+    pub fn with_token_dir(mut self, token_dir: PathBuf) -> Self {
+        self.token_dir = Some(token_dir);
+        self
+    }
+
+    /// Base URL for API requests: the OAuth host when we have a token, the public site otherwise
+    pub(crate) fn api_base_url(&self) -> &'static str {
+        if self.access_token.is_some() {
+            "https://oauth.reddit.com"
+        } else {
+            "https://www.reddit.com"
+        }
+    }
+
+    /// Send a GET request to an API path and deserialize the JSON response.
+    ///
+    /// The path is appended to the OAuth or public base URL depending on whether
+    /// we have an access token, and the token is sent as a Bearer header if present.
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, RedditClientError> {
+        let url = format!("{}{}", self.api_base_url(), path);
+        debug!("Fetching from URL: {}", url);
+
+        let body = self.send_get(self.client.get(&url).query(query)).await?;
+
+        serde_json::from_str::<T>(&body).map_err(|e| {
+            debug!("Error parsing response from {}: {}", url, e);
+            debug!(
+                "First 100 chars: {}",
+                body.chars().take(100).collect::<String>()
+            );
+            RedditClientError::ParseError(e)
+        })
+    }
+
+    /// Send a GET request with our Bearer token, if any, and return the body.
+    ///
+    /// Successful responses are served from and saved to the response cache
+    /// when one is configured; clients from `bypassing_cache` only save them.
+    pub(crate) async fn send_get(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<String, RedditClientError> {
+        let request = match &self.access_token {
+            Some(token) => request.header("Authorization", format!("Bearer {}", token)),
+            None => request,
+        }
+        .build()?;
+        let url = request.url().to_string();
+
+        let identity = self.cache_identity(&request);
+        if let Some(cache) = self.cache.as_ref().filter(|_| !self.bypass_cache) {
+            if let Some(body) = cache.lookup(&identity, &url)? {
+                return Ok(body);
+            }
+        }
+
+        self.throttle().await;
+        let response = self.execute(request).await?;
+        self.record_rate_limit(response.headers()).await;
+        let status = response.status();
+        debug!("Response status: {}", status);
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(RedditClientError::ApiError(format!(
+                "Server returned error status: {}: {}",
+                status,
+                body.chars().take(200).collect::<String>()
+            )));
+        }
+
+        let body = response.text().await?;
+        debug!("Response body length: {} bytes", body.len());
+
+        if let Some(cache) = &self.cache {
+            cache.store(&identity, &url, &body);
+        }
+        Ok(body)
+    }
+
+    /// Build and send a request, through the cassette when one is set
+    pub(crate) async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, RedditClientError> {
+        self.execute(request.build()?).await
+    }
+
+    /// Send a built request, through the cassette when one is set.
+    ///
+    /// Every request the client makes goes through here, so this is also where
+    /// offline mode refuses writes, token refreshes and uncached reads.
+    pub(crate) async fn execute(
+        &self,
+        mut request: reqwest::Request,
+    ) -> Result<reqwest::Response, RedditClientError> {
+        if self
+            .cache
+            .as_ref()
+            .is_some_and(|cache| cache.mode() == CacheMode::Offline)
+        {
+            return Err(RedditClientError::ApiError(format!(
+                "Offline; not sending {} {}",
+                request.method(),
+                request.url()
+            )));
+        }
+
+        if let Some(base_url) = &self.base_url {
+            redirect_to(request.url_mut(), base_url);
+        }
+
+        match &self.cassette {
+            Some(cassette) => cassette.execute(&self.client, request).await,
+            None => Ok(self.client.execute(request).await?),
+        }
+    }
+
+    /// Send an authenticated form POST to an OAuth API path and return the JSON response.
+    ///
+    /// Requires an access token. Errors reported by Reddit in the `json.errors`
+    /// array are turned into an `ApiError`.
+    pub(crate) async fn post_form(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<serde_json::Value, RedditClientError> {
+        let request = self.authorized_request(reqwest::Method::POST, path)?;
+        self.send_authorized(path, request.form(params)).await
+    }
+
+    /// Send an authenticated POST with a JSON body to an OAuth API path.
+    ///
+    /// Used by the newer `/api/v1/` endpoints that don't accept form data.
+    pub(crate) async fn post_json(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<serde_json::Value, RedditClientError> {
+        let request = self.authorized_request(reqwest::Method::POST, path)?;
+        self.send_authorized(path, request.json(body)).await
+    }
+
+    /// Build a request to an OAuth API path with the Bearer token attached
+    pub(crate) fn authorized_request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+    ) -> Result<reqwest::RequestBuilder, RedditClientError> {
+        let token = self.access_token.as_ref().ok_or_else(|| {
+            RedditClientError::ApiError(
+                "No access token available. This action requires user authentication.".to_string(),
+            )
+        })?;
+
+        let url = format!("https://oauth.reddit.com{}", path);
+        debug!("Sending {} to URL: {}", method, url);
+
+        Ok(self
+            .client
+            .request(method, &url)
+            .header("Authorization", format!("Bearer {}", token)))
+    }
+
+    /// Send an authenticated request and check the response for HTTP and API errors
+    pub(crate) async fn send_authorized(
+        &self,
+        path: &str,
+        request: reqwest::RequestBuilder,
+    ) -> Result<serde_json::Value, RedditClientError> {
+        self.throttle().await;
+        let response = self.send(request).await?;
+        self.record_rate_limit(response.headers()).await;
+        let status = response.status();
+        debug!("Response status: {}", status);
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(RedditClientError::ApiError(format!(
+                "Request to {} failed: HTTP {}: {}",
+                path,
+                status,
+                body.chars().take(200).collect::<String>()
+            )));
+        }
+
+        // Some endpoints return an empty body on success
+        let body = response.text().await?;
+        if body.trim().is_empty() {
+            return Ok(serde_json::Value::Null);
+        }
+        let json: serde_json::Value = serde_json::from_str(&body)?;
+
+        if let Some(errors) = json["json"]["errors"].as_array() {
+            if !errors.is_empty() {
+                return Err(RedditClientError::ApiError(format!(
+                    "Reddit API returned an error: {:?}",
+                    errors
+                )));
+            }
+        }
+
+        Ok(json)
+    }
+
+    /// Make sure we have a usable access token, refreshing it from stored tokens if needed.
+    ///
+    /// This never opens a browser; use `authenticate_with_stored_or_browser` for
+    /// interactive authentication.
+    pub async fn ensure_access_token(&mut self) -> Result<String, RedditClientError> {
+        let stored_token_valid = self
+            .token_storage
+            .as_ref()
+            .is_none_or(|storage| storage.is_access_token_valid());
+
+        if let Some(token) = &self.access_token {
+            if stored_token_valid {
+                return Ok(token.clone());
+            }
+        }
+
+        if self
+            .token_storage
+            .as_ref()
+            .is_some_and(|storage| storage.has_refresh_token())
+        {
+            debug!("Access token missing or expired, refreshing from stored refresh token");
+            return self.refresh_access_token().await;
+        }
+
+        // Fall back to whatever token we were given directly
+        self.access_token.clone().ok_or_else(|| {
+            RedditClientError::ApiError(
+                "No access token available. Authenticate with browser-create first or set REDDIT_ACCESS_TOKEN.".to_string(),
+            )
+        })
+    }
+
     /// Get an application-only access token for reading public data.
     ///
     /// This method gets a token that can only be used for reading public data.
@@ -287,11 +607,12 @@ impl RedditClient {
         let auth = base64::encode(format!("{}:", client_id));
 
         let res = self
-            .client
-            .post("https://www.reddit.com/api/v1/access_token")
-            .header("Authorization", format!("Basic {}", auth))
-            .form(&params)
-            .send()
+            .send(
+                self.client
+                    .post("https://www.reddit.com/api/v1/access_token")
+                    .header("Authorization", format!("Basic {}", auth))
+                    .form(&params),
+            )
             .await?;
 
         let json: serde_json::Value = res.json().await?;
@@ -311,43 +632,6 @@ impl RedditClient {
         Ok(token)
     }
 
-    /// Authenticate with Reddit using username and password (password flow).
-    ///
-    /// This method gets a user-specific token that can be used for actions like posting,
-    /// commenting, voting, and other operations that require a user account.
-    ///
-    /// # Arguments
-    /// * `client_id` - Your Reddit API client ID
-    /// * `username` - Reddit username
-    /// * `password` - Reddit password
-    ///
-    /// # Note
-    /// Your Reddit application must be set up as a "script" type app for this to work.
-    /// The scope "submit" is included to allow posting.
-    ///
-    /// # Important
-    /// This method doesn't work with Reddit accounts that use Google OAuth or other
-    /// third-party login methods. For those, use authenticate_with_api_credentials() instead.
-
-    /// Authenticate with Reddit using the interactive browser OAuth flow.
-    ///
-    /// This method works with any Reddit account, including those using Google OAuth.
-    /// It will open a web browser where the user can log in with their normal method
-    /// and authorize the application.
-    ///
-    /// # Arguments
-    /// * `client_id` - Your Reddit API client ID for an installed app
-    /// * `redirect_port` - The port to use for the localhost redirect (default: 8080)
-    /// * `scopes` - The permissions to request (default includes read and submit)
-    ///
-    /// # Returns
-    /// A Result containing the access token if successful
-    ///
-    /// # How this works:
-    /// 1. Starts a local web server on localhost to receive the OAuth callback
-    /// 2. Opens a browser for the user to log in and authorize the app
-    /// 3. Reddit redirects back to localhost with an authorization code
-    /// 4. Exchanges this code for an access token
     /// Try to refresh the access token using a stored refresh token
     pub async fn refresh_access_token(&mut self) -> Result<String, RedditClientError> {
         let storage = match &self.token_storage {
@@ -373,11 +657,12 @@ impl RedditClient {
         let auth = base64::encode(format!("{}:", client_id));
 
         let res = self
-            .client
-            .post("https://www.reddit.com/api/v1/access_token")
-            .header("Authorization", format!("Basic {}", auth))
-            .form(&params)
-            .send()
+            .send(
+                self.client
+                    .post("https://www.reddit.com/api/v1/access_token")
+                    .header("Authorization", format!("Basic {}", auth))
+                    .form(&params),
+            )
             .await?;
 
         // Check for HTTP errors
@@ -476,6 +761,25 @@ impl RedditClient {
             .await
     }
 
+    /// Authenticate with Reddit using the interactive browser OAuth flow.
+    ///
+    /// This method works with any Reddit account, including those using Google OAuth.
+    /// It will open a web browser where the user can log in with their normal method
+    /// and authorize the application.
+    ///
+    /// # Arguments
+    /// * `client_id` - Your Reddit API client ID for an installed app
+    /// * `redirect_port` - The port to use for the localhost redirect (default: 8080)
+    /// * `scopes` - The permissions to request (default includes read and submit)
+    ///
+    /// # Returns
+    /// A Result containing the access token if successful
+    ///
+    /// # How this works:
+    /// 1. Starts a local web server on localhost to receive the OAuth callback
+    /// 2. Opens a browser for the user to log in and authorize the app
+    /// 3. Reddit redirects back to localhost with an authorization code
+    /// 4. Exchanges this code for an access token
     pub async fn authenticate_with_browser_oauth(
         &mut self,
         client_id: &str,
@@ -484,7 +788,9 @@ impl RedditClient {
     ) -> Result<String, RedditClientError> {
         // Setup parameters
         let port = redirect_port.unwrap_or(8080);
-        let scopes = scopes.unwrap_or("identity read submit");
+        let scopes = scopes.unwrap_or(
+            "identity read submit history vote save report edit modposts modflair privatemessages modmail modcontributors modothers modwiki wikiread wikiedit",
+        );
         let redirect_uri = format!("http://localhost:{}/callback", port);
 
         // Generate a random state token to prevent CSRF
@@ -677,11 +983,12 @@ impl RedditClient {
         let auth = base64::encode(format!("{}:", client_id));
 
         let res = self
-            .client
-            .post("https://www.reddit.com/api/v1/access_token")
-            .header("Authorization", format!("Basic {}", auth))
-            .form(&params)
-            .send()
+            .send(
+                self.client
+                    .post("https://www.reddit.com/api/v1/access_token")
+                    .header("Authorization", format!("Basic {}", auth))
+                    .form(&params),
+            )
             .await?;
 
         // Check for HTTP errors
@@ -795,11 +1102,12 @@ impl RedditClient {
         let auth = base64::encode(format!("{}:{}", client_id, client_secret));
 
         let res = self
-            .client
-            .post("https://www.reddit.com/api/v1/access_token")
-            .header("Authorization", format!("Basic {}", auth))
-            .form(&params)
-            .send()
+            .send(
+                self.client
+                    .post("https://www.reddit.com/api/v1/access_token")
+                    .header("Authorization", format!("Basic {}", auth))
+                    .form(&params),
+            )
             .await?;
 
         // Check for HTTP errors
@@ -841,6 +1149,23 @@ impl RedditClient {
         Ok(token)
     }
 
+    /// Authenticate with Reddit using username and password (password flow).
+    ///
+    /// This method gets a user-specific token that can be used for actions like posting,
+    /// commenting, voting, and other operations that require a user account.
+    ///
+    /// # Arguments
+    /// * `client_id` - Your Reddit API client ID
+    /// * `username` - Reddit username
+    /// * `password` - Reddit password
+    ///
+    /// # Note
+    /// Your Reddit application must be set up as a "script" type app for this to work.
+    /// The scope "submit" is included to allow posting.
+    ///
+    /// # Important
+    /// This method doesn't work with Reddit accounts that use Google OAuth or other
+    /// third-party login methods. For those, use authenticate_with_api_credentials() instead.
     pub async fn authenticate_user(
         &mut self,
         client_id: &str,
@@ -860,11 +1185,12 @@ impl RedditClient {
         let auth = base64::encode(format!("{}:", client_id));
 
         let res = self
-            .client
-            .post("https://www.reddit.com/api/v1/access_token")
-            .header("Authorization", format!("Basic {}", auth))
-            .form(&params)
-            .send()
+            .send(
+                self.client
+                    .post("https://www.reddit.com/api/v1/access_token")
+                    .header("Authorization", format!("Basic {}", auth))
+                    .form(&params),
+            )
             .await?;
 
         // Check for HTTP errors
@@ -911,6 +1237,19 @@ impl RedditClient {
         &self,
         subreddit: &str,
         limit: i32,
+    ) -> Result<RedditRNewResponse, RedditClientError> {
+        self.fetch_new_posts_after(subreddit, limit, None).await
+    }
+
+    /// Fetch a page of new posts from a specific subreddit
+    ///
+    /// # Arguments
+    /// * `after` - Fullname of the last post on the previous page, for pagination
+    pub async fn fetch_new_posts_after(
+        &self,
+        subreddit: &str,
+        limit: i32,
+        after: Option<&str>,
     ) -> Result<RedditRNewResponse, RedditClientError> {
         // Check if we have an access token and use OAuth endpoint if we do
         let base_url = if self.access_token.is_some() {
@@ -920,37 +1259,16 @@ impl RedditClient {
             debug!("Using public API endpoint (no access token)");
             "https://www.reddit.com/r"
         };
-        
-        let url = format!(
-            "{}/{}/new.json?limit={}",
-            base_url, subreddit, limit
-        );
-        debug!("Fetching from subreddit URL: {}", url);
-        debug!("Using User-Agent: {}", self.user_agent);
-        
-        // Create request builder
-        let mut req_builder = self.client.get(&url);
-        
-        // Add authorization header if we have a token
-        if let Some(token) = &self.access_token {
-            debug!("Adding Authorization header with token");
-            req_builder = req_builder.header("Authorization", format!("Bearer {}", token));
-        }
-        
-        // Send the request
-        let response = req_builder.send().await?;
-        let status = response.status();
-        debug!("Response status: {}", status);
 
-        if !status.is_success() {
-            return Err(RedditClientError::ApiError(format!(
-                "Server returned error status: {}",
-                status
-            )));
+        let mut url = format!("{}/{}/new.json?limit={}", base_url, subreddit, limit);
+        if let Some(after) = after {
+            url.push_str(&format!("&after={}", after));
         }
+        debug!("Fetching from subreddit URL: {}", url);
+        debug!("Using User-Agent: {}", self.user_agent);
 
-        let body = response.text().await?;
-        debug!("Response body length: {} bytes", body.len());
+        // Send the request, with our token if we have one
+        let body = self.send_get(self.client.get(&url)).await?;
 
         // Parse using our specialized SubredditPostsResponse model
         let parsed = match serde_json::from_str::<SubredditPostsResponse>(&body) {
@@ -1040,6 +1358,9 @@ impl RedditClient {
                                 pinned: post.data.pinned,
                                 spoiler: post.data.spoiler,
                                 over_18: post.data.over_18,
+                                num_reports: None,
+                                user_reports: Vec::new(),
+                                mod_reports: Vec::new(),
                                 hide_score: post.data.hide_score,
                                 contest_mode: post.data.contest_mode,
                                 edited: post.data.edited.clone(),
@@ -1065,11 +1386,45 @@ impl RedditClient {
         Ok(result)
     }
 
+    /// Fetch a page of a subreddit's new posts as Reddit sent them, for callers
+    /// that keep the original JSON rather than the typed post
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `params` - Pagination parameters
+    pub async fn fetch_new_posts_json(
+        &self,
+        subreddit: &str,
+        params: &ListingParams,
+    ) -> Result<Listing<serde_json::Value>, RedditClientError> {
+        let path = format!(
+            "/r/{}/new.json",
+            subreddits::clean_subreddit_name(subreddit)
+        );
+        self.get_json(&path, &params.to_query()).await
+    }
+
     /// Fetch new posts from the public Reddit frontpage
     pub async fn fetch_public_new_posts(
         &self,
         limit: i32,
     ) -> Result<RedditRNewResponse, RedditClientError> {
+        self.fetch_public_new_posts_after(limit, None).await
+    }
+
+    /// Fetch a page of new posts from the public Reddit frontpage
+    ///
+    /// # Arguments
+    /// * `after` - Fullname of the last post on the previous page, for pagination
+    pub async fn fetch_public_new_posts_after(
+        &self,
+        limit: i32,
+        after: Option<&str>,
+    ) -> Result<RedditRNewResponse, RedditClientError> {
+        let after_param = after
+            .map(|after| format!("&after={}", after))
+            .unwrap_or_default();
+
         // Check if we have an access token and use OAuth endpoint if we do
         let base_url = if self.access_token.is_some() {
             debug!("Using OAuth API endpoint with access token");
@@ -1078,51 +1433,32 @@ impl RedditClient {
             debug!("Using public API endpoint (no access token)");
             "https://www.reddit.com"
         };
-        
+
         // Using the URL that shows new posts on the main feed
-        let url = format!("{}/new.json?feed=home&limit={}", base_url, limit);
+        let url = format!(
+            "{}/new.json?feed=home&limit={}{}",
+            base_url, limit, after_param
+        );
         debug!("Fetching from URL: {}", url);
         debug!("Using User-Agent: {}", self.user_agent);
-        
-        // Create request builder
-        let mut req_builder = self.client.get(&url);
-        
-        // Add authorization header if we have a token
-        if let Some(token) = &self.access_token {
-            debug!("Adding Authorization header with token");
-            req_builder = req_builder.header("Authorization", format!("Bearer {}", token));
-        }
 
         // Try to get a response from this endpoint
-        let response = match req_builder.send().await {
-            Ok(resp) => resp,
-            Err(e) => {
+        let body = match self.send_get(self.client.get(&url)).await {
+            Ok(body) => body,
+            Err(RedditClientError::RequestError(e)) => {
                 debug!("Error fetching {}: {:?}", url, e);
                 // If this fails, fall back to r/popular/new
-                let fallback_url = format!("{}/r/popular/new.json?limit={}", base_url, limit);
+                let fallback_url = format!(
+                    "{}/r/popular/new.json?limit={}{}",
+                    base_url, limit, after_param
+                );
                 debug!("Falling back to URL: {}", fallback_url);
-                
-                let mut fallback_req = self.client.get(&fallback_url);
-                if let Some(token) = &self.access_token {
-                    fallback_req = fallback_req.header("Authorization", format!("Bearer {}", token));
-                }
-                fallback_req.send().await?
+
+                self.send_get(self.client.get(&fallback_url)).await?
             }
+            Err(err) => return Err(err),
         };
 
-        let status = response.status();
-        debug!("Response status: {}", status);
-
-        if !status.is_success() {
-            return Err(RedditClientError::ApiError(format!(
-                "Server returned error status: {}",
-                status
-            )));
-        }
-
-        let body = response.text().await?;
-        debug!("Response body length: {} bytes", body.len());
-
         // Parse using our more forgiving PublicFeedResponse model
         let parsed = match serde_json::from_str::<PublicFeedResponse>(&body) {
             Ok(parsed) => parsed,
@@ -1151,83 +1487,9 @@ impl RedditClient {
                     .data
                     .children
                     .into_iter()
-                    .map(|post| {
-                        // Convert public feed post to regular post
-                        crate::models::RedditPostEntity {
-                            kind: post.kind,
-                            data: crate::models::RedditPostData {
-                                id: post.data.id,
-                                name: post.data.name,
-                                title: post.data.title,
-                                author: post.data.author,
-                                author_fullname: None,
-                                permalink: post.data.permalink,
-                                url: post.data.url,
-                                created_utc: post.data.created_utc,
-                                is_self: post.data.is_self,
-                                selftext: post.data.selftext,
-                                selftext_html: post.data.selftext_html,
-                                is_video: post.data.is_video,
-                                is_original_content: post.data.is_original_content,
-                                is_reddit_media_domain: post.data.is_reddit_media_domain,
-                                is_meta: post.data.is_meta,
-                                is_crosspostable: post.data.is_crosspostable,
-                                thumbnail: post.data.thumbnail,
-                                thumbnail_width: post.data.thumbnail_width,
-                                thumbnail_height: post.data.thumbnail_height,
-                                secure_media: None,
-                                secure_media_embed: crate::models::RedditMediaEmbed {
-                                    content: None,
-                                    width: None,
-                                    height: None,
-                                },
-                                media: None,
-                                media_embed: crate::models::RedditMediaEmbed {
-                                    content: None,
-                                    width: None,
-                                    height: None,
-                                },
-                                preview: None,
-                                gallery_data: None,
-                                media_metadata: None,
-                                score: post.data.score,
-                                upvote_ratio: post.data.upvote_ratio,
-                                ups: post.data.ups,
-                                downs: post.data.downs,
-                                num_comments: post.data.num_comments,
-                                num_crossposts: post.data.num_crossposts,
-                                total_awards_received: post.data.total_awards_received,
-                                subreddit: post.data.subreddit,
-                                subreddit_id: post.data.subreddit_id,
-                                subreddit_subscribers: post.data.subreddit_subscribers,
-                                subreddit_type: post.data.subreddit_type,
-                                subreddit_name_prefixed: post.data.subreddit_name_prefixed,
-                                archived: post.data.archived,
-                                locked: post.data.locked,
-                                hidden: post.data.hidden,
-                                removed_by_category: post.data.removed_by_category,
-                                removed_by: post.data.removed_by,
-                                stickied: post.data.stickied,
-                                pinned: post.data.pinned,
-                                spoiler: post.data.spoiler,
-                                over_18: post.data.over_18,
-                                hide_score: post.data.hide_score,
-                                contest_mode: post.data.contest_mode,
-                                edited: post.data.edited.clone(),
-                                distinguished: post.data.distinguished,
-                                link_flair_text: post.data.link_flair_text,
-                                link_flair_type: post.data.link_flair_type,
-                                link_flair_background_color: post.data.link_flair_background_color,
-                                link_flair_text_color: post.data.link_flair_text_color,
-                                author_flair_text: post.data.author_flair_text,
-                                author_flair_type: post.data.author_flair_type,
-                                author_flair_background_color: post
-                                    .data
-                                    .author_flair_background_color,
-                                author_flair_text_color: post.data.author_flair_text_color,
-                                additional_fields: post.data.additional_fields,
-                            },
-                        }
+                    .map(|post| crate::models::RedditPostEntity {
+                        kind: post.kind,
+                        data: post.data.into(),
                     })
                     .collect(),
             },
@@ -1265,11 +1527,7 @@ impl RedditClient {
         };
 
         // Clean up the subreddit name - remove r/ if it's there
-        let subreddit_clean = if subreddit.starts_with("r/") {
-            &subreddit[2..]
-        } else {
-            subreddit
-        };
+        let subreddit_clean = subreddits::clean_subreddit_name(subreddit);
 
         let mut params = HashMap::new();
         params.insert("sr", subreddit_clean);
@@ -1279,14 +1537,17 @@ impl RedditClient {
 
         let url = "https://oauth.reddit.com/api/submit";
 
+        self.throttle().await;
         let response = self
-            .client
-            .post(url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .form(&params)
-            .send()
+            .send(
+                self.client
+                    .post(url)
+                    .header("Authorization", format!("Bearer {}", token))
+                    .header("Content-Type", "application/x-www-form-urlencoded")
+                    .form(&params),
+            )
             .await?;
+        self.record_rate_limit(response.headers()).await;
 
         // Check if request was successful
         if !response.status().is_success() {
@@ -1308,7 +1569,7 @@ impl RedditClient {
             if let Some(jquery) = json["jquery"].as_array() {
                 for item in jquery {
                     if let Some(call_args) = item[3].as_array() {
-                        if call_args.len() > 0
+                        if !call_args.is_empty()
                             && call_args[0].as_str() == Some(".error.USER_REQUIRED")
                         {
                             return Err(RedditClientError::ApiError(
@@ -1320,7 +1581,7 @@ impl RedditClient {
                     // Extract error message if present
                     if item[2].as_str() == Some("call") {
                         if let Some(call_args) = item[3].as_array() {
-                            if call_args.len() > 0 {
+                            if !call_args.is_empty() {
                                 if let Some(err_msg) = call_args[0].as_str() {
                                     if err_msg.starts_with("Please") || err_msg.contains("error") {
                                         return Err(RedditClientError::ApiError(format!(
@@ -1358,7 +1619,7 @@ impl RedditClient {
                         if next_index < jquery.len()
                             && jquery[next_index][2].as_str() == Some("call")
                             && jquery[next_index][3].as_array().is_some()
-                            && jquery[next_index][3].as_array().unwrap().len() > 0
+                            && !jquery[next_index][3].as_array().unwrap().is_empty()
                         {
                             if let Some(url) = jquery[next_index][3][0].as_str() {
                                 return Ok(url.to_string());
@@ -1391,11 +1652,12 @@ impl RedditClient {
         ))
     }
 
-    /// Create a comment on a post or another comment.
+    /// Create a comment on a post or another comment, or reply to a private message.
     ///
     /// # Arguments
-    /// * `thing_id` - The fullname of the parent thing (post or comment) to comment on
-    ///   Format is "t3_" followed by post ID for posts, or "t1_" followed by comment ID for comments
+    /// * `thing_id` - The fullname of the parent thing (post, comment or message) to reply to
+    ///   Format is "t3_" followed by post ID for posts, "t1_" followed by comment ID for comments,
+    ///   or "t4_" followed by message ID for private messages
     /// * `text` - The comment text content
     ///
     /// # Note
@@ -1423,14 +1685,17 @@ impl RedditClient {
 
         let url = "https://oauth.reddit.com/api/comment";
 
+        self.throttle().await;
         let response = self
-            .client
-            .post(url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .form(&params)
-            .send()
+            .send(
+                self.client
+                    .post(url)
+                    .header("Authorization", format!("Bearer {}", token))
+                    .header("Content-Type", "application/x-www-form-urlencoded")
+                    .form(&params),
+            )
             .await?;
+        self.record_rate_limit(response.headers()).await;
 
         // Check if request was successful
         if !response.status().is_success() {
@@ -1493,3 +1758,43 @@ impl RedditClient {
         Ok("Comment was created successfully, but couldn't extract the details".to_string())
     }
 }
+
+/// Check that a fullname has one of the expected kind prefixes (e.g. "t1", "t3")
+///
+/// # Arguments
+/// * `fullname` - The fullname to check, such as "t3_abcdef"
+/// * `kinds` - The accepted kind prefixes, without the trailing underscore
+pub fn check_fullname(fullname: &str, kinds: &[&str]) -> Result<(), RedditClientError> {
+    let valid = fullname.split_once('_').is_some_and(|(kind, id)| {
+        kinds.contains(&kind) && !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric())
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(RedditClientError::ApiError(format!(
+            "Invalid ID '{}': expected a fullname starting with {}",
+            fullname,
+            kinds
+                .iter()
+                .map(|kind| format!("{}_", kind))
+                .collect::<Vec<_>>()
+                .join(" or ")
+        )))
+    }
+}
+
+/// Point a Reddit URL at another server, keeping its path and query
+fn redirect_to(url: &mut Url, base_url: &Url) {
+    if !url
+        .host_str()
+        .is_some_and(|host| host.ends_with("reddit.com"))
+    {
+        return;
+    }
+    // Setting the scheme fails only between special and non-special schemes,
+    // and http and https are both special
+    let _ = url.set_scheme(base_url.scheme());
+    let _ = url.set_host(base_url.host_str());
+    let _ = url.set_port(base_url.port());
+}
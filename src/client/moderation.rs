@@ -0,0 +1,349 @@
+use super::listing::ListingParams;
+use super::subreddits::clean_subreddit_name;
+use super::{check_fullname, RedditClient, RedditClientError};
+use crate::models::listing::{Listing, Thing};
+use log::debug;
+use std::fmt;
+use std::str::FromStr;
+
+/// How a post or comment should be distinguished
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistinguishKind {
+    /// Distinguish as a moderator
+    Moderator,
+    /// Remove an existing distinguish
+    None,
+    /// Distinguish as an admin (admins only)
+    Admin,
+    /// Distinguish with a special status (admins only)
+    Special,
+}
+
+impl DistinguishKind {
+    /// The value Reddit expects in the `how` parameter
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            DistinguishKind::Moderator => "yes",
+            DistinguishKind::None => "no",
+            DistinguishKind::Admin => "admin",
+            DistinguishKind::Special => "special",
+        }
+    }
+}
+
+impl fmt::Display for DistinguishKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            DistinguishKind::Moderator => "moderator",
+            DistinguishKind::None => "none",
+            DistinguishKind::Admin => "admin",
+            DistinguishKind::Special => "special",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for DistinguishKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "moderator" | "mod" | "yes" => Ok(DistinguishKind::Moderator),
+            "none" | "no" => Ok(DistinguishKind::None),
+            "admin" => Ok(DistinguishKind::Admin),
+            "special" => Ok(DistinguishKind::Special),
+            other => Err(format!(
+                "Unknown distinguish kind '{}', expected one of: moderator, none, admin, special",
+                other
+            )),
+        }
+    }
+}
+
+/// The moderation listings available under `/r/{sub}/about/`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModListing {
+    /// Items awaiting review: reported, filtered or caught as spam
+    Modqueue,
+    /// Items with at least one report
+    Reports,
+    /// Items removed as spam
+    Spam,
+    /// Items edited after posting
+    Edited,
+    /// Posts no moderator has approved or removed yet
+    Unmoderated,
+}
+
+impl ModListing {
+    /// The path segment for this listing under `/r/{sub}/about/`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModListing::Modqueue => "modqueue",
+            ModListing::Reports => "reports",
+            ModListing::Spam => "spam",
+            ModListing::Edited => "edited",
+            ModListing::Unmoderated => "unmoderated",
+        }
+    }
+}
+
+impl fmt::Display for ModListing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ModListing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "modqueue" | "queue" => Ok(ModListing::Modqueue),
+            "reports" => Ok(ModListing::Reports),
+            "spam" => Ok(ModListing::Spam),
+            "edited" => Ok(ModListing::Edited),
+            "unmoderated" => Ok(ModListing::Unmoderated),
+            other => Err(format!(
+                "Unknown moderation listing '{}', expected one of: modqueue, reports, spam, edited, unmoderated",
+                other
+            )),
+        }
+    }
+}
+
+/// Moderator actions on posts and comments.
+///
+/// All of these require moderator permissions in the thing's subreddit and
+/// user authentication with the 'modposts' scope.
+impl RedditClient {
+    /// Approve a post or comment, restoring it if it was removed or filtered
+    pub async fn approve(&self, fullname: &str) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        self.post_form("/api/approve", &[("id", fullname)]).await?;
+        debug!("Approved {}", fullname);
+
+        Ok(())
+    }
+
+    /// Remove a post or comment, optionally marking it as spam
+    ///
+    /// # Arguments
+    /// * `fullname` - The fullname of the post ("t3_...") or comment ("t1_...")
+    /// * `spam` - Whether to also train the subreddit's spam filter on this item
+    pub async fn remove(&self, fullname: &str, spam: bool) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        let spam = if spam { "true" } else { "false" };
+        self.post_form("/api/remove", &[("id", fullname), ("spam", spam)])
+            .await?;
+        debug!("Removed {} (spam: {})", fullname, spam);
+
+        Ok(())
+    }
+
+    /// Attach a removal reason and/or private mod note to removed posts or comments
+    ///
+    /// # Arguments
+    /// * `fullnames` - The removed items
+    /// * `reason_id` - The ID of one of the subreddit's configured removal reasons
+    /// * `mod_note` - A short note visible only to moderators
+    pub async fn set_removal_reason(
+        &self,
+        fullnames: &[&str],
+        reason_id: Option<&str>,
+        mod_note: Option<&str>,
+    ) -> Result<(), RedditClientError> {
+        for fullname in fullnames {
+            check_fullname(fullname, &["t1", "t3"])?;
+        }
+
+        let body = serde_json::json!({
+            "item_ids": fullnames,
+            "reason_id": reason_id,
+            "mod_note": mod_note,
+        });
+        self.post_json("/api/v1/modactions/removal_reasons", &body)
+            .await?;
+        debug!("Set removal reason on {} item(s)", fullnames.len());
+
+        Ok(())
+    }
+
+    /// Lock or unlock a post or comment so no new replies can be made
+    pub async fn set_locked(&self, fullname: &str, locked: bool) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        let path = if locked { "/api/lock" } else { "/api/unlock" };
+        self.post_form(path, &[("id", fullname)]).await?;
+        debug!("Set locked={} on {}", locked, fullname);
+
+        Ok(())
+    }
+
+    /// Sticky or unsticky a post at the top of its subreddit
+    ///
+    /// # Arguments
+    /// * `fullname` - The fullname of the post ("t3_...")
+    /// * `state` - Whether the post should be stickied
+    /// * `slot` - Which sticky slot to use (1-4); Reddit picks the bottom slot when omitted
+    pub async fn set_sticky(
+        &self,
+        fullname: &str,
+        state: bool,
+        slot: Option<u8>,
+    ) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t3"])?;
+
+        let state = if state { "true" } else { "false" };
+        let slot = slot.map(|slot| slot.to_string());
+        let mut params = vec![("api_type", "json"), ("id", fullname), ("state", state)];
+        if let Some(slot) = &slot {
+            params.push(("num", slot));
+        }
+
+        self.post_form("/api/set_subreddit_sticky", &params).await?;
+        debug!("Set sticky={} on {}", state, fullname);
+
+        Ok(())
+    }
+
+    /// Distinguish a post or comment, optionally stickying a top-level comment
+    ///
+    /// # Arguments
+    /// * `fullname` - The fullname of the post ("t3_...") or comment ("t1_...")
+    /// * `how` - How to distinguish the item
+    /// * `sticky` - For top-level comments, also sticky the comment to the top of the thread
+    pub async fn distinguish(
+        &self,
+        fullname: &str,
+        how: DistinguishKind,
+        sticky: bool,
+    ) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        let sticky = if sticky { "true" } else { "false" };
+        self.post_form(
+            "/api/distinguish",
+            &[
+                ("api_type", "json"),
+                ("id", fullname),
+                ("how", how.as_param()),
+                ("sticky", sticky),
+            ],
+        )
+        .await?;
+        debug!("Distinguished {} as {}", fullname, how);
+
+        Ok(())
+    }
+
+    /// Mark or unmark a post as NSFW
+    pub async fn set_nsfw(&self, fullname: &str, nsfw: bool) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t3"])?;
+
+        let path = if nsfw {
+            "/api/marknsfw"
+        } else {
+            "/api/unmarknsfw"
+        };
+        self.post_form(path, &[("id", fullname)]).await?;
+        debug!("Set nsfw={} on {}", nsfw, fullname);
+
+        Ok(())
+    }
+
+    /// Mark or unmark a post as a spoiler
+    pub async fn set_spoiler(
+        &self,
+        fullname: &str,
+        spoiler: bool,
+    ) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t3"])?;
+
+        let path = if spoiler {
+            "/api/spoiler"
+        } else {
+            "/api/unspoiler"
+        };
+        self.post_form(path, &[("id", fullname)]).await?;
+        debug!("Set spoiler={} on {}", spoiler, fullname);
+
+        Ok(())
+    }
+
+    /// Set the flair on a post, by template, by text, or both
+    ///
+    /// Requires the 'modflair' scope, or 'flair' when changing the flair on our own post.
+    ///
+    /// # Arguments
+    /// * `fullname` - The fullname of the post ("t3_...")
+    /// * `subreddit` - The post's subreddit, with or without the `r/` prefix
+    /// * `text` - The flair text; overrides the template's text when both are given
+    /// * `template_id` - The ID of one of the subreddit's link flair templates
+    pub async fn set_link_flair(
+        &self,
+        fullname: &str,
+        subreddit: &str,
+        text: Option<&str>,
+        template_id: Option<&str>,
+    ) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t3"])?;
+        if text.is_none() && template_id.is_none() {
+            return Err(RedditClientError::ApiError(
+                "Set flair needs a flair text, a template ID, or both".to_string(),
+            ));
+        }
+
+        let mut params = vec![("api_type", "json"), ("link", fullname)];
+        if let Some(text) = text {
+            params.push(("text", text));
+        }
+        if let Some(template_id) = template_id {
+            params.push(("flair_template_id", template_id));
+        }
+
+        let path = format!("/r/{}/api/selectflair", clean_subreddit_name(subreddit));
+        self.post_form(&path, &params).await?;
+        debug!("Set flair on {}", fullname);
+
+        Ok(())
+    }
+
+    /// Fetch one page of a subreddit's moderation listing
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `listing` - Which listing to fetch (modqueue, reports, spam, ...)
+    /// * `params` - Pagination parameters
+    ///
+    /// # Returns
+    /// A listing of mixed posts and comments with their reports decoded
+    pub async fn fetch_mod_listing(
+        &self,
+        subreddit: &str,
+        listing: ModListing,
+        params: &ListingParams,
+    ) -> Result<Listing<Thing>, RedditClientError> {
+        if self.access_token.is_none() {
+            return Err(RedditClientError::ApiError(format!(
+                "The '{}' listing is only visible to moderators and requires user authentication",
+                listing
+            )));
+        }
+
+        let subreddit = clean_subreddit_name(subreddit);
+        let path = format!("/r/{}/about/{}.json", subreddit, listing);
+
+        let response: Listing<Thing> = self.get_json(&path, &params.to_query()).await?;
+        debug!(
+            "Fetched {} items from r/{}/about/{}",
+            response.data.children.len(),
+            subreddit,
+            listing
+        );
+
+        Ok(response)
+    }
+}
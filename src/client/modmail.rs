@@ -0,0 +1,275 @@
+use super::subreddits::clean_subreddit_name;
+use super::{RedditClient, RedditClientError};
+use crate::models::modmail::{ModmailConversationResponse, ModmailConversationsResponse};
+use log::debug;
+use std::fmt;
+use std::str::FromStr;
+
+/// The states new modmail conversations can be filtered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModmailState {
+    All,
+    New,
+    InProgress,
+    Archived,
+    Highlighted,
+    Mod,
+    Notifications,
+    JoinRequests,
+    Appeals,
+}
+
+impl ModmailState {
+    /// The value Reddit expects in the `state` parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModmailState::All => "all",
+            ModmailState::New => "new",
+            ModmailState::InProgress => "inprogress",
+            ModmailState::Archived => "archived",
+            ModmailState::Highlighted => "highlighted",
+            ModmailState::Mod => "mod",
+            ModmailState::Notifications => "notifications",
+            ModmailState::JoinRequests => "join_requests",
+            ModmailState::Appeals => "appeals",
+        }
+    }
+}
+
+impl fmt::Display for ModmailState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ModmailState {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "all" => Ok(ModmailState::All),
+            "new" => Ok(ModmailState::New),
+            "inprogress" | "in_progress" => Ok(ModmailState::InProgress),
+            "archived" => Ok(ModmailState::Archived),
+            "highlighted" => Ok(ModmailState::Highlighted),
+            "mod" => Ok(ModmailState::Mod),
+            "notifications" => Ok(ModmailState::Notifications),
+            "join_requests" => Ok(ModmailState::JoinRequests),
+            "appeals" => Ok(ModmailState::Appeals),
+            other => Err(format!(
+                "Unknown modmail state '{}', expected one of: all, new, inprogress, archived, highlighted, mod, notifications, join_requests, appeals",
+                other
+            )),
+        }
+    }
+}
+
+/// New modmail.
+///
+/// All of these require moderator permissions with mail access and user
+/// authentication with the 'modmail' scope.
+impl RedditClient {
+    /// Fetch one page of modmail conversations
+    ///
+    /// # Arguments
+    /// * `subreddits` - Subreddits to include; empty for every subreddit we moderate
+    /// * `state` - Which conversations to include
+    /// * `limit` - Maximum number of conversations to return (up to 100)
+    /// * `after` - Conversation ID to continue after, for pagination
+    pub async fn fetch_modmail_conversations(
+        &self,
+        subreddits: &[&str],
+        state: ModmailState,
+        limit: Option<u32>,
+        after: Option<&str>,
+    ) -> Result<ModmailConversationsResponse, RedditClientError> {
+        if self.access_token.is_none() {
+            return Err(RedditClientError::ApiError(
+                "Modmail requires user authentication".to_string(),
+            ));
+        }
+
+        let mut query = vec![
+            ("state", state.as_str().to_string()),
+            ("sort", "recent".to_string()),
+        ];
+        if !subreddits.is_empty() {
+            let entity = subreddits
+                .iter()
+                .map(|sub| clean_subreddit_name(sub))
+                .collect::<Vec<_>>()
+                .join(",");
+            query.push(("entity", entity));
+        }
+        if let Some(limit) = limit {
+            query.push(("limit", limit.min(100).to_string()));
+        }
+        if let Some(after) = after {
+            query.push(("after", after.to_string()));
+        }
+
+        let response: ModmailConversationsResponse =
+            self.get_json("/api/mod/conversations", &query).await?;
+        debug!(
+            "Fetched {} {} modmail conversations",
+            response.conversation_ids.len(),
+            state
+        );
+
+        Ok(response)
+    }
+
+    /// Fetch a single modmail conversation with all of its messages
+    ///
+    /// # Arguments
+    /// * `conversation_id` - The conversation ID (e.g. "1a2b3c")
+    /// * `mark_read` - Whether to mark the conversation as read
+    pub async fn fetch_modmail_conversation(
+        &self,
+        conversation_id: &str,
+        mark_read: bool,
+    ) -> Result<ModmailConversationResponse, RedditClientError> {
+        check_conversation_id(conversation_id)?;
+        if self.access_token.is_none() {
+            return Err(RedditClientError::ApiError(
+                "Modmail requires user authentication".to_string(),
+            ));
+        }
+
+        let path = format!("/api/mod/conversations/{}", conversation_id);
+        self.get_json(&path, &[("markRead", mark_read.to_string())])
+            .await
+    }
+
+    /// Reply to a modmail conversation
+    ///
+    /// # Arguments
+    /// * `conversation_id` - The conversation ID
+    /// * `body` - The reply in markdown
+    /// * `hide_author` - Reply as the subreddit instead of as ourselves
+    /// * `internal` - Leave a private moderator note the user can't see
+    pub async fn reply_modmail(
+        &self,
+        conversation_id: &str,
+        body: &str,
+        hide_author: bool,
+        internal: bool,
+    ) -> Result<ModmailConversationResponse, RedditClientError> {
+        check_conversation_id(conversation_id)?;
+        if body.trim().is_empty() {
+            return Err(RedditClientError::ApiError(
+                "Modmail reply must not be empty".to_string(),
+            ));
+        }
+
+        let path = format!("/api/mod/conversations/{}", conversation_id);
+        let hide_author = hide_author.to_string();
+        let internal = internal.to_string();
+        let json = self
+            .post_form(
+                &path,
+                &[
+                    ("body", body),
+                    ("isAuthorHidden", &hide_author),
+                    ("isInternal", &internal),
+                ],
+            )
+            .await?;
+        debug!("Replied to modmail conversation {}", conversation_id);
+
+        Ok(serde_json::from_value(json)?)
+    }
+
+    /// Archive a modmail conversation
+    pub async fn archive_modmail(&self, conversation_id: &str) -> Result<(), RedditClientError> {
+        self.update_modmail(conversation_id, reqwest::Method::POST, "archive", &[])
+            .await
+    }
+
+    /// Move an archived modmail conversation back to the inbox
+    pub async fn unarchive_modmail(&self, conversation_id: &str) -> Result<(), RedditClientError> {
+        self.update_modmail(conversation_id, reqwest::Method::POST, "unarchive", &[])
+            .await
+    }
+
+    /// Highlight a modmail conversation
+    pub async fn highlight_modmail(&self, conversation_id: &str) -> Result<(), RedditClientError> {
+        self.update_modmail(conversation_id, reqwest::Method::POST, "highlight", &[])
+            .await
+    }
+
+    /// Remove the highlight from a modmail conversation
+    pub async fn unhighlight_modmail(
+        &self,
+        conversation_id: &str,
+    ) -> Result<(), RedditClientError> {
+        self.update_modmail(conversation_id, reqwest::Method::DELETE, "highlight", &[])
+            .await
+    }
+
+    /// Mute the non-moderator participant of a modmail conversation
+    ///
+    /// # Arguments
+    /// * `conversation_id` - The conversation ID
+    /// * `hours` - How long to mute for: 72, 168 or 672 hours (3, 7 or 28 days)
+    pub async fn mute_modmail(
+        &self,
+        conversation_id: &str,
+        hours: u32,
+    ) -> Result<(), RedditClientError> {
+        if ![72, 168, 672].contains(&hours) {
+            return Err(RedditClientError::ApiError(format!(
+                "Invalid mute duration of {} hours, expected 72, 168 or 672",
+                hours
+            )));
+        }
+
+        let hours = hours.to_string();
+        self.update_modmail(
+            conversation_id,
+            reqwest::Method::POST,
+            "mute",
+            &[("num_hours", &hours)],
+        )
+        .await
+    }
+
+    /// Unmute the non-moderator participant of a modmail conversation
+    pub async fn unmute_modmail(&self, conversation_id: &str) -> Result<(), RedditClientError> {
+        self.update_modmail(conversation_id, reqwest::Method::POST, "unmute", &[])
+            .await
+    }
+
+    // Internal helper method for the conversation state endpoints
+    async fn update_modmail(
+        &self,
+        conversation_id: &str,
+        method: reqwest::Method,
+        action: &str,
+        params: &[(&str, &str)],
+    ) -> Result<(), RedditClientError> {
+        check_conversation_id(conversation_id)?;
+
+        let path = format!("/api/mod/conversations/{}/{}", conversation_id, action);
+        let request = self.authorized_request(method, &path)?.form(params);
+        self.send_authorized(&path, request).await?;
+        debug!(
+            "Applied {} to modmail conversation {}",
+            action, conversation_id
+        );
+
+        Ok(())
+    }
+}
+
+/// Check that a modmail conversation ID looks valid before putting it in a URL
+fn check_conversation_id(conversation_id: &str) -> Result<(), RedditClientError> {
+    if conversation_id.is_empty() || !conversation_id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(RedditClientError::ApiError(format!(
+            "Invalid modmail conversation ID '{}'",
+            conversation_id
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use super::RedditClient;
+use log::debug;
+use reqwest::header::HeaderMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Paces requests so that every clone of a client stays within Reddit's limits.
+///
+/// Requests are spaced evenly at the configured rate, and the
+/// `X-Ratelimit-Remaining` / `X-Ratelimit-Reset` headers Reddit sends back
+/// pause all requests until the window resets when the quota runs out.
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Minimum spacing between two requests
+    interval: Duration,
+    state: Mutex<RateLimitState>,
+}
+
+#[derive(Debug, Default)]
+struct RateLimitState {
+    /// When the next request may be sent
+    next_slot: Option<Instant>,
+    /// Requests left in the current window, as reported by Reddit
+    remaining: Option<f64>,
+    /// When the current window resets, as reported by Reddit
+    reset_at: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing at most `requests_per_minute` requests
+    pub fn new(requests_per_minute: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(60) / requests_per_minute.max(1),
+            state: Mutex::new(RateLimitState::default()),
+        }
+    }
+
+    /// Wait until the next request may be sent and reserve its slot
+    pub async fn acquire(&self) {
+        let wait_until = {
+            let mut state = self.state.lock().await;
+            let now = Instant::now();
+
+            let mut slot = state.next_slot.unwrap_or(now).max(now);
+            if let (Some(remaining), Some(reset_at)) = (state.remaining, state.reset_at) {
+                if remaining < 1.0 && reset_at > slot {
+                    debug!(
+                        "Rate limit exhausted, waiting {}s for the window to reset",
+                        (reset_at - now).as_secs()
+                    );
+                    slot = reset_at;
+                    state.remaining = None;
+                }
+            }
+
+            state.next_slot = Some(slot + self.interval);
+            slot
+        };
+
+        tokio::time::sleep_until(wait_until).await;
+    }
+
+    /// Record the rate limit headers from a response
+    pub async fn update(&self, headers: &HeaderMap) {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<f64>().ok())
+        };
+
+        let (Some(remaining), Some(reset)) =
+            (header("x-ratelimit-remaining"), header("x-ratelimit-reset"))
+        else {
+            return;
+        };
+
+        let mut state = self.state.lock().await;
+        state.remaining = Some(remaining);
+        state.reset_at = Some(Instant::now() + Duration::from_secs_f64(reset.max(0.0)));
+    }
+}
+
+/// Client-side rate limiting.
+///
+/// The limiter is shared by every clone of the client, so streams, handlers and
+/// background tasks that each hold a clone draw from the same budget.
+impl RedditClient {
+    /// Limit this client (and its clones) to `requests_per_minute` requests.
+    ///
+    /// Reddit allows 100 requests per minute for OAuth clients; 60 is a safe default.
+    pub fn with_rate_limit(mut self, requests_per_minute: u32) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(requests_per_minute)));
+        self
+    }
+
+    /// Wait for a request slot if this client is rate limited
+    pub(crate) async fn throttle(&self) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+    }
+
+    /// Feed the rate limit headers of a response back into the limiter
+    pub(crate) async fn record_rate_limit(&self, headers: &HeaderMap) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.update(headers).await;
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use super::listing::ListingParams;
+use super::subreddits::clean_subreddit_name;
+use super::users::clean_username;
+use super::{RedditClient, RedditClientError};
+use crate::models::relationship::{RelationshipList, RelationshipListResponse};
+use log::debug;
+use std::fmt;
+use std::str::FromStr;
+
+/// The kinds of user relationship a subreddit can have
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelationshipKind {
+    /// Banned from participating
+    Banned,
+    /// Muted from messaging the moderators
+    Muted,
+    /// Approved submitter
+    Contributor,
+    /// Invited to become a moderator
+    ModeratorInvite,
+    /// Banned from editing the wiki
+    WikiBanned,
+}
+
+impl RelationshipKind {
+    /// The value Reddit expects in the `type` parameter
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            RelationshipKind::Banned => "banned",
+            RelationshipKind::Muted => "muted",
+            RelationshipKind::Contributor => "contributor",
+            RelationshipKind::ModeratorInvite => "moderator_invite",
+            RelationshipKind::WikiBanned => "wikibanned",
+        }
+    }
+
+    /// The path segment of the matching user list under `/r/{sub}/about/`, if it has one
+    pub fn listing_path(&self) -> Option<&'static str> {
+        match self {
+            RelationshipKind::Banned => Some("banned"),
+            RelationshipKind::Muted => Some("muted"),
+            RelationshipKind::Contributor => Some("contributors"),
+            RelationshipKind::WikiBanned => Some("wikibanned"),
+            RelationshipKind::ModeratorInvite => None,
+        }
+    }
+}
+
+impl fmt::Display for RelationshipKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_param())
+    }
+}
+
+impl FromStr for RelationshipKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "banned" | "ban" => Ok(RelationshipKind::Banned),
+            "muted" | "mute" => Ok(RelationshipKind::Muted),
+            "contributor" | "contributors" | "approved" => Ok(RelationshipKind::Contributor),
+            "moderator_invite" | "invite" => Ok(RelationshipKind::ModeratorInvite),
+            "wikibanned" | "wiki_banned" => Ok(RelationshipKind::WikiBanned),
+            other => Err(format!(
+                "Unknown relationship kind '{}', expected one of: banned, muted, contributor, moderator_invite, wikibanned",
+                other
+            )),
+        }
+    }
+}
+
+/// Optional details attached when adding a relationship, mainly for bans
+#[derive(Debug, Clone, Default)]
+pub struct RelationshipDetails {
+    /// Ban length in days (1-999); `None` for a permanent ban
+    pub duration_days: Option<u32>,
+    /// Short ban reason shown in the ban list (up to 100 characters)
+    pub reason: Option<String>,
+    /// Message sent to the user along with the ban notification
+    pub message: Option<String>,
+    /// Private note for other moderators (up to 300 characters)
+    pub note: Option<String>,
+}
+
+/// Subreddit user relationships: bans, mutes, approved submitters and invites.
+///
+/// All of these require moderator permissions and user authentication with the
+/// 'modcontributors' scope ('modothers' for moderator invites, 'modwiki' for wiki bans).
+impl RedditClient {
+    /// Add a user relationship to a subreddit, e.g. ban or approve a user
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `username` - The user to add, with or without the `u/` prefix
+    /// * `kind` - The relationship to add
+    /// * `details` - Ban duration, reason, message and note; ignored where not applicable
+    pub async fn friend(
+        &self,
+        subreddit: &str,
+        username: &str,
+        kind: RelationshipKind,
+        details: &RelationshipDetails,
+    ) -> Result<(), RedditClientError> {
+        if let Some(days) = details.duration_days {
+            if !(1..=999).contains(&days) {
+                return Err(RedditClientError::ApiError(format!(
+                    "Invalid ban duration of {} days, expected 1-999 (omit for a permanent ban)",
+                    days
+                )));
+            }
+        }
+        if details
+            .reason
+            .as_ref()
+            .is_some_and(|reason| reason.chars().count() > 100)
+        {
+            return Err(RedditClientError::ApiError(
+                "Ban reason must be 100 characters or fewer".to_string(),
+            ));
+        }
+        if details
+            .note
+            .as_ref()
+            .is_some_and(|note| note.chars().count() > 300)
+        {
+            return Err(RedditClientError::ApiError(
+                "Mod note must be 300 characters or fewer".to_string(),
+            ));
+        }
+
+        let subreddit = clean_subreddit_name(subreddit);
+        let username = clean_username(username);
+        let path = format!("/r/{}/api/friend", subreddit);
+
+        let duration = details.duration_days.map(|days| days.to_string());
+        let mut params = vec![
+            ("api_type", "json"),
+            ("name", username),
+            ("type", kind.as_param()),
+        ];
+        if let Some(duration) = &duration {
+            params.push(("duration", duration));
+        }
+        if let Some(reason) = &details.reason {
+            params.push(("ban_reason", reason));
+        }
+        if let Some(message) = &details.message {
+            params.push(("ban_message", message));
+        }
+        if let Some(note) = &details.note {
+            params.push(("note", note));
+        }
+
+        self.post_form(&path, &params).await?;
+        debug!(
+            "Added {} relationship for u/{} in r/{}",
+            kind, username, subreddit
+        );
+
+        Ok(())
+    }
+
+    /// Remove a user relationship from a subreddit, e.g. unban a user
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `username` - The user to remove, with or without the `u/` prefix
+    /// * `kind` - The relationship to remove
+    pub async fn unfriend(
+        &self,
+        subreddit: &str,
+        username: &str,
+        kind: RelationshipKind,
+    ) -> Result<(), RedditClientError> {
+        let subreddit = clean_subreddit_name(subreddit);
+        let username = clean_username(username);
+        let path = format!("/r/{}/api/unfriend", subreddit);
+
+        self.post_form(
+            &path,
+            &[
+                ("api_type", "json"),
+                ("name", username),
+                ("type", kind.as_param()),
+            ],
+        )
+        .await?;
+        debug!(
+            "Removed {} relationship for u/{} in r/{}",
+            kind, username, subreddit
+        );
+
+        Ok(())
+    }
+
+    /// Fetch one page of a subreddit's user list, e.g. the banned users
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `kind` - Which user list to fetch
+    /// * `params` - Pagination parameters; the cursor is the previous page's last `rel_id`
+    pub async fn fetch_relationships(
+        &self,
+        subreddit: &str,
+        kind: RelationshipKind,
+        params: &ListingParams,
+    ) -> Result<RelationshipList, RedditClientError> {
+        let listing = kind.listing_path().ok_or_else(|| {
+            RedditClientError::ApiError(format!("The {} relationship has no user list", kind))
+        })?;
+        if self.access_token.is_none() {
+            return Err(RedditClientError::ApiError(format!(
+                "The {} list is only visible to moderators and requires user authentication",
+                listing
+            )));
+        }
+
+        let subreddit = clean_subreddit_name(subreddit);
+        let path = format!("/r/{}/about/{}.json", subreddit, listing);
+
+        let response: RelationshipListResponse = self.get_json(&path, &params.to_query()).await?;
+        debug!(
+            "Fetched {} users from r/{}/about/{} ({})",
+            response.data.children.len(),
+            subreddit,
+            listing,
+            response.kind
+        );
+
+        Ok(response.data)
+    }
+}
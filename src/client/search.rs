@@ -0,0 +1,45 @@
+use super::listing::ListingParams;
+use super::subreddits::clean_subreddit_name;
+use super::{RedditClient, RedditClientError};
+use crate::models::listing::{Listing, Thing};
+use log::debug;
+
+impl RedditClient {
+    /// Search for posts across Reddit or within one subreddit
+    ///
+    /// # Arguments
+    /// * `query` - Search terms, using Reddit's search syntax (e.g. `title:rust author:spez`)
+    /// * `subreddit` - Restrict the search to this subreddit
+    /// * `params` - Pagination, sort and time filter parameters; results are by relevance
+    ///   unless a sort is given
+    pub async fn search_posts(
+        &self,
+        query: &str,
+        subreddit: Option<&str>,
+        params: &ListingParams,
+    ) -> Result<Listing<Thing>, RedditClientError> {
+        let path = match subreddit {
+            Some(subreddit) => format!("/r/{}/search.json", clean_subreddit_name(subreddit)),
+            None => "/search.json".to_string(),
+        };
+
+        let mut query_params = params.to_query();
+        query_params.push(("q", query.to_string()));
+        query_params.push(("type", "link".to_string()));
+        if subreddit.is_some() {
+            query_params.push(("restrict_sr", "1".to_string()));
+        }
+
+        let response: Listing<Thing> = self.get_json(&path, &query_params).await?;
+        debug!(
+            "Found {} posts for '{}'{}",
+            response.data.children.len(),
+            query,
+            subreddit
+                .map(|subreddit| format!(" in r/{}", clean_subreddit_name(subreddit)))
+                .unwrap_or_default()
+        );
+
+        Ok(response)
+    }
+}
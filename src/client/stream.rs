@@ -0,0 +1,359 @@
+use super::listing::ListingParams;
+use super::messages::InboxFolder;
+use super::subreddits::clean_subreddit_name;
+use super::{RedditClient, RedditClientError};
+use crate::models::comment::CommentData;
+use crate::models::listing::{Listing, Thing};
+use crate::models::RedditPostData;
+use log::{debug, warn};
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+/// How many fullnames a stream remembers for deduplication
+const SEEN_CAPACITY: usize = 2000;
+
+/// Polling behaviour for submission and comment streams
+#[derive(Debug, Clone)]
+pub struct StreamConfig {
+    /// Shortest wait between polls, used while new items keep arriving
+    pub min_interval: Duration,
+    /// Longest wait between polls, reached after repeated empty polls
+    pub max_interval: Duration,
+    /// Longest wait between retries after consecutive errors
+    pub max_backoff: Duration,
+    /// Number of items requested per poll (up to 100)
+    pub limit: u32,
+    /// Skip the items already in the listing when the stream starts
+    pub skip_existing: bool,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            min_interval: Duration::from_secs(5),
+            max_interval: Duration::from_secs(60),
+            max_backoff: Duration::from_secs(300),
+            limit: 100,
+            skip_existing: false,
+        }
+    }
+}
+
+/// Polling state shared by the submission and comment streams
+#[derive(Debug)]
+struct PollState {
+    config: StreamConfig,
+    /// Fullnames already emitted, with insertion order for eviction
+    seen: HashSet<String>,
+    seen_order: VecDeque<String>,
+    /// Wait before the next poll; `None` before the first poll
+    interval: Option<Duration>,
+    /// Wait before retrying after an error; `None` when the last poll succeeded
+    backoff: Option<Duration>,
+}
+
+impl PollState {
+    fn new(config: StreamConfig) -> Self {
+        Self {
+            config,
+            seen: HashSet::new(),
+            seen_order: VecDeque::new(),
+            interval: None,
+            backoff: None,
+        }
+    }
+
+    /// Sleep until the next poll is due
+    async fn wait(&self) {
+        let delay = self.backoff.or(self.interval);
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Mark items as already emitted
+    fn seed(&mut self, fullnames: impl IntoIterator<Item = String>) {
+        for name in fullnames {
+            if self.seen.insert(name.clone()) {
+                self.seen_order.push_back(name);
+            }
+        }
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        while self.seen_order.len() > SEEN_CAPACITY {
+            if let Some(oldest) = self.seen_order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+    }
+
+    /// Keep only unseen items, oldest first, and adapt the polling interval
+    fn accept<T>(&mut self, items: Vec<T>, fullname: impl Fn(&T) -> String) -> Vec<T> {
+        let first_poll = self.interval.is_none();
+        self.backoff = None;
+
+        // Listings are newest first; emit in the order the items were created
+        let mut fresh = Vec::new();
+        for item in items.into_iter().rev() {
+            let name = fullname(&item);
+            if self.seen.insert(name.clone()) {
+                self.seen_order.push_back(name);
+                fresh.push(item);
+            }
+        }
+        self.evict();
+
+        // Poll faster while items are arriving, and back off while it's quiet
+        let current = self.interval.unwrap_or(self.config.min_interval);
+        self.interval = Some(if fresh.is_empty() {
+            current.mul_f32(1.5).min(self.config.max_interval)
+        } else {
+            (current / 2).max(self.config.min_interval)
+        });
+
+        if first_poll && self.config.skip_existing {
+            debug!("Skipping {} existing items", fresh.len());
+            return Vec::new();
+        }
+
+        fresh
+    }
+
+    /// Record a failed poll, doubling the retry delay up to the configured maximum
+    fn record_error(&mut self, err: &RedditClientError) {
+        let backoff = match self.backoff {
+            Some(backoff) => (backoff * 2).min(self.config.max_backoff),
+            None => self.config.min_interval,
+        };
+        warn!(
+            "Stream poll failed, retrying in {}s: {}",
+            backoff.as_secs(),
+            err
+        );
+        self.backoff = Some(backoff);
+    }
+}
+
+/// A stream of new posts in a subreddit, created by `RedditClient::stream_submissions`
+pub struct SubmissionStream {
+    client: RedditClient,
+    subreddit: String,
+    state: PollState,
+    /// Posts created before this were already there when the stream was seeded
+    seeded_until: Option<f64>,
+}
+
+impl SubmissionStream {
+    /// Continue from posts the caller already has, e.g. a listing it just printed.
+    ///
+    /// They aren't emitted again, and neither are posts older than the newest
+    /// of them; anything newer is, including posts that arrived in between.
+    pub fn seed(&mut self, posts: &[RedditPostData]) {
+        self.state
+            .seed(posts.iter().map(|post| format!("t3_{}", post.id)));
+        let newest = posts
+            .iter()
+            .filter(|post| !post.stickied)
+            .map(|post| post.created_utc)
+            .reduce(f64::max);
+        if newest.is_some() {
+            self.seeded_until = newest;
+        }
+    }
+
+    /// Wait for the next batch of unseen posts, oldest first.
+    ///
+    /// Network and API errors are logged and retried with exponential backoff,
+    /// so this only returns once at least one new post has arrived.
+    pub async fn next_batch(&mut self) -> Vec<RedditPostData> {
+        loop {
+            self.state.wait().await;
+
+            match poll_submissions(&mut self.client, &self.subreddit, self.state.config.limit).await
+            {
+                Ok(mut posts) => {
+                    if let Some(until) = self.seeded_until {
+                        posts.retain(|post| post.stickied || post.created_utc >= until);
+                    }
+                    let fresh = self.state.accept(posts, |post| format!("t3_{}", post.id));
+                    if !fresh.is_empty() {
+                        return fresh;
+                    }
+                }
+                Err(err) => self.state.record_error(&err),
+            }
+        }
+    }
+}
+
+/// A stream of new comments in a subreddit, created by `RedditClient::stream_comments`
+pub struct CommentStream {
+    client: RedditClient,
+    subreddit: String,
+    state: PollState,
+}
+
+impl CommentStream {
+    /// Wait for the next batch of unseen comments, oldest first.
+    ///
+    /// Network and API errors are logged and retried with exponential backoff,
+    /// so this only returns once at least one new comment has arrived.
+    pub async fn next_batch(&mut self) -> Vec<CommentData> {
+        loop {
+            self.state.wait().await;
+
+            match poll_comments(&mut self.client, &self.subreddit, self.state.config.limit).await {
+                Ok(comments) => {
+                    let fresh = self.state.accept(comments, |comment| comment.name.clone());
+                    if !fresh.is_empty() {
+                        return fresh;
+                    }
+                }
+                Err(err) => self.state.record_error(&err),
+            }
+        }
+    }
+}
+
+/// A stream of new inbox items, created by `RedditClient::stream_inbox`
+pub struct InboxStream {
+    client: RedditClient,
+    state: PollState,
+}
+
+impl InboxStream {
+    /// Wait for the next batch of unseen messages, replies and mentions, oldest first.
+    ///
+    /// Network and API errors are logged and retried with exponential backoff,
+    /// so this only returns once at least one new item has arrived.
+    pub async fn next_batch(&mut self) -> Vec<Thing> {
+        loop {
+            self.state.wait().await;
+
+            match poll_inbox(&mut self.client, self.state.config.limit).await {
+                Ok(items) => {
+                    let fresh = self
+                        .state
+                        .accept(items, |item| item.fullname().unwrap_or_default());
+                    if !fresh.is_empty() {
+                        return fresh;
+                    }
+                }
+                Err(err) => self.state.record_error(&err),
+            }
+        }
+    }
+}
+
+/// Live streams of new posts, comments and inbox items.
+///
+/// Each stream owns a clone of the client, refreshes its access token as
+/// needed, and polls the subreddit's `new` listing with an adaptive interval.
+impl RedditClient {
+    /// Stream new posts from a subreddit
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `config` - Polling intervals, batch size and whether to skip existing posts
+    pub fn stream_submissions(&self, subreddit: &str, config: StreamConfig) -> SubmissionStream {
+        SubmissionStream {
+            client: self.clone().bypassing_cache(),
+            subreddit: clean_subreddit_name(subreddit).to_string(),
+            state: PollState::new(config),
+            seeded_until: None,
+        }
+    }
+
+    /// Stream new comments from a subreddit
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `config` - Polling intervals, batch size and whether to skip existing comments
+    pub fn stream_comments(&self, subreddit: &str, config: StreamConfig) -> CommentStream {
+        CommentStream {
+            client: self.clone().bypassing_cache(),
+            subreddit: clean_subreddit_name(subreddit).to_string(),
+            state: PollState::new(config),
+        }
+    }
+
+    /// Stream new private messages, comment replies and username mentions.
+    ///
+    /// Requires user authentication with the 'privatemessages' scope.
+    ///
+    /// # Arguments
+    /// * `config` - Polling intervals, batch size and whether to skip existing items
+    pub fn stream_inbox(&self, config: StreamConfig) -> InboxStream {
+        InboxStream {
+            client: self.clone().bypassing_cache(),
+            state: PollState::new(config),
+        }
+    }
+}
+
+/// Fetch the newest posts, refreshing the access token first if we have one
+async fn poll_submissions(
+    client: &mut RedditClient,
+    subreddit: &str,
+    limit: u32,
+) -> Result<Vec<RedditPostData>, RedditClientError> {
+    if client.access_token.is_some() {
+        client.ensure_access_token().await?;
+    }
+
+    let response = client
+        .fetch_new_posts(subreddit, limit.min(100) as i32)
+        .await?;
+    Ok(response
+        .data
+        .children
+        .into_iter()
+        .map(|entity| entity.data)
+        .collect())
+}
+
+/// Fetch the newest comments, refreshing the access token first if we have one
+async fn poll_comments(
+    client: &mut RedditClient,
+    subreddit: &str,
+    limit: u32,
+) -> Result<Vec<CommentData>, RedditClientError> {
+    if client.access_token.is_some() {
+        client.ensure_access_token().await?;
+    }
+
+    let path = format!("/r/{}/comments.json", subreddit);
+    let query = [
+        ("limit", limit.min(100).to_string()),
+        ("raw_json", "1".to_string()),
+    ];
+    let response: Listing<Thing> = client.get_json(&path, &query).await?;
+
+    Ok(response
+        .data
+        .children
+        .into_iter()
+        .filter_map(|thing| match thing {
+            Thing::Comment(comment) => Some(*comment),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Fetch the newest inbox items, refreshing the access token first
+async fn poll_inbox(
+    client: &mut RedditClient,
+    limit: u32,
+) -> Result<Vec<Thing>, RedditClientError> {
+    client.ensure_access_token().await?;
+
+    let params = ListingParams {
+        limit: Some(limit.min(100)),
+        ..Default::default()
+    };
+    let response = client.fetch_inbox(InboxFolder::Inbox, &params).await?;
+
+    Ok(response.data.children)
+}
@@ -0,0 +1,148 @@
+use super::{RedditClient, RedditClientError};
+use crate::models::subreddit::{
+    Moderator, ModeratorListResponse, PostRequirements, SubredditAbout, SubredditAboutResponse,
+    SubredditRules,
+};
+use log::debug;
+
+impl RedditClient {
+    /// Fetch a subreddit's metadata, including its sidebar and submission settings
+    pub async fn fetch_subreddit_about(
+        &self,
+        subreddit: &str,
+    ) -> Result<SubredditAbout, RedditClientError> {
+        let path = format!("/r/{}/about.json", clean_subreddit_name(subreddit));
+
+        let response: SubredditAboutResponse = self
+            .get_json(&path, &[("raw_json", "1".to_string())])
+            .await?;
+        debug!(
+            "Fetched subreddit {} ({})",
+            response.data.display_name, response.kind
+        );
+
+        Ok(response.data)
+    }
+
+    /// Fetch a subreddit's rules
+    pub async fn fetch_subreddit_rules(
+        &self,
+        subreddit: &str,
+    ) -> Result<SubredditRules, RedditClientError> {
+        let path = format!("/r/{}/about/rules.json", clean_subreddit_name(subreddit));
+
+        let rules: SubredditRules = self
+            .get_json(&path, &[("raw_json", "1".to_string())])
+            .await?;
+        debug!("Fetched {} rules", rules.rules.len());
+
+        Ok(rules)
+    }
+
+    /// Fetch the submission requirements a subreddit enforces on new posts
+    ///
+    /// # Note
+    /// This endpoint is only available through the OAuth API, so an access token is required.
+    pub async fn fetch_post_requirements(
+        &self,
+        subreddit: &str,
+    ) -> Result<PostRequirements, RedditClientError> {
+        if self.access_token.is_none() {
+            return Err(RedditClientError::ApiError(
+                "No access token available. Post requirements require OAuth authentication."
+                    .to_string(),
+            ));
+        }
+
+        let path = format!(
+            "/api/v1/{}/post_requirements",
+            clean_subreddit_name(subreddit)
+        );
+
+        self.get_json(&path, &[]).await
+    }
+
+    /// Fetch the list of moderators for a subreddit
+    pub async fn fetch_subreddit_moderators(
+        &self,
+        subreddit: &str,
+    ) -> Result<Vec<Moderator>, RedditClientError> {
+        let path = format!(
+            "/r/{}/about/moderators.json",
+            clean_subreddit_name(subreddit)
+        );
+
+        let response: ModeratorListResponse = self.get_json(&path, &[]).await?;
+        debug!(
+            "Fetched {} moderators ({})",
+            response.data.children.len(),
+            response.kind
+        );
+
+        Ok(response.data.children)
+    }
+
+    /// Check a text post against a subreddit's settings and post requirements
+    /// without submitting it.
+    ///
+    /// Returns the list of problems found; an empty list means the post looks valid.
+    /// Post requirements are only checked when an access token is available.
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit the post is intended for
+    /// * `title` - The post title
+    /// * `text` - The post body
+    /// * `has_flair` - Whether a flair will be set on the post
+    pub async fn validate_self_post(
+        &self,
+        subreddit: &str,
+        title: &str,
+        text: &str,
+        has_flair: bool,
+    ) -> Result<Vec<String>, RedditClientError> {
+        let mut violations = Vec::new();
+
+        let about = self.fetch_subreddit_about(subreddit).await?;
+        if !about.allows_self_posts() {
+            violations.push(format!("r/{} only accepts link posts", about.display_name));
+        }
+
+        if self.access_token.is_some() {
+            let requirements = self.fetch_post_requirements(subreddit).await?;
+            violations.extend(requirements.check_self_post(title, text, has_flair));
+        } else {
+            debug!("Skipping post requirements check: no access token");
+        }
+
+        Ok(violations)
+    }
+
+    /// Check a text post with `validate_self_post` and describe any problems.
+    ///
+    /// Returns `None` when the post looks valid, otherwise a message listing
+    /// each problem, for the create commands' `--validate` flag.
+    pub async fn check_self_post(
+        &self,
+        subreddit: &str,
+        title: &str,
+        text: &str,
+    ) -> Result<Option<String>, RedditClientError> {
+        let violations = self
+            .validate_self_post(subreddit, title, text, false)
+            .await?;
+        if violations.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(format!(
+            "Post does not meet the requirements of r/{}:\n  - {}",
+            clean_subreddit_name(subreddit),
+            violations.join("\n  - ")
+        )))
+    }
+}
+
+/// Remove a leading `r/` or `/r/` from a subreddit name
+pub(crate) fn clean_subreddit_name(subreddit: &str) -> &str {
+    let subreddit = subreddit.strip_prefix('/').unwrap_or(subreddit);
+    subreddit.strip_prefix("r/").unwrap_or(subreddit)
+}
@@ -0,0 +1,79 @@
+use super::{check_fullname, RedditClient, RedditClientError};
+use crate::models::listing::{Listing, Thing};
+use log::debug;
+
+impl RedditClient {
+    /// Fetch posts, comments or subreddits by fullname through `/api/info`
+    ///
+    /// # Arguments
+    /// * `fullnames` - Up to 100 fullnames ("t1_...", "t3_...", "t5_...")
+    pub async fn fetch_info(&self, fullnames: &[&str]) -> Result<Vec<Thing>, RedditClientError> {
+        if fullnames.len() > 100 {
+            return Err(RedditClientError::ApiError(
+                "At most 100 fullnames can be fetched at once".to_string(),
+            ));
+        }
+
+        let query = [("id", fullnames.join(",")), ("raw_json", "1".to_string())];
+        let response: Listing<Thing> = self.get_json("/api/info.json", &query).await?;
+        debug!(
+            "Fetched {} things by fullname",
+            response.data.children.len()
+        );
+
+        Ok(response.data.children)
+    }
+
+    /// Fetch the current markdown of a self post or comment
+    pub async fn fetch_user_text(&self, fullname: &str) -> Result<String, RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        let things = self.fetch_info(&[fullname]).await?;
+        match things.into_iter().next() {
+            Some(Thing::Comment(comment)) => Ok(comment.body),
+            Some(Thing::Link(post)) if post.is_self => Ok(post.selftext),
+            Some(Thing::Link(_)) => Err(RedditClientError::ApiError(format!(
+                "{} is a link post and has no editable text",
+                fullname
+            ))),
+            _ => Err(RedditClientError::ApiError(format!(
+                "Could not find {}",
+                fullname
+            ))),
+        }
+    }
+
+    /// Replace the markdown body of one of our own self posts or comments.
+    ///
+    /// # Arguments
+    /// * `fullname` - The fullname of the post ("t3_...") or comment ("t1_...")
+    /// * `text` - The new markdown text
+    ///
+    /// # Note
+    /// Requires user authentication with the 'edit' scope.
+    pub async fn edit_text(&self, fullname: &str, text: &str) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        self.post_form(
+            "/api/editusertext",
+            &[("api_type", "json"), ("thing_id", fullname), ("text", text)],
+        )
+        .await?;
+        debug!("Edited {}", fullname);
+
+        Ok(())
+    }
+
+    /// Delete one of our own posts or comments.
+    ///
+    /// # Note
+    /// Requires user authentication with the 'edit' scope.
+    pub async fn delete(&self, fullname: &str) -> Result<(), RedditClientError> {
+        check_fullname(fullname, &["t1", "t3"])?;
+
+        self.post_form("/api/del", &[("id", fullname)]).await?;
+        debug!("Deleted {}", fullname);
+
+        Ok(())
+    }
+}
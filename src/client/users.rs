@@ -0,0 +1,151 @@
+use super::listing::ListingParams;
+use super::{RedditClient, RedditClientError};
+use crate::models::account::{Account, AccountResponse};
+use crate::models::listing::{Listing, Thing};
+use log::debug;
+use std::fmt;
+use std::str::FromStr;
+
+/// The listings available under a user's profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserListing {
+    Overview,
+    Submitted,
+    Comments,
+    Saved,
+    Upvoted,
+    Downvoted,
+    Hidden,
+}
+
+impl UserListing {
+    /// The path segment for this listing under `/user/{name}/`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserListing::Overview => "overview",
+            UserListing::Submitted => "submitted",
+            UserListing::Comments => "comments",
+            UserListing::Saved => "saved",
+            UserListing::Upvoted => "upvoted",
+            UserListing::Downvoted => "downvoted",
+            UserListing::Hidden => "hidden",
+        }
+    }
+
+    /// Whether the listing is private and only visible to the account owner
+    pub fn requires_owner(&self) -> bool {
+        matches!(
+            self,
+            UserListing::Saved
+                | UserListing::Upvoted
+                | UserListing::Downvoted
+                | UserListing::Hidden
+        )
+    }
+}
+
+impl fmt::Display for UserListing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for UserListing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "overview" => Ok(UserListing::Overview),
+            "submitted" | "posts" => Ok(UserListing::Submitted),
+            "comments" => Ok(UserListing::Comments),
+            "saved" => Ok(UserListing::Saved),
+            "upvoted" => Ok(UserListing::Upvoted),
+            "downvoted" => Ok(UserListing::Downvoted),
+            "hidden" => Ok(UserListing::Hidden),
+            other => Err(format!(
+                "Unknown user listing '{}', expected one of: overview, submitted, comments, saved, upvoted, downvoted, hidden",
+                other
+            )),
+        }
+    }
+}
+
+impl RedditClient {
+    /// Fetch a user's public profile information
+    ///
+    /// # Arguments
+    /// * `username` - The Reddit username, with or without the `u/` prefix
+    pub async fn fetch_user_about(&self, username: &str) -> Result<Account, RedditClientError> {
+        let username = clean_username(username);
+        let path = format!("/user/{}/about.json", username);
+
+        let response: AccountResponse = self
+            .get_json(&path, &[("raw_json", "1".to_string())])
+            .await?;
+        debug!("Fetched account {} ({})", response.data.name, response.kind);
+
+        Ok(response.data)
+    }
+
+    /// Fetch the account the client is logged in as
+    ///
+    /// # Note
+    /// Requires an access token belonging to a user with the 'identity' scope.
+    pub async fn fetch_me(&self) -> Result<Account, RedditClientError> {
+        if self.access_token.is_none() {
+            return Err(RedditClientError::ApiError(
+                "Looking up the logged-in account requires user authentication".to_string(),
+            ));
+        }
+
+        let account: Account = self
+            .get_json("/api/v1/me", &[("raw_json", "1".to_string())])
+            .await?;
+        debug!("Logged in as u/{}", account.name);
+
+        Ok(account)
+    }
+
+    /// Fetch one page of a user's profile listing
+    ///
+    /// # Arguments
+    /// * `username` - The Reddit username, with or without the `u/` prefix
+    /// * `listing` - Which listing to fetch (submitted, comments, saved, ...)
+    /// * `params` - Pagination, sort and time filter parameters
+    ///
+    /// # Note
+    /// The saved, upvoted, downvoted and hidden listings are private and require
+    /// an access token belonging to the same user with the 'history' scope.
+    pub async fn fetch_user_listing(
+        &self,
+        username: &str,
+        listing: UserListing,
+        params: &ListingParams,
+    ) -> Result<Listing<Thing>, RedditClientError> {
+        if listing.requires_owner() && self.access_token.is_none() {
+            return Err(RedditClientError::ApiError(format!(
+                "The '{}' listing is private and requires user authentication",
+                listing
+            )));
+        }
+
+        let username = clean_username(username);
+        let path = format!("/user/{}/{}.json", username, listing);
+
+        let response: Listing<Thing> = self.get_json(&path, &params.to_query()).await?;
+        debug!(
+            "Fetched {} items from u/{}/{}",
+            response.data.children.len(),
+            username,
+            listing
+        );
+
+        Ok(response)
+    }
+}
+
+/// Remove a leading `u/` or `/u/` from a username
+pub(crate) fn clean_username(username: &str) -> &str {
+    let username = username.strip_prefix('/').unwrap_or(username);
+    username.strip_prefix("u/").unwrap_or(username)
+}
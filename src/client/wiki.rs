@@ -0,0 +1,150 @@
+use super::listing::ListingParams;
+use super::subreddits::clean_subreddit_name;
+use super::{RedditClient, RedditClientError};
+use crate::models::listing::Listing;
+use crate::models::wiki::{WikiPage, WikiPageListResponse, WikiPageResponse, WikiRevision};
+use log::debug;
+
+/// Subreddit wiki pages.
+///
+/// Reading requires the 'wikiread' scope for private wikis; editing requires
+/// user authentication with the 'wikiedit' scope and edit permission on the page.
+impl RedditClient {
+    /// List the names of a subreddit's wiki pages
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    pub async fn wiki_pages(&self, subreddit: &str) -> Result<Vec<String>, RedditClientError> {
+        let subreddit = clean_subreddit_name(subreddit);
+        let path = format!("/r/{}/wiki/pages.json", subreddit);
+
+        let response: WikiPageListResponse = self.get_json(&path, &[]).await?;
+        debug!(
+            "Fetched {} wiki pages from r/{} ({})",
+            response.data.len(),
+            subreddit,
+            response.kind
+        );
+
+        Ok(response.data)
+    }
+
+    /// Fetch a wiki page, optionally at a specific revision
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `page` - The page name, e.g. "index" or "config/automoderator"
+    /// * `revision` - A revision ID from `wiki_revisions`; the latest when `None`
+    pub async fn wiki_page(
+        &self,
+        subreddit: &str,
+        page: &str,
+        revision: Option<&str>,
+    ) -> Result<WikiPage, RedditClientError> {
+        check_page_name(page)?;
+
+        let subreddit = clean_subreddit_name(subreddit);
+        let path = format!("/r/{}/wiki/{}.json", subreddit, page);
+
+        let mut query = vec![("raw_json", "1".to_string())];
+        if let Some(revision) = revision {
+            query.push(("v", revision.to_string()));
+        }
+
+        let response: WikiPageResponse = self.get_json(&path, &query).await?;
+        debug!(
+            "Fetched wiki page {} from r/{} at revision {:?}",
+            page, subreddit, response.data.revision_id
+        );
+
+        Ok(response.data)
+    }
+
+    /// Fetch one page of a wiki page's revision history, newest first
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `page` - The page name
+    /// * `params` - Pagination parameters
+    pub async fn wiki_revisions(
+        &self,
+        subreddit: &str,
+        page: &str,
+        params: &ListingParams,
+    ) -> Result<Listing<WikiRevision>, RedditClientError> {
+        check_page_name(page)?;
+
+        let subreddit = clean_subreddit_name(subreddit);
+        let path = format!("/r/{}/wiki/revisions/{}.json", subreddit, page);
+
+        let response: Listing<WikiRevision> = self.get_json(&path, &params.to_query()).await?;
+        debug!(
+            "Fetched {} revisions of wiki page {} from r/{}",
+            response.data.children.len(),
+            page,
+            subreddit
+        );
+
+        Ok(response)
+    }
+
+    /// Replace the content of a wiki page, creating it if it doesn't exist
+    ///
+    /// # Arguments
+    /// * `subreddit` - The subreddit name, with or without the `r/` prefix
+    /// * `page` - The page name
+    /// * `content` - The new page content in markdown
+    /// * `reason` - Optional edit reason shown in the page history (up to 256 characters)
+    /// * `previous` - The revision ID the edit is based on; Reddit rejects the edit
+    ///   with a conflict if someone else has revised the page since
+    pub async fn wiki_edit(
+        &self,
+        subreddit: &str,
+        page: &str,
+        content: &str,
+        reason: Option<&str>,
+        previous: Option<&str>,
+    ) -> Result<(), RedditClientError> {
+        check_page_name(page)?;
+        if reason.is_some_and(|reason| reason.chars().count() > 256) {
+            return Err(RedditClientError::ApiError(
+                "Wiki edit reason must be 256 characters or fewer".to_string(),
+            ));
+        }
+
+        let subreddit = clean_subreddit_name(subreddit);
+        let path = format!("/r/{}/api/wiki/edit", subreddit);
+
+        let mut params = vec![("page", page), ("content", content)];
+        if let Some(reason) = reason {
+            params.push(("reason", reason));
+        }
+        if let Some(previous) = previous {
+            params.push(("previous", previous));
+        }
+
+        self.post_form(&path, &params).await?;
+        debug!("Edited wiki page {} in r/{}", page, subreddit);
+
+        Ok(())
+    }
+}
+
+/// Check that a wiki page name is safe to put in a URL path
+fn check_page_name(page: &str) -> Result<(), RedditClientError> {
+    let valid = !page.is_empty()
+        && !page.starts_with('/')
+        && !page.contains("..")
+        && page
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '/'));
+
+    if !valid {
+        return Err(RedditClientError::ApiError(format!(
+            "Invalid wiki page name '{}'",
+            page
+        )));
+    }
+
+    Ok(())
+}
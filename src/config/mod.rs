@@ -25,6 +25,32 @@ pub struct AppConfig {
 
     // Reddit IDs for operations
     pub thing_id: Option<String>,
+
+    // Display settings
+    /// IANA timezone for displayed times (local time when unset)
+    pub timezone: Option<String>,
+    /// strftime pattern for displayed times
+    pub time_format: Option<String>,
+    /// Show times as "3h ago"
+    pub relative_time: bool,
+
+    // Response cache settings
+    /// Cache GET responses on disk
+    pub cache: bool,
+    /// Cache directory (~/.redrust/cache when unset)
+    pub cache_dir: Option<String>,
+    /// How long cached responses stay fresh, e.g. "5m"
+    pub cache_ttl: Option<String>,
+
+    // HTTP cassette settings
+    /// Cassette file to record HTTP traffic into or replay it from
+    pub cassette: Option<String>,
+    /// "record" or "replay" (the default)
+    pub cassette_mode: Option<String>,
+
+    // REST gateway settings
+    /// Key callers of `serve` must send
+    pub serve_api_key: Option<String>,
 }
 
 impl Default for AppConfig {
@@ -40,6 +66,15 @@ impl Default for AppConfig {
             refresh_token: None,
             token_expires_in: 3600,
             thing_id: None,
+            timezone: None,
+            time_format: None,
+            relative_time: false,
+            cache: false,
+            cache_dir: None,
+            cache_ttl: None,
+            cassette: None,
+            cassette_mode: None,
+            serve_api_key: None,
         }
     }
 }
@@ -105,6 +140,46 @@ impl AppConfig {
             config.thing_id = Some(thing_id);
         }
 
+        // Display settings
+        if let Ok(timezone) = env::var("REDDIT_TIMEZONE") {
+            config.timezone = Some(timezone);
+        }
+
+        if let Ok(time_format) = env::var("REDDIT_TIME_FORMAT") {
+            config.time_format = Some(time_format);
+        }
+
+        if let Ok(relative) = env::var("REDDIT_RELATIVE_TIME") {
+            config.relative_time = matches!(relative.to_lowercase().as_str(), "1" | "true" | "yes");
+        }
+
+        // Response cache
+        if let Ok(cache) = env::var("REDDIT_CACHE") {
+            config.cache = matches!(cache.to_lowercase().as_str(), "1" | "true" | "yes");
+        }
+
+        if let Ok(cache_dir) = env::var("REDDIT_CACHE_DIR") {
+            config.cache_dir = Some(cache_dir);
+        }
+
+        if let Ok(cache_ttl) = env::var("REDDIT_CACHE_TTL") {
+            config.cache_ttl = Some(cache_ttl);
+        }
+
+        // HTTP cassette for recording and replaying traffic
+        if let Ok(cassette) = env::var("REDDIT_CASSETTE") {
+            config.cassette = Some(cassette);
+        }
+
+        if let Ok(mode) = env::var("REDDIT_CASSETTE_MODE") {
+            config.cassette_mode = Some(mode);
+        }
+
+        // REST gateway
+        if let Ok(api_key) = env::var("REDDIT_SERVE_API_KEY") {
+            config.serve_api_key = Some(api_key);
+        }
+
         config
     }
 
@@ -0,0 +1,434 @@
+//! Atom and RSS feeds of post listings.
+//!
+//! A feed is built from a subreddit's new posts, a user's submissions or a
+//! search. Each entry carries the post's title, author, permalink, its
+//! `selftext_html` (or a link to the target for link posts) and thumbnail.
+//! Entry IDs are the post's `t3_` fullname, as in Reddit's own feeds, so
+//! readers don't show a post twice when its title or score changes.
+
+use crate::client::listing::{ListingParams, Sort};
+use crate::client::subreddits::clean_subreddit_name;
+use crate::client::{RedditClient, RedditClientError, UserListing};
+use crate::models::listing::{Listing, Thing};
+use crate::models::RedditPostData;
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Reddit returns at most this many posts per listing page
+const PAGE_SIZE: u32 = 100;
+
+/// Namespace of the `media:thumbnail` element
+const MEDIA_NAMESPACE: &str = "http://search.yahoo.com/mrss/";
+
+/// Feed formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeedFormat {
+    #[default]
+    Atom,
+    Rss,
+}
+
+impl FeedFormat {
+    /// The Content-Type to serve this format with
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+        }
+    }
+}
+
+impl fmt::Display for FeedFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FeedFormat::Atom => write!(f, "atom"),
+            FeedFormat::Rss => write!(f, "rss"),
+        }
+    }
+}
+
+impl FromStr for FeedFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "atom" => Ok(FeedFormat::Atom),
+            "rss" => Ok(FeedFormat::Rss),
+            other => Err(format!(
+                "Unknown feed format '{}', expected atom or rss",
+                other
+            )),
+        }
+    }
+}
+
+/// The listing a feed is built from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedSource {
+    /// A subreddit's newest posts
+    Subreddit(String),
+    /// A user's newest submissions
+    User(String),
+    /// Newest posts matching a search, optionally within one subreddit
+    Search {
+        query: String,
+        subreddit: Option<String>,
+    },
+}
+
+impl FeedSource {
+    /// The feed's title
+    pub fn title(&self) -> String {
+        match self {
+            FeedSource::Subreddit(subreddit) => {
+                format!("r/{}: new posts", clean_subreddit_name(subreddit))
+            }
+            FeedSource::User(user) => format!("u/{}: submitted posts", user),
+            FeedSource::Search {
+                query,
+                subreddit: Some(subreddit),
+            } => format!(
+                "r/{}: search for \"{}\"",
+                clean_subreddit_name(subreddit),
+                query
+            ),
+            FeedSource::Search {
+                query,
+                subreddit: None,
+            } => format!("Reddit: search for \"{}\"", query),
+        }
+    }
+
+    /// The page on Reddit the feed mirrors; also the feed's ID
+    pub fn link(&self) -> String {
+        match self {
+            FeedSource::Subreddit(subreddit) => {
+                format!(
+                    "https://www.reddit.com/r/{}/new/",
+                    clean_subreddit_name(subreddit)
+                )
+            }
+            FeedSource::User(user) => format!("https://www.reddit.com/user/{}/submitted/", user),
+            FeedSource::Search { query, subreddit } => {
+                let mut link = url::Url::parse("https://www.reddit.com/search/").unwrap();
+                if let Some(subreddit) = subreddit {
+                    link.set_path(&format!("/r/{}/search/", clean_subreddit_name(subreddit)));
+                    link.query_pairs_mut().append_pair("restrict_sr", "1");
+                }
+                link.query_pairs_mut()
+                    .append_pair("q", query)
+                    .append_pair("sort", "new");
+                link.to_string()
+            }
+        }
+    }
+
+    /// Fetch the newest posts for this source, a page at a time until `limit`
+    /// posts are found or the listing runs out
+    pub async fn fetch(
+        &self,
+        client: &RedditClient,
+        limit: u32,
+    ) -> Result<Vec<RedditPostData>, RedditClientError> {
+        let mut posts = Vec::new();
+        let mut after = None;
+        while posts.len() < limit as usize {
+            let page_size = (limit - posts.len() as u32).min(PAGE_SIZE);
+            let (page, next) = self.fetch_page(client, page_size, after).await?;
+            let empty = page.is_empty();
+            posts.extend(page);
+            match next {
+                Some(next) if !empty => after = Some(next),
+                _ => break,
+            }
+        }
+        posts.truncate(limit as usize);
+        Ok(posts)
+    }
+
+    /// Fetch one page of posts and the cursor for the next one
+    async fn fetch_page(
+        &self,
+        client: &RedditClient,
+        limit: u32,
+        after: Option<String>,
+    ) -> Result<(Vec<RedditPostData>, Option<String>), RedditClientError> {
+        let mut params = ListingParams {
+            after,
+            ..ListingParams::with_limit(limit)
+        };
+        match self {
+            FeedSource::Subreddit(subreddit) => {
+                let listing = client
+                    .fetch_new_posts_after(
+                        clean_subreddit_name(subreddit),
+                        limit as i32,
+                        params.after.as_deref(),
+                    )
+                    .await?;
+                let posts = listing
+                    .data
+                    .children
+                    .into_iter()
+                    .map(|entity| entity.data)
+                    .collect();
+                Ok((posts, listing.data.after))
+            }
+            FeedSource::User(user) => {
+                params.sort = Some(Sort::New);
+                let listing = client
+                    .fetch_user_listing(user, UserListing::Submitted, &params)
+                    .await?;
+                let after = listing.data.after.clone();
+                Ok((posts(listing), after))
+            }
+            FeedSource::Search { query, subreddit } => {
+                params.sort = Some(Sort::New);
+                let listing = client
+                    .search_posts(query, subreddit.as_deref(), &params)
+                    .await?;
+                let after = listing.data.after.clone();
+                Ok((posts(listing), after))
+            }
+        }
+    }
+}
+
+/// The posts in a mixed listing
+fn posts(listing: Listing<Thing>) -> Vec<RedditPostData> {
+    listing
+        .data
+        .children
+        .into_iter()
+        .filter_map(|thing| match thing {
+            Thing::Link(post) => Some(*post),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Render posts as a feed document
+pub fn render(source: &FeedSource, posts: &[RedditPostData], format: FeedFormat) -> String {
+    match format {
+        FeedFormat::Atom => render_atom(source, posts),
+        FeedFormat::Rss => render_rss(source, posts),
+    }
+}
+
+fn render_atom(source: &FeedSource, posts: &[RedditPostData]) -> String {
+    use atom_syndication::{Content, Entry, Feed, Link, Person, Text};
+
+    let entries: Vec<Entry> = posts
+        .iter()
+        .map(|post| {
+            let created = post_time(post);
+            Entry {
+                id: format!("t3_{}", post.id),
+                title: Text::plain(post.title.clone()),
+                updated: edited_time(post).unwrap_or(created),
+                published: Some(created),
+                authors: vec![Person {
+                    name: format!("/u/{}", post.author),
+                    uri: Some(format!("https://www.reddit.com/user/{}", post.author)),
+                    ..Default::default()
+                }],
+                links: vec![Link {
+                    href: permalink(post),
+                    ..Default::default()
+                }],
+                content: Some(Content {
+                    value: Some(content_html(post)),
+                    content_type: Some("html".to_string()),
+                    ..Default::default()
+                }),
+                extensions: thumbnail(post)
+                    .map(|url| media_thumbnail(url, atom_extension))
+                    .unwrap_or_default(),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    let feed = Feed {
+        id: source.link(),
+        title: Text::plain(source.title()),
+        updated: entries
+            .iter()
+            .map(|entry| entry.updated)
+            .max()
+            .unwrap_or_else(|| Utc::now().fixed_offset()),
+        links: vec![Link {
+            href: source.link(),
+            rel: "alternate".to_string(),
+            ..Default::default()
+        }],
+        namespaces: media_namespace(),
+        entries,
+        ..Default::default()
+    };
+    feed.to_string()
+}
+
+fn render_rss(source: &FeedSource, posts: &[RedditPostData]) -> String {
+    use rss::{Channel, Guid, Item};
+
+    let items: Vec<Item> = posts
+        .iter()
+        .map(|post| Item {
+            guid: Some(Guid {
+                value: format!("t3_{}", post.id),
+                permalink: false,
+            }),
+            title: Some(post.title.clone()),
+            link: Some(permalink(post)),
+            // RSS wants an email address here; Reddit's own feeds use dc:creator instead
+            dublin_core_ext: Some(rss::extension::dublincore::DublinCoreExtension {
+                creators: vec![format!("/u/{}", post.author)],
+                ..Default::default()
+            }),
+            pub_date: Some(post_time(post).to_rfc2822()),
+            description: Some(content_html(post)),
+            comments: Some(permalink(post)),
+            extensions: thumbnail(post)
+                .map(|url| media_thumbnail(url, rss_extension))
+                .unwrap_or_default(),
+            ..Default::default()
+        })
+        .collect();
+
+    let mut namespaces = media_namespace();
+    namespaces.insert(
+        "dc".to_string(),
+        rss::extension::dublincore::NAMESPACE.to_string(),
+    );
+    let channel = Channel {
+        title: source.title(),
+        link: source.link(),
+        description: source.title(),
+        last_build_date: posts
+            .iter()
+            .map(post_time)
+            .max()
+            .map(|time| time.to_rfc2822()),
+        namespaces,
+        items,
+        ..Default::default()
+    };
+    channel.to_string()
+}
+
+/// The entry body: the thumbnail, then the post text or a link to its target
+fn content_html(post: &RedditPostData) -> String {
+    let mut html = String::new();
+    if let Some(url) = thumbnail(post) {
+        html.push_str(&format!(
+            "<a href=\"{}\"><img src=\"{}\" alt=\"{}\" title=\"{}\" /></a> ",
+            escape(&permalink(post)),
+            escape(url),
+            escape(&post.title),
+            escape(&post.title)
+        ));
+    }
+
+    match post
+        .selftext_html
+        .as_deref()
+        .filter(|text| !text.is_empty())
+    {
+        Some(text) => html.push_str(&unescape_html(text)),
+        None if !post.is_self => html.push_str(&format!(
+            "<p><a href=\"{}\">[link]</a></p>",
+            escape(&post.url)
+        )),
+        None => {}
+    }
+
+    html.push_str(&format!(
+        "<p>submitted by <a href=\"https://www.reddit.com/user/{}\">/u/{}</a> to \
+         <a href=\"https://www.reddit.com/r/{}/\">r/{}</a> · \
+         <a href=\"{}\">[{} comments]</a></p>",
+        escape(&post.author),
+        escape(&post.author),
+        escape(&post.subreddit),
+        escape(&post.subreddit),
+        escape(&permalink(post)),
+        post.num_comments
+    ));
+    html
+}
+
+fn permalink(post: &RedditPostData) -> String {
+    format!("https://www.reddit.com{}", post.permalink)
+}
+
+/// The post's thumbnail, if it has a real image rather than "self", "default" or "nsfw"
+fn thumbnail(post: &RedditPostData) -> Option<&str> {
+    Some(post.thumbnail.as_str()).filter(|url| url.starts_with("http"))
+}
+
+fn post_time(post: &RedditPostData) -> DateTime<FixedOffset> {
+    timestamp(post.created_utc).unwrap_or_else(|| Utc::now().fixed_offset())
+}
+
+/// When the post was last edited; `edited` is `false` or a timestamp
+fn edited_time(post: &RedditPostData) -> Option<DateTime<FixedOffset>> {
+    post.edited.as_f64().and_then(timestamp)
+}
+
+fn timestamp(seconds: f64) -> Option<DateTime<FixedOffset>> {
+    Utc.timestamp_opt(seconds as i64, 0)
+        .single()
+        .map(|time| time.fixed_offset())
+}
+
+fn media_namespace() -> BTreeMap<String, String> {
+    BTreeMap::from([("media".to_string(), MEDIA_NAMESPACE.to_string())])
+}
+
+/// A `media:thumbnail` extension map, built with either crate's extension type
+fn media_thumbnail<E>(
+    url: &str,
+    extension: fn(&str) -> E,
+) -> BTreeMap<String, BTreeMap<String, Vec<E>>> {
+    BTreeMap::from([(
+        "media".to_string(),
+        BTreeMap::from([("thumbnail".to_string(), vec![extension(url)])]),
+    )])
+}
+
+fn atom_extension(url: &str) -> atom_syndication::extension::Extension {
+    atom_syndication::extension::Extension {
+        name: "media:thumbnail".to_string(),
+        attrs: BTreeMap::from([("url".to_string(), url.to_string())]),
+        ..Default::default()
+    }
+}
+
+fn rss_extension(url: &str) -> rss::extension::Extension {
+    rss::extension::Extension {
+        name: "media:thumbnail".to_string(),
+        attrs: BTreeMap::from([("url".to_string(), url.to_string())]),
+        ..Default::default()
+    }
+}
+
+/// `selftext_html` comes entity-escaped unless the request asked for `raw_json`
+fn unescape_html(text: &str) -> String {
+    if !text.starts_with("&lt;") {
+        return text.to_string();
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
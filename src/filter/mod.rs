@@ -0,0 +1,163 @@
+//! Client-side filtering of post listings
+//!
+//! Reddit's `new` listings can't be filtered server-side, so `posts` fetches
+//! pages and keeps the posts matching a [`PostFilter`] built from the
+//! `--min-score`, `--max-age`, `--flair`, `--domain`, `--exclude-nsfw`,
+//! `--match` and `--author` flags.
+
+use crate::client::RedditClientError;
+use crate::models::RedditPostData;
+use crate::rules::RuleItem;
+use chrono::{Duration, Utc};
+use regex::{Regex, RegexBuilder};
+
+/// Conditions a post must meet to be shown; unset conditions match everything
+#[derive(Debug, Clone, Default)]
+pub struct PostFilter {
+    /// Minimum post score
+    pub min_score: Option<i32>,
+    /// Maximum time since the post was created
+    pub max_age: Option<Duration>,
+    /// Case-insensitive regex matched against the post flair text
+    pub flair: Option<Regex>,
+    /// Link domains; subdomains match too, and self posts have the domain `self.{subreddit}`
+    pub domains: Vec<String>,
+    /// Hide posts marked NSFW
+    pub exclude_nsfw: bool,
+    /// Case-insensitive regex matched against the title and self text
+    pub pattern: Option<Regex>,
+    /// Authors to keep, compared case-insensitively
+    pub authors: Vec<String>,
+}
+
+impl PostFilter {
+    /// Build a filter from command line values, checking the age and regexes.
+    ///
+    /// `max_age` is a duration such as "30m", "2h", "3d" or "1w".
+    pub fn new(
+        min_score: Option<i32>,
+        max_age: Option<&str>,
+        flair: Option<&str>,
+        domains: Vec<String>,
+        exclude_nsfw: bool,
+        pattern: Option<&str>,
+        authors: Vec<String>,
+    ) -> Result<Self, RedditClientError> {
+        Ok(Self {
+            min_score,
+            max_age: max_age.map(parse_age).transpose()?,
+            flair: flair.map(|flair| compile("--flair", flair)).transpose()?,
+            domains: domains
+                .iter()
+                .map(|domain| domain.trim_start_matches("www.").to_lowercase())
+                .collect(),
+            exclude_nsfw,
+            pattern: pattern
+                .map(|pattern| compile("--match", pattern))
+                .transpose()?,
+            authors: authors
+                .iter()
+                .map(|author| author.trim_start_matches("u/").to_lowercase())
+                .collect(),
+        })
+    }
+
+    /// Whether any condition is set
+    pub fn is_active(&self) -> bool {
+        self.min_score.is_some()
+            || self.max_age.is_some()
+            || self.flair.is_some()
+            || !self.domains.is_empty()
+            || self.exclude_nsfw
+            || self.pattern.is_some()
+            || !self.authors.is_empty()
+    }
+
+    /// Check a post against every condition
+    pub fn matches(&self, post: &RedditPostData) -> bool {
+        if self.min_score.is_some_and(|min| post.score < min) {
+            return false;
+        }
+        if self.is_too_old(post) {
+            return false;
+        }
+        if let Some(flair) = &self.flair {
+            if !post
+                .link_flair_text
+                .as_deref()
+                .is_some_and(|text| flair.is_match(text))
+            {
+                return false;
+            }
+        }
+        if !self.domains.is_empty() {
+            let Some(domain) = RuleItem::Submission(post).domain() else {
+                return false;
+            };
+            let listed = self
+                .domains
+                .iter()
+                .any(|listed| domain == *listed || domain.ends_with(&format!(".{}", listed)));
+            if !listed {
+                return false;
+            }
+        }
+        if self.exclude_nsfw && post.over_18 {
+            return false;
+        }
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(&post.title) && !pattern.is_match(&post.selftext) {
+                return false;
+            }
+        }
+        if !self.authors.is_empty() && !self.authors.contains(&post.author.to_lowercase()) {
+            return false;
+        }
+
+        true
+    }
+
+    /// Whether a post is older than `max_age`
+    pub fn is_too_old(&self, post: &RedditPostData) -> bool {
+        self.max_age.is_some_and(|max_age| {
+            let age_seconds = Utc::now().timestamp() as f64 - post.created_utc;
+            age_seconds > max_age.num_seconds() as f64
+        })
+    }
+}
+
+/// Parse an age such as "90s", "30m", "2h", "3d" or "1w"
+pub fn parse_age(age: &str) -> Result<Duration, RedditClientError> {
+    let invalid = || {
+        RedditClientError::ApiError(format!(
+            "Invalid age '{}', expected a number and unit like 30m, 2h, 3d or 1w",
+            age
+        ))
+    };
+
+    let age = age.trim();
+    let split = age
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (amount, unit) = age.split_at(split);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+
+    // The try_ constructors return None instead of panicking on huge amounts
+    let duration = match unit {
+        "s" => Duration::try_seconds(amount),
+        "m" => Duration::try_minutes(amount),
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    };
+    duration.ok_or_else(invalid)
+}
+
+/// Compile a case-insensitive regex given on the command line
+fn compile(flag: &str, pattern: &str) -> Result<Regex, RedditClientError> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .build()
+        .map_err(|e| RedditClientError::ApiError(format!("Invalid {} regex: {}", flag, e)))
+}
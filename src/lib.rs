@@ -3,33 +3,98 @@
 //! This library provides a convenient interface for interacting with the Reddit API,
 //! allowing you to fetch posts, create new posts, add comments, and more.
 
+pub mod archive;
+pub mod batch;
+pub mod bot;
 pub mod client;
 pub mod config;
+pub mod feed;
+pub mod filter;
 pub mod models;
 pub mod operations;
+pub mod output;
+pub mod rules;
+pub mod schedule;
+pub mod serve;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+pub mod tui;
 
 // Re-export the most commonly used types for convenience
 pub use client::RedditClient;
 pub use client::RedditClientError;
 pub use config::AppConfig;
+pub use filter::PostFilter;
+pub use operations::actions::{ActionOperation, ActionOptions, ActionResult, ThingAction};
 pub use operations::api_create::{ApiCreateOperation, ApiCreateOptions, ApiCreateResult};
+pub use operations::archive::{
+    ArchiveCounts, ArchiveSyncOperation, ArchiveSyncOptions, ArchiveSyncResult,
+};
+pub use operations::batch::{BatchOperation, BatchOptions, BatchResult};
 pub use operations::browser_create::{
     BrowserCreateOperation, BrowserCreateOptions, BrowserCreateResult,
 };
 pub use operations::comment::{CommentOperation, CommentOptions, CommentResult};
 pub use operations::create::{CreateOperation, CreateOptions, CreateResult};
+pub use operations::edit::{EditOperation, EditOptions, EditResult};
+pub use operations::feed::{FeedOperation, FeedOptions, FeedResult};
+pub use operations::inbox::{InboxOperation, InboxOptions, InboxResult};
+pub use operations::message::{MessageOperation, MessageOptions, MessageResult};
+pub use operations::moderation::{ModAction, ModerationOperation, ModerationOptions};
+pub use operations::modmail::{ModmailOperation, ModmailOptions, ModmailRequest, ModmailResult};
+pub use operations::modqueue::{ModqueueOperation, ModqueueOptions, ModqueueResult};
 pub use operations::posts::{PostsOperation, PostsOptions, PostsResult};
+pub use operations::relationships::{
+    RelationshipOperation, RelationshipOptions, RelationshipRequest, RelationshipResult,
+};
+pub use operations::rules::{
+    RulesRunOperation, RulesRunOptions, RulesTestCase, RulesTestOperation, RulesTestOptions,
+    RulesTestResult,
+};
+pub use operations::schedule::{
+    ScheduleOperation, ScheduleOptions, ScheduleRequest, ScheduleResult, ScheduleRunOperation,
+    ScheduleRunOptions,
+};
+pub use operations::serve::{ServeOperation, ServeOptions};
+pub use operations::subreddit::{
+    SubredditInfoOperation, SubredditInfoOptions, SubredditInfoResult,
+};
 pub use operations::token_create::{TokenCreateOperation, TokenCreateOptions, TokenCreateResult};
+pub use operations::tui::{TuiOperation, TuiOptions};
+pub use operations::user::{UserOperation, UserOptions, UserResult};
 pub use operations::user_create::{UserCreateOperation, UserCreateOptions, UserCreateResult};
+pub use operations::wiki::{
+    WikiEditOperation, WikiEditOptions, WikiEditPreview, WikiEditResult, WikiOperation,
+    WikiOptions, WikiRequest, WikiResult,
+};
+pub use output::{CommandOutput, OutputFormat};
 
 // Re-export the client-aware handler functions for convenient usage
+pub use operations::actions::handle_action_command_with_client;
 pub use operations::api_create::handle_api_create_command_with_client;
+pub use operations::archive::handle_archive_sync_command_with_client;
+pub use operations::batch::handle_batch_run_command_with_client;
 pub use operations::browser_create::handle_browser_create_command_with_client;
 pub use operations::comment::{
     handle_browser_comment_command_with_client, handle_comment_command_with_client,
     handle_user_comment_command_with_client,
 };
 pub use operations::create::handle_create_command_with_client;
+pub use operations::edit::handle_edit_command_with_client;
+pub use operations::feed::handle_feed_command_with_client;
+pub use operations::inbox::handle_inbox_command_with_client;
+pub use operations::message::handle_message_send_command_with_client;
+pub use operations::moderation::handle_mod_command_with_client;
+pub use operations::modmail::handle_modmail_command_with_client;
+pub use operations::modqueue::handle_modqueue_command_with_client;
 pub use operations::posts::handle_posts_command_with_client;
+pub use operations::relationships::handle_relationship_command_with_client;
+pub use operations::rules::{handle_rules_run_command_with_client, handle_rules_test_command};
+pub use operations::schedule::{handle_schedule_command, handle_schedule_run_command_with_client};
+pub use operations::serve::handle_serve_command_with_client;
+pub use operations::subreddit::handle_subreddit_info_command_with_client;
 pub use operations::token_create::handle_token_create_command_with_client;
+pub use operations::tui::handle_tui_command_with_client;
+pub use operations::user::handle_user_command_with_client;
 pub use operations::user_create::handle_user_create_command_with_client;
+pub use operations::wiki::{handle_wiki_command_with_client, handle_wiki_edit_command_with_client};
@@ -1,21 +1,54 @@
-use crate::cli::{Cli, Commands};
+use crate::cli::{
+    ArchiveCommands, BatchCommands, Cli, Commands, MessageCommands, ModCommands, ModmailCommands,
+    RulesCommands, ScheduleCommands, SubredditCommands, WikiCommands,
+};
 use clap::Parser;
 use log::error;
 use redrust::{
+    client::{
+        CacheMode, Cassette, CassetteMode, RelationshipDetails, RelationshipKind, ResponseCache,
+    },
+    feed::FeedSource,
+    filter::{parse_age, PostFilter},
     operations::{
+        actions::{handle_action_command_with_client, ThingAction},
         api_create::handle_api_create_command_with_client,
+        archive::handle_archive_sync_command_with_client,
+        batch::handle_batch_run_command_with_client,
         browser_create::handle_browser_create_command_with_client,
         comment::{
             handle_browser_comment_command_with_client, handle_comment_command_with_client,
             handle_user_comment_command_with_client,
         },
         create::handle_create_command_with_client,
+        edit::handle_edit_command_with_client,
+        feed::handle_feed_command_with_client,
+        inbox::handle_inbox_command_with_client,
+        message::handle_message_send_command_with_client,
+        moderation::{handle_mod_command_with_client, ModAction},
+        modmail::{handle_modmail_command_with_client, ModmailRequest},
+        modqueue::handle_modqueue_command_with_client,
         posts::handle_posts_command_with_client,
+        relationships::{handle_relationship_command_with_client, RelationshipRequest},
+        rules::{handle_rules_run_command_with_client, handle_rules_test_command},
+        schedule::{
+            handle_schedule_command, handle_schedule_run_command_with_client, ScheduleRequest,
+        },
+        serve::handle_serve_command_with_client,
+        subreddit::handle_subreddit_info_command_with_client,
         token_create::handle_token_create_command_with_client,
+        tui::handle_tui_command_with_client,
+        user::handle_user_command_with_client,
         user_create::handle_user_create_command_with_client,
+        wiki::{
+            handle_wiki_command_with_client, handle_wiki_edit_command_with_client, WikiRequest,
+        },
     },
+    output::time::{set_time_display, TimeDisplay},
     AppConfig,
 };
+use std::path::PathBuf;
+use std::time::Duration;
 
 mod cli;
 
@@ -30,33 +63,148 @@ async fn main() {
     // Create a RedditClient with the loaded configuration
     // This will be passed to all operation handlers to ensure
     // consistent configuration and credentials
-    let client = config.create_client();
+    let mut client = config.create_client();
 
     let cli = Cli::parse();
+    let output = cli.output;
+
+    // Flags take precedence over the configured time display
+    match TimeDisplay::new(
+        cli.tz.as_deref().or(config.timezone.as_deref()),
+        cli.time_format
+            .clone()
+            .or_else(|| config.time_format.clone()),
+        cli.relative_time || config.relative_time,
+    ) {
+        Ok(display) => set_time_display(display),
+        Err(err) => {
+            error!("Invalid time display settings: {:?}", err);
+            std::process::exit(1);
+        }
+    }
+
+    // --offline and --refresh only make sense with the cache, so they enable it
+    if cli.cache || cli.offline || cli.refresh || config.cache {
+        let ttl = match cli
+            .cache_ttl
+            .as_deref()
+            .or(config.cache_ttl.as_deref())
+            .map(parse_age)
+            .transpose()
+        {
+            Ok(ttl) => ttl.map_or(Duration::from_secs(300), |ttl| {
+                ttl.to_std().unwrap_or_default()
+            }),
+            Err(err) => {
+                error!("Invalid cache settings: {:?}", err);
+                std::process::exit(1);
+            }
+        };
+        let dir = cli
+            .cache_dir
+            .clone()
+            .or_else(|| config.cache_dir.as_ref().map(PathBuf::from))
+            .unwrap_or_else(ResponseCache::default_dir);
+        let mode = if cli.offline {
+            CacheMode::Offline
+        } else if cli.refresh {
+            CacheMode::Refresh
+        } else {
+            CacheMode::ReadWrite
+        };
+        client = client.with_cache(ResponseCache::new(dir, ttl, mode));
+    }
+
+    // Record or replay HTTP traffic, e.g. for tests that must not touch the network
+    if let Some(path) = &config.cassette {
+        let cassette = config
+            .cassette_mode
+            .as_deref()
+            .unwrap_or("replay")
+            .parse::<CassetteMode>()
+            .and_then(|mode| Cassette::open(path, mode));
+        match cassette {
+            Ok(cassette) => client = client.with_cassette(cassette),
+            Err(err) => {
+                error!("Invalid cassette settings: {:?}", err);
+                std::process::exit(1);
+            }
+        }
+    }
 
     let result = match cli.command {
         Commands::Posts {
             count,
             subreddit,
             brief,
-        } => handle_posts_command_with_client(count, subreddit, brief, client.clone()).await,
+            follow,
+            min_score,
+            max_age,
+            flair,
+            domains,
+            exclude_nsfw,
+            pattern,
+            authors,
+        } => {
+            match PostFilter::new(
+                min_score,
+                max_age.as_deref(),
+                flair.as_deref(),
+                domains,
+                exclude_nsfw,
+                pattern.as_deref(),
+                authors,
+            ) {
+                Ok(filter) => {
+                    handle_posts_command_with_client(
+                        count,
+                        subreddit,
+                        brief,
+                        follow,
+                        filter,
+                        output,
+                        client.clone(),
+                    )
+                    .await
+                }
+                Err(err) => Err(err),
+            }
+        }
 
         Commands::Create {
             subreddit,
             title,
             text,
+            validate,
         } => {
             // Use the properly configured client that already has the credentials
-            handle_create_command_with_client(subreddit, title, text, client.clone()).await
+            handle_create_command_with_client(
+                subreddit,
+                title,
+                text,
+                validate,
+                output,
+                client.clone(),
+            )
+            .await
         }
 
         Commands::UserCreate {
             subreddit,
             title,
             text,
+            validate,
         } => {
             // Use the fully configured client
-            handle_user_create_command_with_client(subreddit, title, text, client.clone()).await
+            handle_user_create_command_with_client(
+                subreddit,
+                title,
+                text,
+                validate,
+                output,
+                client.clone(),
+            )
+            .await
         }
 
         Commands::BrowserCreate {
@@ -64,6 +212,7 @@ async fn main() {
             title,
             text,
             port,
+            validate,
         } => {
             // Use port from CLI or config, with fully configured client
             let port_value = config.oauth_port.or(port);
@@ -73,6 +222,8 @@ async fn main() {
                 title,
                 text,
                 port_value,
+                validate,
+                output,
                 client.clone(),
             )
             .await
@@ -83,6 +234,7 @@ async fn main() {
             title,
             text,
             expires_in,
+            validate,
         } => {
             // Use the fully configured client with expires_in from CLI or default
             handle_token_create_command_with_client(
@@ -90,6 +242,8 @@ async fn main() {
                 title,
                 text,
                 expires_in,
+                validate,
+                output,
                 client.clone(),
             )
             .await
@@ -99,14 +253,23 @@ async fn main() {
             subreddit,
             title,
             text,
+            validate,
         } => {
             // Use the fully configured client
-            handle_api_create_command_with_client(subreddit, title, text, client.clone()).await
+            handle_api_create_command_with_client(
+                subreddit,
+                title,
+                text,
+                validate,
+                output,
+                client.clone(),
+            )
+            .await
         }
 
         Commands::Comment { thing_id, text } => {
             // Use the fully configured client
-            handle_comment_command_with_client(thing_id, text, client.clone()).await
+            handle_comment_command_with_client(thing_id, text, output, client.clone()).await
         }
 
         Commands::BrowserComment {
@@ -117,14 +280,556 @@ async fn main() {
             // Use port from CLI or config with fully configured client
             let port_value = config.oauth_port.or(port);
 
-            handle_browser_comment_command_with_client(thing_id, text, port_value, client.clone())
-                .await
+            handle_browser_comment_command_with_client(
+                thing_id,
+                text,
+                port_value,
+                output,
+                client.clone(),
+            )
+            .await
         }
 
         Commands::UserComment { thing_id, text } => {
             // Use the fully configured client
-            handle_user_comment_command_with_client(thing_id, text, client.clone()).await
+            handle_user_comment_command_with_client(thing_id, text, output, client.clone()).await
+        }
+
+        Commands::User {
+            username,
+            what,
+            count,
+            sort,
+            time,
+        } => {
+            handle_user_command_with_client(
+                username,
+                what,
+                count,
+                sort,
+                time,
+                output,
+                client.clone(),
+            )
+            .await
+        }
+
+        Commands::Vote { direction, ids } => {
+            handle_action_command_with_client(
+                ThingAction::Vote(direction),
+                ids,
+                output,
+                client.clone(),
+            )
+            .await
+        }
+
+        Commands::Save { ids, category } => {
+            handle_action_command_with_client(
+                ThingAction::Save { category },
+                ids,
+                output,
+                client.clone(),
+            )
+            .await
+        }
+
+        Commands::Unsave { ids } => {
+            handle_action_command_with_client(ThingAction::Unsave, ids, output, client.clone())
+                .await
+        }
+
+        Commands::Hide { ids } => {
+            handle_action_command_with_client(ThingAction::Hide, ids, output, client.clone()).await
+        }
+
+        Commands::Unhide { ids } => {
+            handle_action_command_with_client(ThingAction::Unhide, ids, output, client.clone())
+                .await
+        }
+
+        Commands::Report { reason, ids } => {
+            handle_action_command_with_client(
+                ThingAction::Report { reason },
+                ids,
+                output,
+                client.clone(),
+            )
+            .await
+        }
+
+        Commands::Edit {
+            thing_id,
+            file,
+            yes,
+        } => handle_edit_command_with_client(thing_id, file, yes, output, client.clone()).await,
+
+        Commands::Delete { ids } => {
+            handle_action_command_with_client(ThingAction::Delete, ids, output, client.clone())
+                .await
+        }
+
+        Commands::Inbox {
+            folder,
+            count,
+            mark_read,
+        } => {
+            handle_inbox_command_with_client(folder, count, mark_read, output, client.clone()).await
+        }
+
+        Commands::Message { command } => match command {
+            MessageCommands::Send {
+                to,
+                subject,
+                text,
+                file,
+            } => {
+                handle_message_send_command_with_client(
+                    to,
+                    subject,
+                    text,
+                    file,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+            MessageCommands::Reply { thing_id, text } => {
+                // Replies to messages go through the regular comment endpoint
+                handle_comment_command_with_client(thing_id, text, output, client.clone()).await
+            }
+            MessageCommands::Read { ids } => {
+                handle_action_command_with_client(
+                    ThingAction::MarkRead,
+                    ids,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+            MessageCommands::Unread { ids } => {
+                handle_action_command_with_client(
+                    ThingAction::MarkUnread,
+                    ids,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+        },
+
+        Commands::Mod { command } => match command {
+            ModCommands::Approve { ids } => {
+                handle_mod_command_with_client(ModAction::Approve, ids, output, client.clone())
+                    .await
+            }
+            ModCommands::Remove {
+                ids,
+                spam,
+                reason_id,
+                note,
+            } => {
+                let action = ModAction::Remove {
+                    spam,
+                    reason_id,
+                    mod_note: note,
+                };
+                handle_mod_command_with_client(action, ids, output, client.clone()).await
+            }
+            ModCommands::Lock { ids } => {
+                handle_mod_command_with_client(ModAction::Lock, ids, output, client.clone()).await
+            }
+            ModCommands::Unlock { ids } => {
+                handle_mod_command_with_client(ModAction::Unlock, ids, output, client.clone()).await
+            }
+            ModCommands::Sticky { ids, slot, unset } => {
+                let action = if unset {
+                    ModAction::Unsticky
+                } else {
+                    ModAction::Sticky { slot }
+                };
+                handle_mod_command_with_client(action, ids, output, client.clone()).await
+            }
+            ModCommands::Distinguish { ids, how, sticky } => {
+                let action = ModAction::Distinguish { how, sticky };
+                handle_mod_command_with_client(action, ids, output, client.clone()).await
+            }
+            ModCommands::Nsfw { ids, unset } => {
+                let action = if unset {
+                    ModAction::UnmarkNsfw
+                } else {
+                    ModAction::MarkNsfw
+                };
+                handle_mod_command_with_client(action, ids, output, client.clone()).await
+            }
+            ModCommands::Spoiler { ids, unset } => {
+                let action = if unset {
+                    ModAction::Unspoiler
+                } else {
+                    ModAction::Spoiler
+                };
+                handle_mod_command_with_client(action, ids, output, client.clone()).await
+            }
+
+            // User-relationship subcommands go through the friend/unfriend endpoints
+            ModCommands::Ban {
+                subreddit,
+                username,
+                days,
+                reason,
+                message,
+                note,
+            } => {
+                let request = RelationshipRequest::Add {
+                    username,
+                    kind: RelationshipKind::Banned,
+                    details: RelationshipDetails {
+                        duration_days: days,
+                        reason,
+                        message,
+                        note,
+                    },
+                };
+                handle_relationship_command_with_client(subreddit, request, output, client.clone())
+                    .await
+            }
+            ModCommands::Unban {
+                subreddit,
+                username,
+            } => {
+                let request = RelationshipRequest::Remove {
+                    username,
+                    kind: RelationshipKind::Banned,
+                };
+                handle_relationship_command_with_client(subreddit, request, output, client.clone())
+                    .await
+            }
+            ModCommands::Mute {
+                subreddit,
+                username,
+                note,
+            } => {
+                let request = RelationshipRequest::Add {
+                    username,
+                    kind: RelationshipKind::Muted,
+                    details: RelationshipDetails {
+                        note,
+                        ..RelationshipDetails::default()
+                    },
+                };
+                handle_relationship_command_with_client(subreddit, request, output, client.clone())
+                    .await
+            }
+            ModCommands::Unmute {
+                subreddit,
+                username,
+            } => {
+                let request = RelationshipRequest::Remove {
+                    username,
+                    kind: RelationshipKind::Muted,
+                };
+                handle_relationship_command_with_client(subreddit, request, output, client.clone())
+                    .await
+            }
+            ModCommands::ApproveUser {
+                subreddit,
+                username,
+            } => {
+                let request = RelationshipRequest::Add {
+                    username,
+                    kind: RelationshipKind::Contributor,
+                    details: RelationshipDetails::default(),
+                };
+                handle_relationship_command_with_client(subreddit, request, output, client.clone())
+                    .await
+            }
+            ModCommands::UnapproveUser {
+                subreddit,
+                username,
+            } => {
+                let request = RelationshipRequest::Remove {
+                    username,
+                    kind: RelationshipKind::Contributor,
+                };
+                handle_relationship_command_with_client(subreddit, request, output, client.clone())
+                    .await
+            }
+            ModCommands::Users {
+                subreddit,
+                kind,
+                count,
+            } => {
+                let request = RelationshipRequest::List { kind, count };
+                handle_relationship_command_with_client(subreddit, request, output, client.clone())
+                    .await
+            }
+        },
+
+        Commands::Modmail { command } => {
+            let request = match command {
+                ModmailCommands::List {
+                    subreddits,
+                    state,
+                    count,
+                } => ModmailRequest::List {
+                    subreddits,
+                    state,
+                    count,
+                },
+                ModmailCommands::Show { id } => ModmailRequest::Show {
+                    conversation_id: id,
+                },
+                ModmailCommands::Reply {
+                    id,
+                    text,
+                    as_subreddit,
+                    private,
+                } => ModmailRequest::Reply {
+                    conversation_id: id,
+                    body: text,
+                    as_subreddit,
+                    internal: private,
+                },
+                ModmailCommands::Archive { ids } => ModmailRequest::Archive {
+                    conversation_ids: ids,
+                },
+                ModmailCommands::Unarchive { ids } => ModmailRequest::Unarchive {
+                    conversation_ids: ids,
+                },
+                ModmailCommands::Highlight { ids } => ModmailRequest::Highlight {
+                    conversation_ids: ids,
+                },
+                ModmailCommands::Unhighlight { ids } => ModmailRequest::Unhighlight {
+                    conversation_ids: ids,
+                },
+                ModmailCommands::Mute { id, days } => ModmailRequest::Mute {
+                    conversation_id: id,
+                    days,
+                },
+                ModmailCommands::Unmute { id } => ModmailRequest::Unmute {
+                    conversation_id: id,
+                },
+            };
+
+            handle_modmail_command_with_client(request, output, client.clone()).await
+        }
+
+        Commands::Modqueue {
+            subreddit,
+            listing,
+            count,
+            ids_only,
+        } => {
+            handle_modqueue_command_with_client(
+                subreddit,
+                listing,
+                count,
+                ids_only,
+                output,
+                client.clone(),
+            )
+            .await
+        }
+
+        Commands::Wiki { command } => match command {
+            WikiCommands::List { subreddit } => {
+                handle_wiki_command_with_client(
+                    subreddit,
+                    WikiRequest::List,
+                    None,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+            WikiCommands::Get {
+                subreddit,
+                page,
+                revision,
+                file,
+            } => {
+                let request = WikiRequest::Get { page, revision };
+                handle_wiki_command_with_client(subreddit, request, file, output, client.clone())
+                    .await
+            }
+            WikiCommands::Edit {
+                subreddit,
+                page,
+                file,
+                reason,
+                yes,
+            } => {
+                handle_wiki_edit_command_with_client(
+                    subreddit,
+                    page,
+                    file,
+                    reason,
+                    yes,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+            WikiCommands::History {
+                subreddit,
+                page,
+                count,
+            } => {
+                let request = WikiRequest::History { page, count };
+                handle_wiki_command_with_client(subreddit, request, None, output, client.clone())
+                    .await
+            }
+        },
+
+        Commands::Rules { command } => match command {
+            RulesCommands::Run {
+                rules,
+                subreddit,
+                dry_run,
+            } => {
+                handle_rules_run_command_with_client(
+                    rules,
+                    subreddit,
+                    dry_run,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+            RulesCommands::Test { rules, fixtures } => {
+                handle_rules_test_command(rules, fixtures, output)
+            }
+        },
+
+        Commands::Schedule { store, command } => match command {
+            ScheduleCommands::Add {
+                subreddit,
+                title,
+                at,
+                tz,
+                text,
+                text_file,
+            } => {
+                let request = ScheduleRequest::Add {
+                    subreddit,
+                    title,
+                    text,
+                    text_file,
+                    at,
+                    timezone: tz,
+                };
+                handle_schedule_command(store, request, output)
+            }
+            ScheduleCommands::List { all } => {
+                handle_schedule_command(store, ScheduleRequest::List { all }, output)
+            }
+            ScheduleCommands::Show { id } => {
+                handle_schedule_command(store, ScheduleRequest::Show { id }, output)
+            }
+            ScheduleCommands::Cancel { id } => {
+                handle_schedule_command(store, ScheduleRequest::Cancel { id }, output)
+            }
+            ScheduleCommands::Run { once, max_attempts } => {
+                handle_schedule_run_command_with_client(
+                    store,
+                    once,
+                    max_attempts,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+        },
+
+        Commands::Batch { command } => match command {
+            BatchCommands::Run {
+                plan,
+                results,
+                dry_run,
+                resume,
+                requests_per_minute,
+            } => {
+                handle_batch_run_command_with_client(
+                    plan,
+                    results,
+                    dry_run,
+                    resume,
+                    requests_per_minute,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+        },
+
+        Commands::Archive { command } => match command {
+            ArchiveCommands::Sync {
+                subreddit,
+                db,
+                full,
+                max_posts,
+                no_comments,
+                requests_per_minute,
+            } => {
+                handle_archive_sync_command_with_client(
+                    subreddit,
+                    db,
+                    full,
+                    max_posts,
+                    !no_comments,
+                    requests_per_minute,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+        },
+
+        Commands::Feed {
+            subreddit,
+            user,
+            search,
+            format,
+            limit,
+        } => {
+            let source = match (user, search) {
+                (Some(user), _) => FeedSource::User(user),
+                (None, Some(query)) => FeedSource::Search { query, subreddit },
+                (None, None) => FeedSource::Subreddit(subreddit.unwrap_or_default()),
+            };
+            handle_feed_command_with_client(source, format, limit, output, client.clone()).await
+        }
+
+        Commands::Serve {
+            bind,
+            audit_log,
+            requests_per_minute,
+        } => {
+            handle_serve_command_with_client(
+                bind,
+                config.serve_api_key.clone(),
+                audit_log,
+                requests_per_minute,
+                client.clone(),
+            )
+            .await
         }
+
+        Commands::Tui { subreddit, count } => {
+            handle_tui_command_with_client(subreddit, count, client.clone()).await
+        }
+
+        Commands::Subreddit { command } => match command {
+            SubredditCommands::Info { subreddit, sidebar } => {
+                handle_subreddit_info_command_with_client(
+                    subreddit,
+                    sidebar,
+                    output,
+                    client.clone(),
+                )
+                .await
+            }
+        },
     };
 
     if let Err(err) = result {
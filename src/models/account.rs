@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Response wrapper for `/user/{name}/about`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AccountResponse {
+    pub kind: String,
+    pub data: Account,
+}
+
+/// A Reddit account (kind `t2`)
+///
+/// Suspended and shadowbanned accounts only return a handful of fields,
+/// so everything except the name is optional or defaulted.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Account {
+    // Basic account information
+    pub name: String,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub created_utc: f64,
+
+    // Karma breakdown
+    #[serde(default)]
+    pub link_karma: i64,
+    #[serde(default)]
+    pub comment_karma: i64,
+    #[serde(default)]
+    pub total_karma: i64,
+    #[serde(default)]
+    pub awardee_karma: i64,
+    #[serde(default)]
+    pub awarder_karma: i64,
+
+    // Account status
+    #[serde(default)]
+    pub is_gold: bool,
+    #[serde(default)]
+    pub is_mod: bool,
+    #[serde(default)]
+    pub is_employee: bool,
+    #[serde(default)]
+    pub verified: bool,
+    pub has_verified_email: Option<bool>,
+    #[serde(default)]
+    pub is_suspended: bool,
+
+    // Profile information
+    pub icon_img: Option<String>,
+    pub subreddit: Option<serde_json::Value>,
+
+    // Additional fields we don't explicitly model
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, serde_json::Value>,
+}
+
+impl Account {
+    /// The account's fullname (`t2_` prefix plus ID)
+    pub fn fullname(&self) -> String {
+        format!("t2_{}", self.id)
+    }
+
+    /// Format the account for display with karma and age
+    pub fn format_summary(&self) -> String {
+        if self.is_suspended {
+            return format!("u/{} (suspended)\n", self.name);
+        }
+
+        let created = chrono::DateTime::from_timestamp(self.created_utc as i64, 0)
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let mut flags = Vec::new();
+        if self.is_mod {
+            flags.push("Moderator");
+        }
+        if self.is_gold {
+            flags.push("Premium");
+        }
+        if self.verified {
+            flags.push("Verified");
+        }
+        if self.is_employee {
+            flags.push("Admin");
+        }
+
+        let mut content = format!(
+            "u/{} ({})\nCreated: {}\nKarma: {} total ({} post, {} comment)\n",
+            self.name,
+            self.fullname(),
+            created,
+            self.total_karma,
+            self.link_karma,
+            self.comment_karma,
+        );
+        if !flags.is_empty() {
+            content.push_str(&format!("Flags: [{}]\n", flags.join(", ")));
+        }
+
+        content
+    }
+}
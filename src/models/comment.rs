@@ -0,0 +1,149 @@
+use super::report::{self, ModReport, UserReport};
+use super::RedditPostData;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A Reddit comment (kind `t1`)
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CommentData {
+    // Basic comment information
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub author: String,
+    pub author_fullname: Option<String>,
+    #[serde(default)]
+    pub body: String,
+    pub body_html: Option<String>,
+    #[serde(default)]
+    pub created_utc: f64,
+    #[serde(default)]
+    pub permalink: String,
+
+    // Thread information
+    #[serde(default)]
+    pub link_id: String,
+    #[serde(default)]
+    pub parent_id: String,
+    pub link_title: Option<String>,
+    pub link_permalink: Option<String>,
+    #[serde(default)]
+    pub subreddit: String,
+    #[serde(default)]
+    pub subreddit_name_prefixed: String,
+
+    // Comment metrics
+    #[serde(default)]
+    pub score: i32,
+    #[serde(default)]
+    pub score_hidden: bool,
+
+    // Comment status and moderation
+    #[serde(default = "default_edited_value")]
+    pub edited: serde_json::Value, // Can be boolean or timestamp
+    pub distinguished: Option<String>,
+    #[serde(default)]
+    pub stickied: bool,
+    #[serde(default)]
+    pub locked: bool,
+    pub num_reports: Option<i32>,
+    #[serde(default, deserialize_with = "report::reports_or_empty")]
+    pub user_reports: Vec<UserReport>,
+    #[serde(default, deserialize_with = "report::reports_or_empty")]
+    pub mod_reports: Vec<ModReport>,
+
+    // Inbox information, present when the comment arrives as a reply or mention
+    #[serde(default)]
+    pub new: bool,
+    pub context: Option<String>,
+
+    // Nested replies; an empty string when there are none, otherwise a listing
+    #[serde(default)]
+    pub replies: serde_json::Value,
+
+    // Additional fields we don't explicitly model
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, serde_json::Value>,
+}
+
+fn default_edited_value() -> serde_json::Value {
+    serde_json::Value::Bool(false)
+}
+
+impl CommentData {
+    /// Get the comment body on one line, truncated to `max_chars` characters
+    pub fn excerpt(&self, max_chars: usize) -> String {
+        let text = self.body.trim().replace('\n', " ");
+        if text.chars().count() > max_chars {
+            let mut excerpt = text
+                .chars()
+                .take(max_chars.saturating_sub(3))
+                .collect::<String>();
+            excerpt.push_str("...");
+            excerpt
+        } else {
+            text
+        }
+    }
+
+    /// Format a comment for display with important metadata
+    pub fn format_summary(&self) -> String {
+        let mut content = format!(
+            "Author: u/{}\nSubreddit: r/{}\nScore: {}\n",
+            self.author, self.subreddit, self.score
+        );
+
+        if let Some(title) = &self.link_title {
+            content.push_str(&format!("In thread: {}\n", title));
+        }
+
+        content.push_str("\nComment:\n---------\n");
+        content.push_str(&self.body);
+        content.push_str("\n---------\n");
+
+        content.push_str(&format!(
+            "\nPermalink: https://reddit.com{}",
+            self.permalink
+        ));
+
+        content
+    }
+}
+
+/// A post and its comment tree, as returned by `RedditClient::fetch_comments`
+#[derive(Serialize, Debug)]
+pub struct CommentThread {
+    pub post: Box<RedditPostData>,
+    pub comments: Vec<CommentNode>,
+    /// Top-level comments Reddit didn't include ("load more comments")
+    pub more: usize,
+}
+
+/// A comment and the replies loaded with it
+#[derive(Serialize, Debug)]
+pub struct CommentNode {
+    pub comment: CommentData,
+    pub replies: Vec<CommentNode>,
+    /// Replies Reddit didn't include ("load more comments")
+    pub more: usize,
+}
+
+impl CommentThread {
+    /// Every loaded comment in display order, with its depth in the tree
+    pub fn flatten(&self) -> Vec<(usize, &CommentData)> {
+        let mut flat = Vec::new();
+        for node in &self.comments {
+            node.flatten_into(0, &mut flat);
+        }
+        flat
+    }
+}
+
+impl CommentNode {
+    fn flatten_into<'a>(&'a self, depth: usize, flat: &mut Vec<(usize, &'a CommentData)>) {
+        flat.push((depth, &self.comment));
+        for reply in &self.replies {
+            reply.flatten_into(depth + 1, flat);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use super::account::Account;
+use super::comment::CommentData;
+use super::message::MessageData;
+use super::public_feed::PublicFeedPostData;
+use super::report::{ModReport, UserReport};
+use super::RedditPostData;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Generic top-level response for Reddit listings
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Listing<T> {
+    pub kind: String,
+    pub data: ListingData<T>,
+}
+
+/// Page of items in a listing, with cursors for pagination
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ListingData<T> {
+    pub after: Option<String>,
+    pub before: Option<String>,
+    #[serde(default)]
+    pub dist: Option<i32>,
+    pub modhash: Option<String>,
+    pub children: Vec<T>,
+}
+
+/// A typed item from a mixed listing, dispatched on its `kind` field
+#[derive(Debug)]
+pub enum Thing {
+    /// A comment (`t1`)
+    Comment(Box<CommentData>),
+    /// An account (`t2`)
+    Account(Box<Account>),
+    /// A link or self post (`t3`)
+    Link(Box<RedditPostData>),
+    /// A private message (`t4`)
+    Message(Box<MessageData>),
+    /// Any kind we don't explicitly model
+    Other {
+        kind: String,
+        data: serde_json::Value,
+    },
+}
+
+/// Untyped form of a listing item used while dispatching on `kind`
+#[derive(Deserialize)]
+struct RawThing {
+    kind: String,
+    data: serde_json::Value,
+}
+
+impl<'de> Deserialize<'de> for Thing {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawThing::deserialize(deserializer)?;
+        let thing = match raw.kind.as_str() {
+            "t1" => Thing::Comment(Box::new(
+                serde_json::from_value(raw.data).map_err(serde::de::Error::custom)?,
+            )),
+            "t2" => Thing::Account(Box::new(
+                serde_json::from_value(raw.data).map_err(serde::de::Error::custom)?,
+            )),
+            "t3" => {
+                // Use the forgiving public feed model, since fields vary between listings
+                let post: PublicFeedPostData =
+                    serde_json::from_value(raw.data).map_err(serde::de::Error::custom)?;
+                Thing::Link(Box::new(post.into()))
+            }
+            "t4" => Thing::Message(Box::new(
+                serde_json::from_value(raw.data).map_err(serde::de::Error::custom)?,
+            )),
+            _ => Thing::Other {
+                kind: raw.kind,
+                data: raw.data,
+            },
+        };
+
+        Ok(thing)
+    }
+}
+
+/// Serialize back to Reddit's `{"kind": ..., "data": ...}` shape
+impl Serialize for Thing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Thing", 2)?;
+        state.serialize_field("kind", self.kind())?;
+        match self {
+            Thing::Comment(comment) => state.serialize_field("data", comment)?,
+            Thing::Account(account) => state.serialize_field("data", account)?,
+            Thing::Link(post) => state.serialize_field("data", post)?,
+            Thing::Message(message) => state.serialize_field("data", message)?,
+            Thing::Other { data, .. } => state.serialize_field("data", data)?,
+        }
+        state.end()
+    }
+}
+
+impl Thing {
+    /// The kind prefix of this thing (e.g. "t1", "t3")
+    pub fn kind(&self) -> &str {
+        match self {
+            Thing::Comment(_) => "t1",
+            Thing::Account(_) => "t2",
+            Thing::Link(_) => "t3",
+            Thing::Message(_) => "t4",
+            Thing::Other { kind, .. } => kind,
+        }
+    }
+
+    /// The fullname of this thing (kind prefix plus ID), if known
+    pub fn fullname(&self) -> Option<String> {
+        match self {
+            Thing::Comment(comment) => Some(comment.name.clone()),
+            Thing::Account(account) => Some(account.fullname()),
+            Thing::Link(post) => Some(format!("t3_{}", post.id)),
+            Thing::Message(message) => Some(message.name.clone()),
+            Thing::Other { data, .. } => data["name"].as_str().map(|name| name.to_string()),
+        }
+    }
+
+    /// Reports left by users on this thing (only visible to moderators)
+    pub fn user_reports(&self) -> &[UserReport] {
+        match self {
+            Thing::Comment(comment) => &comment.user_reports,
+            Thing::Link(post) => &post.user_reports,
+            _ => &[],
+        }
+    }
+
+    /// Reports left by moderators on this thing (only visible to moderators)
+    pub fn mod_reports(&self) -> &[ModReport] {
+        match self {
+            Thing::Comment(comment) => &comment.mod_reports,
+            Thing::Link(post) => &post.mod_reports,
+            _ => &[],
+        }
+    }
+}
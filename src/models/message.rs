@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A private message (kind `t4`)
+#[derive(Deserialize, Serialize, Debug)]
+pub struct MessageData {
+    // Basic message information
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub dest: String,
+    #[serde(default)]
+    pub subject: String,
+    #[serde(default)]
+    pub body: String,
+    pub body_html: Option<String>,
+    #[serde(default)]
+    pub created_utc: f64,
+
+    // Conversation information
+    pub parent_id: Option<String>,
+    pub first_message_name: Option<String>,
+    pub subreddit: Option<String>,
+    #[serde(default)]
+    pub context: String,
+
+    // Message status
+    #[serde(default)]
+    pub new: bool,
+    #[serde(default)]
+    pub was_comment: bool,
+    pub distinguished: Option<String>,
+
+    // Nested replies; an empty string when there are none, otherwise a listing
+    #[serde(default)]
+    pub replies: serde_json::Value,
+
+    // Additional fields we don't explicitly model
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, serde_json::Value>,
+}
+
+impl MessageData {
+    /// The sender, or a placeholder for messages sent on behalf of a subreddit
+    pub fn sender(&self) -> String {
+        match (&self.author, &self.subreddit) {
+            (Some(author), _) => format!("u/{}", author),
+            (None, Some(subreddit)) => format!("r/{}", subreddit),
+            (None, None) => "[unknown]".to_string(),
+        }
+    }
+
+    /// Get the message body on one line, truncated to `max_chars` characters
+    pub fn excerpt(&self, max_chars: usize) -> String {
+        let text = self.body.trim().replace('\n', " ");
+        if text.chars().count() > max_chars {
+            let mut excerpt = text
+                .chars()
+                .take(max_chars.saturating_sub(3))
+                .collect::<String>();
+            excerpt.push_str("...");
+            excerpt
+        } else {
+            text
+        }
+    }
+
+    /// Format a message for display with important metadata
+    pub fn format_summary(&self) -> String {
+        let mut content = format!(
+            "From: {}\nTo: {}\nSubject: {}\n",
+            self.sender(),
+            self.dest,
+            self.subject
+        );
+
+        if self.new {
+            content.push_str("Status: unread\n");
+        }
+
+        content.push_str("\nMessage:\n---------\n");
+        content.push_str(&self.body);
+        content.push_str("\n---------\n");
+
+        content
+    }
+}
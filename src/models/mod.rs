@@ -1,20 +1,30 @@
-use serde::Deserialize;
+use report::{ModReport, UserReport};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub mod account;
+pub mod comment;
+pub mod listing;
+pub mod message;
+pub mod modmail;
 pub mod public_feed;
+pub mod relationship;
+pub mod report;
+pub mod subreddit;
 pub mod subreddit_posts;
+pub mod wiki;
 
 // Common data types - to be gradually migrated to specialized modules
 
 /// Top-level response for Reddit listings
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditRNewResponse {
     pub kind: String,
     pub data: RedditPostCollection,
 }
 
 /// Collection of posts in a listing
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditPostCollection {
     pub after: Option<String>,
     pub dist: i32,
@@ -25,21 +35,21 @@ pub struct RedditPostCollection {
 }
 
 /// Reddit post entity with kind and data fields
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditPostEntity {
     pub kind: String,
     pub data: RedditPostData,
 }
 
 /// Preview images in post
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditPreview {
     pub images: Vec<RedditImage>,
     pub enabled: bool,
 }
 
 /// Image data in post preview
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditImage {
     pub source: RedditImageSource,
     pub resolutions: Vec<RedditImageSource>,
@@ -48,14 +58,14 @@ pub struct RedditImage {
 }
 
 /// Image variant data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditImageVariant {
     pub source: RedditImageSource,
     pub resolutions: Vec<RedditImageSource>,
 }
 
 /// Image source data with dimensions and URL
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditImageSource {
     pub url: String,
     pub width: i32,
@@ -63,7 +73,7 @@ pub struct RedditImageSource {
 }
 
 /// Media embed data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditMediaEmbed {
     pub content: Option<String>,
     pub width: Option<i32>,
@@ -71,7 +81,7 @@ pub struct RedditMediaEmbed {
 }
 
 /// Reddit media data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditMedia {
     pub reddit_video: Option<RedditVideo>,
     #[serde(flatten)]
@@ -79,7 +89,7 @@ pub struct RedditMedia {
 }
 
 /// Reddit video data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditVideo {
     pub bitrate_kbps: i32,
     pub fallback_url: String,
@@ -94,20 +104,20 @@ pub struct RedditVideo {
 }
 
 /// Gallery data in post
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditGalleryData {
     pub items: Vec<RedditGalleryItem>,
 }
 
 /// Gallery item in post
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditGalleryItem {
     pub media_id: String,
     pub id: i32,
 }
 
 /// Flair data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditFlair {
     pub text: String,
     pub background_color: String,
@@ -116,7 +126,7 @@ pub struct RedditFlair {
 }
 
 /// Award data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditAward {
     pub name: String,
     pub description: String,
@@ -125,7 +135,7 @@ pub struct RedditAward {
 }
 
 /// Comprehensive Reddit post data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct RedditPostData {
     // Basic post information
     pub id: String,
@@ -185,6 +195,11 @@ pub struct RedditPostData {
     pub pinned: bool,
     pub spoiler: bool,
     pub over_18: bool,
+    pub num_reports: Option<i32>,
+    #[serde(default, deserialize_with = "report::reports_or_empty")]
+    pub user_reports: Vec<UserReport>,
+    #[serde(default, deserialize_with = "report::reports_or_empty")]
+    pub mod_reports: Vec<ModReport>,
 
     // Flags and display options
     pub hide_score: bool,
@@ -208,6 +223,22 @@ pub struct RedditPostData {
 }
 
 impl RedditPostData {
+    /// One-letter post type used in brief listings: [T]ext, [V]ideo, [I]mage,
+    /// [G]allery or [L]ink
+    pub fn type_indicator(&self) -> &'static str {
+        if self.is_self {
+            "T"
+        } else if self.is_video {
+            "V"
+        } else if self.url.contains("i.redd.it") || self.url.contains("imgur.com") {
+            "I"
+        } else if self.url.contains("reddit.com/gallery") {
+            "G"
+        } else {
+            "L"
+        }
+    }
+
     /// Format a post for display with important metadata
     pub fn format_summary(&self) -> String {
         let mut content = format!(
@@ -293,7 +324,7 @@ impl RedditPostData {
         let timestamp = Utc
             .timestamp_opt(self.created_utc as i64, 0)
             .single()
-            .unwrap_or_else(|| Utc::now());
+            .unwrap_or_else(Utc::now);
 
         timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string()
     }
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Response from listing new modmail conversations
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailConversationsResponse {
+    #[serde(default)]
+    pub conversations: HashMap<String, ModmailConversation>,
+    /// Conversation IDs in the requested sort order
+    #[serde(default)]
+    pub conversation_ids: Vec<String>,
+    /// The most recent message of each conversation, keyed by message ID
+    #[serde(default)]
+    pub messages: HashMap<String, ModmailMessage>,
+}
+
+impl ModmailConversationsResponse {
+    /// The conversations in the order Reddit sorted them
+    pub fn ordered(&self) -> Vec<&ModmailConversation> {
+        self.conversation_ids
+            .iter()
+            .filter_map(|id| self.conversations.get(id))
+            .collect()
+    }
+}
+
+/// Response from fetching, replying to or updating a single conversation
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailConversationResponse {
+    pub conversation: ModmailConversation,
+    /// All messages in the conversation, keyed by message ID
+    #[serde(default)]
+    pub messages: HashMap<String, ModmailMessage>,
+    /// Information about the non-moderator participant, if any
+    pub user: Option<serde_json::Value>,
+}
+
+impl ModmailConversationResponse {
+    /// The conversation's messages in the order they were sent
+    pub fn ordered_messages(&self) -> Vec<&ModmailMessage> {
+        self.conversation
+            .message_ids()
+            .into_iter()
+            .filter_map(|id| self.messages.get(id))
+            .collect()
+    }
+}
+
+/// A new modmail conversation
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailConversation {
+    // Basic conversation information
+    pub id: String,
+    #[serde(default)]
+    pub subject: String,
+    pub owner: ModmailOwner,
+    pub participant: Option<ModmailAuthor>,
+    #[serde(default)]
+    pub authors: Vec<ModmailAuthor>,
+
+    // Conversation status
+    #[serde(default)]
+    pub state: i32,
+    #[serde(default)]
+    pub is_highlighted: bool,
+    #[serde(default)]
+    pub is_internal: bool,
+    #[serde(default)]
+    pub is_auto: bool,
+    #[serde(default)]
+    pub num_messages: i32,
+    pub last_updated: Option<String>,
+    pub last_unread: Option<String>,
+
+    // References to the conversation's messages and mod actions, in order
+    #[serde(default)]
+    pub obj_ids: Vec<ModmailObjectId>,
+
+    // Additional fields we don't explicitly model
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, serde_json::Value>,
+}
+
+impl ModmailConversation {
+    /// IDs of the messages in this conversation, oldest first
+    pub fn message_ids(&self) -> Vec<&str> {
+        self.obj_ids
+            .iter()
+            .filter(|obj| obj.key == "messages")
+            .map(|obj| obj.id.as_str())
+            .collect()
+    }
+
+    /// Whether the conversation has activity we haven't read yet
+    pub fn is_unread(&self) -> bool {
+        match (&self.last_unread, &self.last_updated) {
+            (Some(unread), Some(updated)) => unread >= updated,
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+
+    /// Human-readable name for the conversation's numeric state
+    pub fn state_name(&self) -> &'static str {
+        match self.state {
+            0 => "new",
+            1 => "in progress",
+            2 => "archived",
+            3 => "appeal",
+            4 => "join request",
+            5 => "filtered",
+            _ => "unknown",
+        }
+    }
+}
+
+/// The subreddit a conversation belongs to
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailOwner {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub display_name: String,
+}
+
+/// A reference to an object within a conversation
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ModmailObjectId {
+    pub id: String,
+    pub key: String,
+}
+
+/// The author of a modmail message
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailAuthor {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub is_mod: bool,
+    #[serde(default)]
+    pub is_admin: bool,
+    #[serde(default)]
+    pub is_op: bool,
+    #[serde(default)]
+    pub is_hidden: bool,
+    #[serde(default)]
+    pub is_deleted: bool,
+}
+
+/// A single message in a modmail conversation
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailMessage {
+    pub id: String,
+    pub author: ModmailAuthor,
+    #[serde(default)]
+    pub body_markdown: String,
+    /// The message rendered as HTML
+    #[serde(default)]
+    pub body: String,
+    #[serde(default)]
+    pub date: String,
+    #[serde(default)]
+    pub is_internal: bool,
+}
+
+impl ModmailMessage {
+    /// Format a message for display with its author and flags
+    pub fn format_summary(&self) -> String {
+        let mut flags = Vec::new();
+        if self.author.is_mod {
+            flags.push("mod");
+        }
+        if self.author.is_hidden {
+            flags.push("as subreddit");
+        }
+        if self.is_internal {
+            flags.push("private note");
+        }
+
+        let mut content = format!("u/{} at {}", self.author.name, self.date);
+        if !flags.is_empty() {
+            content.push_str(&format!(" [{}]", flags.join(", ")));
+        }
+        content.push_str("\n---------\n");
+        content.push_str(self.body_markdown.trim());
+        content.push_str("\n---------\n");
+
+        content
+    }
+}
@@ -1,15 +1,17 @@
-use serde::Deserialize;
+use super::report::{self, ModReport, UserReport};
+use super::{RedditMediaEmbed, RedditPostData};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// A simpler model for the public feed response
 /// This handles more cases with less required fields
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct PublicFeedResponse {
     pub kind: String,
     pub data: PublicFeedData,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct PublicFeedData {
     pub after: Option<String>,
     #[serde(default)]
@@ -20,14 +22,14 @@ pub struct PublicFeedData {
     pub before: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct PublicFeedPostEntity {
     pub kind: String,
     pub data: PublicFeedPostData,
 }
 
 /// A more forgiving post data model that handles public feed posts
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct PublicFeedPostData {
     // Required core fields
     pub id: String,
@@ -121,6 +123,11 @@ pub struct PublicFeedPostData {
     pub spoiler: bool,
     #[serde(default)]
     pub over_18: bool,
+    pub num_reports: Option<i32>,
+    #[serde(default, deserialize_with = "report::reports_or_empty")]
+    pub user_reports: Vec<UserReport>,
+    #[serde(default, deserialize_with = "report::reports_or_empty")]
+    pub mod_reports: Vec<ModReport>,
 
     // Flags and display options
     #[serde(default)]
@@ -146,6 +153,85 @@ pub struct PublicFeedPostData {
     pub additional_fields: HashMap<String, serde_json::Value>,
 }
 
+impl From<PublicFeedPostData> for RedditPostData {
+    /// Convert a forgiving public feed post into the common post model
+    fn from(post: PublicFeedPostData) -> Self {
+        RedditPostData {
+            id: post.id,
+            name: post.name,
+            title: post.title,
+            author: post.author,
+            author_fullname: None,
+            permalink: post.permalink,
+            url: post.url,
+            created_utc: post.created_utc,
+            is_self: post.is_self,
+            selftext: post.selftext,
+            selftext_html: post.selftext_html,
+            is_video: post.is_video,
+            is_original_content: post.is_original_content,
+            is_reddit_media_domain: post.is_reddit_media_domain,
+            is_meta: post.is_meta,
+            is_crosspostable: post.is_crosspostable,
+            thumbnail: post.thumbnail,
+            thumbnail_width: post.thumbnail_width,
+            thumbnail_height: post.thumbnail_height,
+            secure_media: None,
+            secure_media_embed: RedditMediaEmbed {
+                content: None,
+                width: None,
+                height: None,
+            },
+            media: None,
+            media_embed: RedditMediaEmbed {
+                content: None,
+                width: None,
+                height: None,
+            },
+            preview: None,
+            gallery_data: None,
+            media_metadata: None,
+            score: post.score,
+            upvote_ratio: post.upvote_ratio,
+            ups: post.ups,
+            downs: post.downs,
+            num_comments: post.num_comments,
+            num_crossposts: post.num_crossposts,
+            total_awards_received: post.total_awards_received,
+            subreddit: post.subreddit,
+            subreddit_id: post.subreddit_id,
+            subreddit_subscribers: post.subreddit_subscribers,
+            subreddit_type: post.subreddit_type,
+            subreddit_name_prefixed: post.subreddit_name_prefixed,
+            archived: post.archived,
+            locked: post.locked,
+            hidden: post.hidden,
+            removed_by_category: post.removed_by_category,
+            removed_by: post.removed_by,
+            stickied: post.stickied,
+            pinned: post.pinned,
+            spoiler: post.spoiler,
+            over_18: post.over_18,
+            num_reports: post.num_reports,
+            user_reports: post.user_reports,
+            mod_reports: post.mod_reports,
+            hide_score: post.hide_score,
+            contest_mode: post.contest_mode,
+            edited: post.edited,
+            distinguished: post.distinguished,
+            link_flair_text: post.link_flair_text,
+            link_flair_type: post.link_flair_type,
+            link_flair_background_color: post.link_flair_background_color,
+            link_flair_text_color: post.link_flair_text_color,
+            author_flair_text: post.author_flair_text,
+            author_flair_type: post.author_flair_type,
+            author_flair_background_color: post.author_flair_background_color,
+            author_flair_text_color: post.author_flair_text_color,
+            additional_fields: post.additional_fields,
+        }
+    }
+}
+
 fn default_edited_value() -> serde_json::Value {
     serde_json::Value::Bool(false)
 }
@@ -228,7 +314,7 @@ impl PublicFeedPostData {
         let timestamp = Utc
             .timestamp_opt(self.created_utc as i64, 0)
             .single()
-            .unwrap_or_else(|| Utc::now());
+            .unwrap_or_else(Utc::now);
 
         timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string()
     }
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Response for the subreddit user lists under `/r/{sub}/about/` (banned, muted, ...)
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RelationshipListResponse {
+    pub kind: String,
+    pub data: RelationshipList,
+}
+
+/// Page of users in a subreddit user list
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RelationshipList {
+    pub after: Option<String>,
+    pub before: Option<String>,
+    pub children: Vec<Relationship>,
+}
+
+/// A user's relationship to a subreddit, such as a ban or approved-submitter entry
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Relationship {
+    pub name: String,
+    /// The user's account fullname ("t2_...")
+    #[serde(default)]
+    pub id: String,
+    /// The ID of the relationship itself, used as the pagination cursor
+    #[serde(default)]
+    pub rel_id: String,
+    /// When the relationship was created (UTC timestamp)
+    #[serde(default)]
+    pub date: f64,
+    /// The moderator note, for bans and mutes
+    pub note: Option<String>,
+    /// Days remaining on a temporary ban; `None` for permanent bans
+    pub days_left: Option<i64>,
+
+    // Additional fields we don't explicitly model
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, serde_json::Value>,
+}
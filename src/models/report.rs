@@ -0,0 +1,86 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A report left by users, as found in a thing's `user_reports` array.
+///
+/// Reddit encodes these as `[reason, count, snoozed, can_snooze]` tuples.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UserReport {
+    pub reason: Option<String>,
+    pub count: i32,
+}
+
+/// A report left by a moderator, as found in a thing's `mod_reports` array.
+///
+/// Reddit encodes these as `[reason, moderator]` tuples.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModReport {
+    pub reason: Option<String>,
+    pub moderator: String,
+}
+
+impl<'de> Deserialize<'de> for UserReport {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let values = Vec::<serde_json::Value>::deserialize(deserializer)?;
+        Ok(UserReport {
+            reason: values
+                .first()
+                .and_then(|value| value.as_str())
+                .map(|reason| reason.to_string()),
+            count: values.get(1).and_then(|value| value.as_i64()).unwrap_or(1) as i32,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ModReport {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let values = Vec::<serde_json::Value>::deserialize(deserializer)?;
+        Ok(ModReport {
+            reason: values
+                .first()
+                .and_then(|value| value.as_str())
+                .map(|reason| reason.to_string()),
+            moderator: values
+                .get(1)
+                .and_then(|value| value.as_str())
+                .unwrap_or_default()
+                .to_string(),
+        })
+    }
+}
+
+/// Deserialize a report array that Reddit may send as `null` for non-moderators
+pub(crate) fn reports_or_empty<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Option::<Vec<T>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Format user and mod reports as one line each for display
+pub fn format_reports(user_reports: &[UserReport], mod_reports: &[ModReport]) -> String {
+    let mut content = String::new();
+
+    for report in user_reports {
+        content.push_str(&format!(
+            "    user report ({}x): {}\n",
+            report.count,
+            report.reason.as_deref().unwrap_or("no reason given")
+        ));
+    }
+    for report in mod_reports {
+        content.push_str(&format!(
+            "    mod report by u/{}: {}\n",
+            report.moderator,
+            report.reason.as_deref().unwrap_or("no reason given")
+        ));
+    }
+
+    content
+}
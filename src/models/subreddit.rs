@@ -0,0 +1,338 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Response wrapper for `/r/{sub}/about`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SubredditAboutResponse {
+    pub kind: String,
+    pub data: SubredditAbout,
+}
+
+/// Subreddit metadata (kind `t5`)
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SubredditAbout {
+    // Basic subreddit information
+    pub display_name: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub created_utc: f64,
+    #[serde(default)]
+    pub public_description: String,
+
+    // Sidebar markdown
+    #[serde(default)]
+    pub description: String,
+    pub description_html: Option<String>,
+
+    // Community metrics
+    #[serde(default)]
+    pub subscribers: i64,
+    pub active_user_count: Option<i64>,
+
+    // Community settings
+    #[serde(default)]
+    pub subreddit_type: String,
+    #[serde(default)]
+    pub over18: bool,
+    #[serde(default)]
+    pub restrict_posting: bool,
+    #[serde(default)]
+    pub quarantine: bool,
+
+    // Submission settings: "any", "link" or "self"
+    #[serde(default = "default_submission_type")]
+    pub submission_type: String,
+    #[serde(default)]
+    pub allow_images: bool,
+    #[serde(default)]
+    pub allow_videos: bool,
+    #[serde(default)]
+    pub allow_galleries: bool,
+    #[serde(default)]
+    pub allow_polls: bool,
+    #[serde(default)]
+    pub link_flair_enabled: bool,
+    pub submit_text: Option<String>,
+
+    // Additional fields we don't explicitly model
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, serde_json::Value>,
+}
+
+fn default_submission_type() -> String {
+    "any".to_string()
+}
+
+impl SubredditAbout {
+    /// Whether the subreddit accepts text (self) posts
+    pub fn allows_self_posts(&self) -> bool {
+        self.submission_type != "link"
+    }
+
+    /// Whether the subreddit accepts link posts
+    pub fn allows_link_posts(&self) -> bool {
+        self.submission_type != "self"
+    }
+
+    /// Format the subreddit for display with important metadata
+    pub fn format_summary(&self) -> String {
+        let created = chrono::DateTime::from_timestamp(self.created_utc as i64, 0)
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let mut content = format!(
+            "r/{} ({})\nTitle: {}\nCreated: {}\nSubscribers: {}",
+            self.display_name, self.name, self.title, created, self.subscribers
+        );
+        if let Some(active) = self.active_user_count {
+            content.push_str(&format!(" | Active: {}", active));
+        }
+        content.push('\n');
+        content.push_str(&format!(
+            "Type: {} | Submissions: {}\n",
+            self.subreddit_type, self.submission_type
+        ));
+
+        // Add community flags
+        let mut flags = Vec::new();
+        if self.over18 {
+            flags.push("NSFW");
+        }
+        if self.quarantine {
+            flags.push("Quarantined");
+        }
+        if self.restrict_posting {
+            flags.push("Restricted posting");
+        }
+        if self.allow_images {
+            flags.push("Images");
+        }
+        if self.allow_videos {
+            flags.push("Videos");
+        }
+        if self.allow_galleries {
+            flags.push("Galleries");
+        }
+        if self.allow_polls {
+            flags.push("Polls");
+        }
+        if !flags.is_empty() {
+            content.push_str(&format!("Flags: [{}]\n", flags.join(", ")));
+        }
+
+        if !self.public_description.is_empty() {
+            content.push_str(&format!("\n{}\n", self.public_description.trim()));
+        }
+
+        content
+    }
+}
+
+/// Response for `/r/{sub}/about/rules`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SubredditRules {
+    #[serde(default)]
+    pub rules: Vec<SubredditRule>,
+    #[serde(default)]
+    pub site_rules: Vec<String>,
+}
+
+/// A single subreddit rule
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SubredditRule {
+    /// What the rule applies to: "link", "comment" or "all"
+    #[serde(default)]
+    pub kind: String,
+    pub short_name: String,
+    #[serde(default)]
+    pub description: String,
+    pub violation_reason: Option<String>,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default)]
+    pub created_utc: f64,
+}
+
+/// Response for `/api/v1/{sub}/post_requirements`
+///
+/// Every constraint is optional; Reddit returns `null` or an empty list when a
+/// subreddit doesn't set one.
+#[derive(Deserialize, Serialize, Debug, Default)]
+#[serde(default)]
+pub struct PostRequirements {
+    // Title constraints
+    pub title_text_min_length: Option<usize>,
+    pub title_text_max_length: Option<usize>,
+    pub title_required_strings: Vec<String>,
+    pub title_blacklisted_strings: Vec<String>,
+    pub title_regexes: Vec<String>,
+
+    // Body constraints; the policy is "required", "notAllowed" or "none"
+    pub body_restriction_policy: Option<String>,
+    pub body_text_min_length: Option<usize>,
+    pub body_text_max_length: Option<usize>,
+    pub body_required_strings: Vec<String>,
+    pub body_blacklisted_strings: Vec<String>,
+    pub body_regexes: Vec<String>,
+
+    // Link constraints
+    pub link_restriction_policy: Option<String>,
+    pub domain_whitelist: Vec<String>,
+    pub domain_blacklist: Vec<String>,
+
+    // Flair and gallery constraints
+    pub is_flair_required: bool,
+    pub gallery_min_items: Option<usize>,
+    pub gallery_max_items: Option<usize>,
+}
+
+impl PostRequirements {
+    /// Check a text post against the requirements, returning every violation found
+    ///
+    /// # Arguments
+    /// * `title` - The post title
+    /// * `text` - The post body
+    /// * `has_flair` - Whether a flair will be set on the post
+    pub fn check_self_post(&self, title: &str, text: &str, has_flair: bool) -> Vec<String> {
+        let mut violations = Vec::new();
+
+        check_text(
+            "Title",
+            title,
+            self.title_text_min_length,
+            self.title_text_max_length,
+            &self.title_required_strings,
+            &self.title_blacklisted_strings,
+            &self.title_regexes,
+            &mut violations,
+        );
+
+        match self.body_restriction_policy.as_deref() {
+            Some("required") if text.trim().is_empty() => {
+                violations.push("Body text is required".to_string());
+            }
+            Some("notAllowed") if !text.trim().is_empty() => {
+                violations.push("Body text is not allowed".to_string());
+            }
+            _ => {}
+        }
+
+        if !text.is_empty() {
+            check_text(
+                "Body",
+                text,
+                self.body_text_min_length,
+                self.body_text_max_length,
+                &self.body_required_strings,
+                &self.body_blacklisted_strings,
+                &self.body_regexes,
+                &mut violations,
+            );
+        }
+
+        if self.is_flair_required && !has_flair {
+            violations.push("A post flair is required".to_string());
+        }
+
+        violations
+    }
+}
+
+/// Check a title or body against length, string and regex constraints
+#[allow(clippy::too_many_arguments)]
+fn check_text(
+    label: &str,
+    text: &str,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    required_strings: &[String],
+    blacklisted_strings: &[String],
+    regexes: &[String],
+    violations: &mut Vec<String>,
+) {
+    let length = text.chars().count();
+    if let Some(min) = min_length {
+        if length < min {
+            violations.push(format!(
+                "{} is {} characters, minimum is {}",
+                label, length, min
+            ));
+        }
+    }
+    if let Some(max) = max_length {
+        if length > max {
+            violations.push(format!(
+                "{} is {} characters, maximum is {}",
+                label, length, max
+            ));
+        }
+    }
+
+    // Reddit matches required and blacklisted strings case-insensitively
+    let lowered = text.to_lowercase();
+    if !required_strings.is_empty()
+        && !required_strings
+            .iter()
+            .any(|s| lowered.contains(&s.to_lowercase()))
+    {
+        violations.push(format!(
+            "{} must contain one of: {}",
+            label,
+            required_strings.join(", ")
+        ));
+    }
+    for banned in blacklisted_strings {
+        if lowered.contains(&banned.to_lowercase()) {
+            violations.push(format!("{} must not contain \"{}\"", label, banned));
+        }
+    }
+
+    if !regexes.is_empty() {
+        let matched = regexes
+            .iter()
+            .any(|pattern| match regex::Regex::new(pattern) {
+                Ok(re) => re.is_match(text),
+                // Reddit's patterns are not always valid Rust regexes; don't block on those
+                Err(_) => true,
+            });
+        if !matched {
+            violations.push(format!(
+                "{} must match one of the patterns: {}",
+                label,
+                regexes.join(", ")
+            ));
+        }
+    }
+}
+
+/// Response for `/r/{sub}/about/moderators`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ModeratorListResponse {
+    pub kind: String,
+    pub data: ModeratorList,
+}
+
+/// List of moderators in a subreddit
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ModeratorList {
+    pub children: Vec<Moderator>,
+}
+
+/// A single subreddit moderator and their permissions
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Moderator {
+    pub name: String,
+    #[serde(default)]
+    pub id: String,
+    /// When the user became a moderator (UTC timestamp)
+    #[serde(default)]
+    pub date: f64,
+    #[serde(default)]
+    pub mod_permissions: Vec<String>,
+    pub author_flair_text: Option<String>,
+}
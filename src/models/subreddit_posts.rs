@@ -1,15 +1,15 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Top-level response for subreddit listings
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditPostsResponse {
     pub kind: String,
     pub data: SubredditPostCollection,
 }
 
 /// Collection of posts in a subreddit listing
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditPostCollection {
     pub after: Option<String>,
     pub dist: i32,
@@ -20,21 +20,21 @@ pub struct SubredditPostCollection {
 }
 
 /// Reddit post entity with kind and data fields
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditPostEntity {
     pub kind: String,
     pub data: SubredditPostData,
 }
 
 /// Preview images in post
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditPreview {
     pub images: Vec<SubredditImage>,
     pub enabled: bool,
 }
 
 /// Image data in post preview
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditImage {
     pub source: SubredditImageSource,
     pub resolutions: Vec<SubredditImageSource>,
@@ -43,14 +43,14 @@ pub struct SubredditImage {
 }
 
 /// Image variant data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditImageVariant {
     pub source: SubredditImageSource,
     pub resolutions: Vec<SubredditImageSource>,
 }
 
 /// Image source data with dimensions and URL
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditImageSource {
     pub url: String,
     pub width: i32,
@@ -58,7 +58,7 @@ pub struct SubredditImageSource {
 }
 
 /// Media embed data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditMediaEmbed {
     pub content: Option<String>,
     pub width: Option<i32>,
@@ -66,7 +66,7 @@ pub struct SubredditMediaEmbed {
 }
 
 /// Reddit media data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditMedia {
     pub reddit_video: Option<SubredditVideo>,
     #[serde(flatten)]
@@ -74,7 +74,7 @@ pub struct SubredditMedia {
 }
 
 /// Reddit video data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditVideo {
     pub bitrate_kbps: i32,
     pub fallback_url: String,
@@ -89,20 +89,20 @@ pub struct SubredditVideo {
 }
 
 /// Gallery data in post
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditGalleryData {
     pub items: Vec<SubredditGalleryItem>,
 }
 
 /// Gallery item in post
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditGalleryItem {
     pub media_id: String,
     pub id: i32,
 }
 
 /// Flair data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditFlair {
     pub text: String,
     pub background_color: String,
@@ -111,7 +111,7 @@ pub struct SubredditFlair {
 }
 
 /// Award data
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditAward {
     pub name: String,
     pub description: String,
@@ -120,7 +120,7 @@ pub struct SubredditAward {
 }
 
 /// Comprehensive Reddit post data for subreddit posts
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct SubredditPostData {
     // Basic post information
     pub id: String,
@@ -288,7 +288,7 @@ impl SubredditPostData {
         let timestamp = Utc
             .timestamp_opt(self.created_utc as i64, 0)
             .single()
-            .unwrap_or_else(|| Utc::now());
+            .unwrap_or_else(Utc::now);
 
         timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string()
     }
@@ -0,0 +1,68 @@
+use super::account::AccountResponse;
+use serde::{Deserialize, Serialize};
+
+/// Response for `/r/{sub}/wiki/pages`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct WikiPageListResponse {
+    pub kind: String,
+    /// Names of every wiki page we can see, e.g. "index" or "config/sidebar"
+    pub data: Vec<String>,
+}
+
+/// Response for `/r/{sub}/wiki/{page}`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct WikiPageResponse {
+    pub kind: String,
+    pub data: WikiPage,
+}
+
+/// The content of a wiki page at a particular revision
+#[derive(Deserialize, Serialize, Debug)]
+pub struct WikiPage {
+    #[serde(default)]
+    pub content_md: String,
+    pub content_html: Option<String>,
+    /// Whether the current user may edit the page
+    #[serde(default)]
+    pub may_revise: bool,
+
+    // Revision information
+    pub revision_id: Option<String>,
+    pub revision_date: Option<f64>,
+    pub revision_by: Option<AccountResponse>,
+    pub reason: Option<String>,
+}
+
+impl WikiPage {
+    /// The username of the revision's author, if known
+    pub fn revised_by(&self) -> Option<&str> {
+        self.revision_by
+            .as_ref()
+            .map(|author| author.data.name.as_str())
+    }
+}
+
+/// A single revision in a wiki page's history
+#[derive(Deserialize, Serialize, Debug)]
+pub struct WikiRevision {
+    pub id: String,
+    #[serde(default)]
+    pub page: String,
+    pub reason: Option<String>,
+    /// When the revision was made (UTC timestamp)
+    #[serde(default)]
+    pub timestamp: f64,
+    pub author: Option<AccountResponse>,
+    #[serde(default)]
+    pub revision_hidden: bool,
+}
+
+impl WikiRevision {
+    /// The username of the revision's author, if known
+    pub fn author_name(&self) -> &str {
+        self.author
+            .as_ref()
+            .map(|author| author.data.name.as_str())
+            .unwrap_or("[deleted]")
+    }
+}
@@ -0,0 +1,209 @@
+use crate::client::{RedditClient, RedditClientError, VoteDirection};
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+use serde_json::json;
+use std::io::Read;
+
+/// An action that can be applied to posts and comments by fullname
+#[derive(Debug, Clone)]
+pub enum ThingAction {
+    /// Vote up, down, or clear an existing vote
+    Vote(VoteDirection),
+    /// Save, optionally into a category
+    Save { category: Option<String> },
+    /// Remove from the saved list
+    Unsave,
+    /// Hide a post from listings
+    Hide,
+    /// Unhide a hidden post
+    Unhide,
+    /// Report to the subreddit moderators
+    Report { reason: String },
+    /// Delete one of our own posts or comments
+    Delete,
+    /// Mark an inbox message or comment reply as read
+    MarkRead,
+    /// Mark an inbox message or comment reply as unread
+    MarkUnread,
+}
+
+impl ThingAction {
+    /// Past-tense description of the action for output messages
+    pub fn describe(&self) -> &'static str {
+        match self {
+            ThingAction::Vote(VoteDirection::Up) => "Upvoted",
+            ThingAction::Vote(VoteDirection::Down) => "Downvoted",
+            ThingAction::Vote(VoteDirection::Clear) => "Cleared vote on",
+            ThingAction::Save { .. } => "Saved",
+            ThingAction::Unsave => "Unsaved",
+            ThingAction::Hide => "Hid",
+            ThingAction::Unhide => "Unhid",
+            ThingAction::Report { .. } => "Reported",
+            ThingAction::Delete => "Deleted",
+            ThingAction::MarkRead => "Marked as read",
+            ThingAction::MarkUnread => "Marked as unread",
+        }
+    }
+}
+
+/// Configuration options for applying an action to posts and comments
+#[derive(Debug, Clone)]
+pub struct ActionOptions {
+    /// The action to apply
+    pub action: ThingAction,
+    /// Fullnames of the posts ("t3_...") and comments ("t1_...") to act on
+    pub fullnames: Vec<String>,
+}
+
+/// Result of applying an action to a set of things
+#[derive(Debug, Serialize)]
+pub struct ActionResult {
+    /// Fullnames the action was applied to
+    pub succeeded: Vec<String>,
+    /// Fullnames the action failed for, with the error message
+    pub failed: Vec<(String, String)>,
+    /// Formatted message for CLI output
+    #[serde(skip)]
+    pub message: String,
+}
+
+impl CommandOutput for ActionResult {
+    fn text(&self) -> String {
+        self.message.clone()
+    }
+
+    /// One row per fullname, with the error for failed ones
+    fn rows(&self) -> Vec<serde_json::Value> {
+        let succeeded = self
+            .succeeded
+            .iter()
+            .map(|fullname| json!({ "fullname": fullname, "success": true, "error": null }));
+        let failed = self
+            .failed
+            .iter()
+            .map(|(fullname, err)| json!({ "fullname": fullname, "success": false, "error": err }));
+        succeeded.chain(failed).collect()
+    }
+}
+
+/// Operation for voting, saving, hiding, reporting and deleting things by fullname
+pub struct ActionOperation {
+    /// Configuration options for the operation
+    options: ActionOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl ActionOperation {
+    /// Create a new action operation with the provided options
+    pub fn new(options: ActionOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new action operation with a custom Reddit client
+    pub fn with_client(options: ActionOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the action against every fullname, continuing past individual failures
+    pub async fn execute(&mut self) -> Result<ActionResult, RedditClientError> {
+        info!(
+            "Applying {:?} to {} item(s)",
+            self.options.action,
+            self.options.fullnames.len()
+        );
+
+        // Refresh the access token once up front rather than per item
+        self.client.ensure_access_token().await?;
+
+        let mut succeeded = Vec::new();
+        let mut failed = Vec::new();
+        let mut message = String::new();
+
+        for fullname in &self.options.fullnames {
+            let outcome = match &self.options.action {
+                ThingAction::Vote(direction) => self.client.vote(fullname, *direction).await,
+                ThingAction::Save { category } => {
+                    self.client.save(fullname, category.as_deref()).await
+                }
+                ThingAction::Unsave => self.client.unsave(fullname).await,
+                ThingAction::Hide => self.client.hide(fullname).await,
+                ThingAction::Unhide => self.client.unhide(fullname).await,
+                ThingAction::Report { reason } => self.client.report(fullname, reason).await,
+                ThingAction::Delete => self.client.delete(fullname).await,
+                ThingAction::MarkRead => self.client.read_message(&[fullname]).await,
+                ThingAction::MarkUnread => self.client.unread_message(&[fullname]).await,
+            };
+
+            match outcome {
+                Ok(()) => {
+                    message.push_str(&format!(
+                        "{} {}\n",
+                        self.options.action.describe(),
+                        fullname
+                    ));
+                    succeeded.push(fullname.clone());
+                }
+                Err(err) => {
+                    message.push_str(&format!("Failed on {}: {}\n", fullname, err));
+                    failed.push((fullname.clone(), err.to_string()));
+                }
+            }
+        }
+
+        Ok(ActionResult {
+            succeeded,
+            failed,
+            message,
+        })
+    }
+}
+
+/// Expand a list of IDs from the command line, reading whitespace-separated IDs
+/// from stdin when the only argument is "-"
+pub fn expand_fullnames(ids: Vec<String>) -> Result<Vec<String>, RedditClientError> {
+    if ids.len() == 1 && ids[0] == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input).map_err(|e| {
+            RedditClientError::ApiError(format!("Failed to read IDs from stdin: {}", e))
+        })?;
+        return Ok(input.split_whitespace().map(str::to_string).collect());
+    }
+
+    Ok(ids)
+}
+
+/// CLI handler function for vote, save, hide, report and delete commands with client
+pub async fn handle_action_command_with_client(
+    action: ThingAction,
+    ids: Vec<String>,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let options = ActionOptions {
+        action,
+        fullnames: expand_fullnames(ids)?,
+    };
+
+    let mut operation = ActionOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            print_result(&result, output)?;
+            if result.failed.is_empty() {
+                Ok(())
+            } else {
+                Err(RedditClientError::ApiError(format!(
+                    "{} of {} actions failed",
+                    result.failed.len(),
+                    result.failed.len() + result.succeeded.len()
+                )))
+            }
+        }
+        Err(err) => {
+            error!("Error executing action: {:?}", err);
+            Err(err)
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::client::RedditClient;
+use crate::output::{print_result, CommandOutput, OutputFormat};
 use log::{error, info};
+use serde::Serialize;
 
 /// Configuration options for creating a post with API credentials
 #[derive(Debug, Clone)]
@@ -10,10 +12,12 @@ pub struct ApiCreateOptions {
     pub title: String,
     /// Text content of the post
     pub text: String,
+    /// Check the post against the subreddit's requirements before submitting
+    pub validate: bool,
 }
 
 /// Result of an API-authenticated post creation operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ApiCreateResult {
     /// Whether the post was successfully created
     pub success: bool,
@@ -23,6 +27,12 @@ pub struct ApiCreateResult {
     pub message: String,
 }
 
+impl CommandOutput for ApiCreateResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.message)
+    }
+}
+
 /// Operation for creating a post on Reddit using API credentials
 pub struct ApiCreateOperation {
     /// Configuration options for the operation
@@ -57,6 +67,25 @@ impl ApiCreateOperation {
             display_sub, self.options.title
         );
 
+        // Check the post against the subreddit's requirements before submitting
+        if self.options.validate {
+            if let Some(message) = self
+                .client
+                .check_self_post(
+                    &self.options.subreddit,
+                    &self.options.title,
+                    &self.options.text,
+                )
+                .await?
+            {
+                return Ok(ApiCreateResult {
+                    success: false,
+                    post_url: None,
+                    message,
+                });
+            }
+        }
+
         // Now create the post
         match self
             .client
@@ -96,18 +125,23 @@ pub async fn handle_api_create_command_with_client(
     subreddit: String,
     title: String,
     text: String,
+    validate: bool,
+    output: OutputFormat,
     client: RedditClient,
 ) -> Result<(), crate::client::RedditClientError> {
     let options = ApiCreateOptions {
         subreddit,
         title,
         text,
+        validate,
     };
 
     let mut operation = ApiCreateOperation::with_client(options, client);
     match operation.execute().await {
         Ok(result) => {
-            if result.success {
+            if !output.is_text() {
+                print_result(&result, output)?;
+            } else if result.success {
                 info!("{}", result.message);
             } else {
                 error!("{}", result.message);
@@ -0,0 +1,347 @@
+use crate::archive::{Archive, Upsert};
+use crate::client::listing::{ListingParams, Sort};
+use crate::client::{RedditClient, RedditClientError};
+use crate::models::comment::CommentData;
+use crate::models::listing::Thing;
+use crate::models::RedditPostData;
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use log::{error, info, warn};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// Posts requested per listing page
+const PAGE_SIZE: u32 = 100;
+
+/// Most comments requested per thread
+const COMMENT_LIMIT: u32 = 500;
+
+/// Configuration options for syncing a subreddit into an archive
+#[derive(Debug, Clone)]
+pub struct ArchiveSyncOptions {
+    /// The subreddit to mirror
+    pub subreddit: String,
+    /// The SQLite archive file
+    pub db_path: PathBuf,
+    /// Page through the whole listing instead of stopping at the newest stored post
+    pub full: bool,
+    /// Most posts to fetch in one sync (Reddit stops listings at about 1000)
+    pub max_posts: usize,
+    /// Fetch comment trees for new posts and posts with new comments
+    pub comments: bool,
+    /// API requests per minute, unless the client is already rate limited
+    pub requests_per_minute: u32,
+}
+
+/// Counts of what changed in one table during a sync
+#[derive(Debug, Default, Serialize)]
+pub struct ArchiveCounts {
+    pub inserted: usize,
+    pub updated: usize,
+    pub score_changes: usize,
+    pub edits: usize,
+    pub removals: usize,
+}
+
+impl ArchiveCounts {
+    fn record(&mut self, upsert: Upsert) {
+        if upsert.inserted {
+            self.inserted += 1;
+        } else {
+            self.updated += 1;
+        }
+        self.score_changes += upsert.score_changed as usize;
+        self.edits += upsert.edited as usize;
+        self.removals += upsert.removed as usize;
+    }
+}
+
+/// Result of an archive sync
+#[derive(Debug, Serialize)]
+pub struct ArchiveSyncResult {
+    pub subreddit: String,
+    pub db_path: PathBuf,
+    /// Listing pages fetched
+    pub pages: usize,
+    pub posts: ArchiveCounts,
+    pub comments: ArchiveCounts,
+    /// Comment threads fetched
+    pub threads: usize,
+    /// Comments Reddit left behind "load more comments" links
+    pub comments_not_loaded: usize,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+}
+
+impl CommandOutput for ArchiveSyncResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.formatted_output)
+    }
+}
+
+/// Operation for mirroring a subreddit's posts and comments into SQLite
+pub struct ArchiveSyncOperation {
+    /// Configuration options for the operation
+    options: ArchiveSyncOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl ArchiveSyncOperation {
+    /// Create a new archive sync operation with the provided options
+    pub fn new(options: ArchiveSyncOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new archive sync operation with a custom Reddit client
+    pub fn with_client(options: ArchiveSyncOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the archive sync operation
+    pub async fn execute(&mut self) -> Result<ArchiveSyncResult, RedditClientError> {
+        let subreddit = self.options.subreddit.trim_start_matches("r/").to_string();
+        let mut archive = Archive::open(&self.options.db_path)?;
+
+        // Stored tokens are optional; public listings work without them
+        if self.client.access_token.is_some() {
+            self.client.ensure_access_token().await?;
+        }
+        if self.client.rate_limiter.is_none() {
+            self.client = self
+                .client
+                .clone()
+                .with_rate_limit(self.options.requests_per_minute);
+        }
+
+        let resume_from = match archive.sync_state(&subreddit)? {
+            Some(state) if !self.options.full => {
+                info!(
+                    "Resuming r/{} from {} (last sync {})",
+                    subreddit,
+                    state.newest_fullname.as_deref().unwrap_or("the start"),
+                    state.last_sync
+                );
+                state.newest_created_utc
+            }
+            _ => None,
+        };
+
+        let mut result = ArchiveSyncResult {
+            subreddit: subreddit.clone(),
+            db_path: self.options.db_path.clone(),
+            pages: 0,
+            posts: ArchiveCounts::default(),
+            comments: ArchiveCounts::default(),
+            threads: 0,
+            comments_not_loaded: 0,
+            formatted_output: String::new(),
+        };
+        let mut newest: Option<RedditPostData> = None;
+        let mut fetched = 0;
+        let mut after: Option<String> = None;
+        // Whether paging reached the stored posts or the end of the listing
+        let mut caught_up = false;
+
+        while fetched < self.options.max_posts {
+            let params = ListingParams {
+                after: after.clone(),
+                ..ListingParams::with_limit(PAGE_SIZE)
+            };
+            let page = self
+                .client
+                .fetch_new_posts_json(&subreddit, &params)
+                .await?;
+            result.pages += 1;
+            after = page.data.after.clone();
+
+            // Keep each post's JSON as Reddit sent it; the typed post drops fields
+            let mut posts: Vec<(RedditPostData, Value)> = Vec::new();
+            for child in page.data.children {
+                if let Thing::Link(post) = serde_json::from_value::<Thing>(child.clone())? {
+                    posts.push((*post, child["data"].clone()));
+                }
+            }
+            if posts.is_empty() {
+                caught_up = true;
+                break;
+            }
+
+            // The listing is newest first; stop after the page that reaches stored posts
+            let reached_stored = resume_from.is_some_and(|resume| {
+                posts
+                    .iter()
+                    .any(|(post, _)| !post.stickied && post.created_utc <= resume)
+            });
+
+            for (post, raw) in posts {
+                if fetched >= self.options.max_posts {
+                    break;
+                }
+                fetched += 1;
+                self.sync_post(&mut archive, &post, &raw, &mut result)
+                    .await?;
+                if !post.stickied
+                    && newest
+                        .as_ref()
+                        .is_none_or(|newest| post.created_utc > newest.created_utc)
+                {
+                    newest = Some(post);
+                }
+            }
+
+            info!(
+                "Synced page {} of r/{}: {} posts so far",
+                result.pages, subreddit, fetched
+            );
+            if reached_stored || after.is_none() {
+                caught_up = true;
+                break;
+            }
+        }
+
+        // Moving the resume point past a gap would leave it unfilled for good,
+        // so keep the old one and page down to it again next time
+        if !caught_up && resume_from.is_some() {
+            warn!(
+                "Stopped at --max-posts {} before reaching the posts stored by the last sync; \
+                 the next sync will page through them again",
+                self.options.max_posts
+            );
+            newest = None;
+        }
+        archive.save_sync_state(&subreddit, newest.as_ref())?;
+
+        result.formatted_output = format!(
+            "Synced r/{} into {} ({} pages)\n\
+             Posts: {} new, {} updated, {} score changes, {} edits, {} removals\n\
+             Comments: {} new, {} updated, {} score changes, {} edits, {} removals ({} threads fetched{})",
+            subreddit,
+            self.options.db_path.display(),
+            result.pages,
+            result.posts.inserted,
+            result.posts.updated,
+            result.posts.score_changes,
+            result.posts.edits,
+            result.posts.removals,
+            result.comments.inserted,
+            result.comments.updated,
+            result.comments.score_changes,
+            result.comments.edits,
+            result.comments.removals,
+            result.threads,
+            if result.comments_not_loaded > 0 {
+                format!(", {} behind \"load more\" not archived", result.comments_not_loaded)
+            } else {
+                String::new()
+            }
+        );
+
+        Ok(result)
+    }
+
+    /// Store a post, and fetch its comments if it is new or has new comments
+    async fn sync_post(
+        &self,
+        archive: &mut Archive,
+        post: &RedditPostData,
+        raw: &Value,
+        result: &mut ArchiveSyncResult,
+    ) -> Result<(), RedditClientError> {
+        let fullname = format!("t3_{}", post.id);
+        result.posts.record(archive.upsert_post(post, raw)?);
+
+        if !self.options.comments
+            || post.num_comments == 0
+            || archive.synced_comment_count(&fullname)? == Some(post.num_comments)
+        {
+            return Ok(());
+        }
+
+        // The thread is only marked synced once it is stored, so one that fails
+        // to load is fetched again on the next sync
+        match self
+            .client
+            .fetch_comments_json(&post.id, Some(Sort::New), Some(COMMENT_LIMIT))
+            .await
+        {
+            Ok(listing) => {
+                result.threads += 1;
+                store_comments(archive, listing.data.children, result)?;
+                archive.mark_comments_synced(&fullname, post.num_comments)?;
+            }
+            Err(err) => warn!("Failed to fetch comments for {}: {}", fullname, err),
+        }
+        Ok(())
+    }
+}
+
+/// Store the comments in a listing's children and all of their loaded replies,
+/// counting the comments left behind "more" stubs
+fn store_comments(
+    archive: &mut Archive,
+    children: Vec<Value>,
+    result: &mut ArchiveSyncResult,
+) -> Result<(), RedditClientError> {
+    for mut child in children {
+        match child["kind"].as_str() {
+            Some("t1") => {
+                // Replies are stored as rows of their own, so they leave the parent's JSON
+                let mut data = child.get_mut("data").map(Value::take).unwrap_or_default();
+                let mut replies = data
+                    .as_object_mut()
+                    .and_then(|data| data.remove("replies"))
+                    .unwrap_or_default();
+                let comment: CommentData = serde_json::from_value(data.clone())?;
+                result
+                    .comments
+                    .record(archive.upsert_comment(&comment, &data)?);
+
+                // Replies are an empty string when there are none, otherwise a listing
+                if let Some(Value::Array(replies)) =
+                    replies.pointer_mut("/data/children").map(Value::take)
+                {
+                    store_comments(archive, replies, result)?;
+                }
+            }
+            Some("more") => {
+                result.comments_not_loaded += child["data"]["count"].as_u64().unwrap_or(0) as usize;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// CLI handler function for the archive sync command with client
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_archive_sync_command_with_client(
+    subreddit: String,
+    db_path: PathBuf,
+    full: bool,
+    max_posts: usize,
+    comments: bool,
+    requests_per_minute: u32,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let options = ArchiveSyncOptions {
+        subreddit,
+        db_path,
+        full,
+        max_posts,
+        comments,
+        requests_per_minute,
+    };
+
+    let mut operation = ArchiveSyncOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => print_result(&result, output),
+        Err(err) => {
+            error!("Error executing archive sync operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use crate::batch::{
+    default_results_path, load_plan, load_results, save_results, BatchAction, BatchItem,
+    BatchRecord, RowStatus,
+};
+use crate::client::{RedditClient, RedditClientError};
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use chrono::Utc;
+use log::{error, info, warn};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How many times a row waits out Reddit's RATELIMIT error before it is marked failed
+const MAX_RATELIMIT_WAITS: u32 = 3;
+
+/// Wait used when a RATELIMIT error doesn't say how long to wait
+const DEFAULT_RATELIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// Configuration options for running a batch plan
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    /// The plan file (.json, .yaml, .yml or .csv)
+    pub plan_path: PathBuf,
+    /// Where to record the outcome of each row (.json or .csv)
+    pub results_path: PathBuf,
+    /// Print what would be posted without posting or writing results
+    pub dry_run: bool,
+    /// Continue from an existing results file, skipping rows that already succeeded
+    pub resume: bool,
+    /// API requests per minute, unless the client is already rate limited
+    pub requests_per_minute: u32,
+}
+
+/// Result of a batch run
+#[derive(Debug, Serialize)]
+pub struct BatchResult {
+    pub succeeded: usize,
+    pub failed: usize,
+    /// Rows skipped because an earlier run already posted them
+    pub skipped: usize,
+    /// The results file contents after the run
+    pub records: Vec<BatchRecord>,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+}
+
+impl CommandOutput for BatchResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.formatted_output)
+    }
+
+    /// One row per results file record
+    fn rows(&self) -> Vec<serde_json::Value> {
+        self.records
+            .iter()
+            .filter_map(|record| serde_json::to_value(record).ok())
+            .collect()
+    }
+}
+
+/// Operation for posting and commenting in bulk from a plan file
+pub struct BatchOperation {
+    /// Configuration options for the operation
+    options: BatchOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl BatchOperation {
+    /// Create a new batch operation with the provided options
+    pub fn new(options: BatchOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new batch operation with a custom Reddit client
+    pub fn with_client(options: BatchOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the batch operation
+    pub async fn execute(&mut self) -> Result<BatchResult, RedditClientError> {
+        let items = load_plan(&self.options.plan_path)?;
+        let mut keys = HashSet::new();
+        if let Some(item) = items.iter().find(|item| !keys.insert(item.key.as_str())) {
+            return Err(RedditClientError::ApiError(format!(
+                "Duplicate row id '{}' in {}",
+                item.key,
+                self.options.plan_path.display()
+            )));
+        }
+
+        let results_path = self.options.results_path.clone();
+        let mut records = if self.options.resume {
+            load_results(&results_path)?
+        } else if results_path.exists() && !self.options.dry_run {
+            return Err(RedditClientError::ApiError(format!(
+                "{} already exists; pass --resume to continue that run or --results to start a new one",
+                results_path.display()
+            )));
+        } else {
+            Vec::new()
+        };
+        let done: HashSet<String> = records
+            .iter()
+            .filter(|record| record.status == RowStatus::Ok)
+            .map(|record| record.key.clone())
+            .collect();
+
+        info!(
+            "Running {} rows from {}{}",
+            items.len(),
+            self.options.plan_path.display(),
+            if self.options.dry_run {
+                " (dry run)"
+            } else {
+                ""
+            }
+        );
+
+        if !self.options.dry_run {
+            self.client.ensure_access_token().await?;
+            if self.client.rate_limiter.is_none() {
+                self.client = self
+                    .client
+                    .clone()
+                    .with_rate_limit(self.options.requests_per_minute);
+            }
+        }
+
+        let mut output = String::new();
+        let (mut succeeded, mut failed, mut skipped) = (0, 0, 0);
+
+        for item in &items {
+            let summary = describe(item);
+            if done.contains(&item.key) {
+                skipped += 1;
+                output.push_str(&format!("{}: skipped (already posted)\n", summary));
+                continue;
+            }
+
+            if self.options.dry_run {
+                output.push_str(&format!("[dry-run] {}\n", summary));
+                records.retain(|existing| existing.key != item.key);
+                records.push(BatchRecord {
+                    key: item.key.clone(),
+                    action: item.action.kind(),
+                    target: item.action.target(),
+                    status: RowStatus::DryRun,
+                    url: None,
+                    error: None,
+                    attempts: 0,
+                    finished_at: Utc::now(),
+                });
+                continue;
+            }
+
+            let (outcome, attempts) = self.run_item(item).await;
+            let record = BatchRecord {
+                key: item.key.clone(),
+                action: item.action.kind(),
+                target: item.action.target(),
+                status: if outcome.is_ok() {
+                    RowStatus::Ok
+                } else {
+                    RowStatus::Failed
+                },
+                url: outcome.as_ref().ok().cloned(),
+                error: outcome.as_ref().err().map(|err| err.to_string()),
+                attempts,
+                finished_at: Utc::now(),
+            };
+
+            match &outcome {
+                Ok(url) => {
+                    succeeded += 1;
+                    output.push_str(&format!("{}: ok {}\n", summary, url));
+                }
+                Err(err) => {
+                    failed += 1;
+                    error!("Row {} failed: {}", item.key, err);
+                    output.push_str(&format!("{}: failed: {}\n", summary, err));
+                }
+            }
+
+            // Replace the record from any earlier run, and save after every row
+            // so an interrupted run can be resumed
+            records.retain(|existing| existing.key != record.key);
+            records.push(record);
+            save_results(&results_path, &records)?;
+        }
+
+        if self.options.dry_run {
+            output.push_str(&format!(
+                "\n{} rows would be posted, {} already posted",
+                items.len() - skipped,
+                skipped
+            ));
+        } else {
+            output.push_str(&format!(
+                "\n{} succeeded, {} failed, {} skipped. Results written to {}",
+                succeeded,
+                failed,
+                skipped,
+                results_path.display()
+            ));
+        }
+
+        Ok(BatchResult {
+            succeeded,
+            failed,
+            skipped,
+            records,
+            formatted_output: output,
+        })
+    }
+
+    /// Post one row, waiting out Reddit's RATELIMIT errors a few times
+    async fn run_item(&mut self, item: &BatchItem) -> (Result<String, RedditClientError>, u32) {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let outcome = match self.client.ensure_access_token().await {
+                Ok(_) => match &item.action {
+                    BatchAction::Submit {
+                        subreddit,
+                        title,
+                        text,
+                    } => self.client.create_post(subreddit, title, text).await,
+                    BatchAction::Comment { thing_id, text } => {
+                        self.client.create_comment(thing_id, text).await
+                    }
+                },
+                Err(err) => Err(err),
+            };
+
+            match &outcome {
+                Err(err) if attempts <= MAX_RATELIMIT_WAITS => {
+                    if let Some(wait) = ratelimit_wait(err) {
+                        warn!(
+                            "Reddit rate limited row {}, waiting {}s before retrying",
+                            item.key,
+                            wait.as_secs()
+                        );
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+
+            return (outcome, attempts);
+        }
+    }
+}
+
+/// A one-line description of a row
+fn describe(item: &BatchItem) -> String {
+    match &item.action {
+        BatchAction::Submit {
+            subreddit, title, ..
+        } => format!(
+            "row {} submit to r/{}: {}",
+            item.key,
+            subreddit.trim_start_matches("r/"),
+            title
+        ),
+        BatchAction::Comment { thing_id, .. } => {
+            format!("row {} comment on {}", item.key, thing_id)
+        }
+    }
+}
+
+/// How long Reddit asks us to wait, if the error is its RATELIMIT error,
+/// e.g. "Take a break for 5 minutes before trying again"
+fn ratelimit_wait(err: &RedditClientError) -> Option<Duration> {
+    let message = err.to_string();
+    if !message.contains("RATELIMIT") && !message.contains("doing that a lot") {
+        return None;
+    }
+
+    let pattern = Regex::new(r"(\d+)\s*(millisecond|second|minute)").unwrap();
+    let wait = pattern
+        .captures(&message)
+        .and_then(|captures| {
+            let amount: u64 = captures[1].parse().ok()?;
+            Some(match &captures[2] {
+                "millisecond" => Duration::from_millis(amount),
+                "second" => Duration::from_secs(amount),
+                _ => Duration::from_secs(amount * 60),
+            })
+        })
+        .unwrap_or(DEFAULT_RATELIMIT_WAIT);
+
+    // Reddit rounds down, so wait a little longer than it says
+    Some(wait + Duration::from_secs(5))
+}
+
+/// CLI handler function for the batch run command with client
+///
+/// Returns an error when any row fails, after recording it, so the run can be
+/// retried with `--resume`.
+pub async fn handle_batch_run_command_with_client(
+    plan_path: PathBuf,
+    results_path: Option<PathBuf>,
+    dry_run: bool,
+    resume: bool,
+    requests_per_minute: u32,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let results_path = results_path.unwrap_or_else(|| default_results_path(&plan_path));
+    let options = BatchOptions {
+        plan_path,
+        results_path,
+        dry_run,
+        resume,
+        requests_per_minute,
+    };
+
+    let mut operation = BatchOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            print_result(&result, output)?;
+            if result.failed > 0 {
+                return Err(RedditClientError::ApiError(format!(
+                    "{} rows failed; run again with --resume to retry them",
+                    result.failed
+                )));
+            }
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error executing batch operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::client::RedditClient;
+use crate::output::{print_result, CommandOutput, OutputFormat};
 use log::{error, info};
+use serde::Serialize;
 
 /// Configuration options for creating a post with browser-based authentication
 #[derive(Debug, Clone)]
@@ -12,10 +14,12 @@ pub struct BrowserCreateOptions {
     pub text: String,
     /// Port to use for the localhost callback (default: 8080)
     pub port: Option<u16>,
+    /// Check the post against the subreddit's requirements before submitting
+    pub validate: bool,
 }
 
 /// Result of a browser-authenticated post creation operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct BrowserCreateResult {
     /// Whether the post was successfully created
     pub success: bool,
@@ -27,6 +31,12 @@ pub struct BrowserCreateResult {
     pub used_stored_tokens: bool,
 }
 
+impl CommandOutput for BrowserCreateResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.message)
+    }
+}
+
 /// Operation for creating a post on Reddit using browser-based OAuth authentication
 pub struct BrowserCreateOperation {
     /// Configuration options for the operation
@@ -65,9 +75,31 @@ impl BrowserCreateOperation {
         );
 
         // Assume client is already properly authenticated
-        let used_stored_tokens = self.client.token_storage.as_ref().map_or(false, |s| {
-            s.is_access_token_valid() || s.has_refresh_token()
-        });
+        let used_stored_tokens = self
+            .client
+            .token_storage
+            .as_ref()
+            .is_some_and(|s| s.is_access_token_valid() || s.has_refresh_token());
+
+        // Check the post against the subreddit's requirements before submitting
+        if self.options.validate {
+            if let Some(message) = self
+                .client
+                .check_self_post(
+                    &self.options.subreddit,
+                    &self.options.title,
+                    &self.options.text,
+                )
+                .await?
+            {
+                return Ok(BrowserCreateResult {
+                    success: false,
+                    post_url: None,
+                    message,
+                    used_stored_tokens,
+                });
+            }
+        }
 
         // Now create the post
         info!("Authentication successful! Creating post...");
@@ -114,6 +146,8 @@ pub async fn handle_browser_create_command_with_client(
     title: String,
     text: String,
     port: Option<u16>,
+    validate: bool,
+    output: OutputFormat,
     client: RedditClient,
 ) -> Result<(), crate::client::RedditClientError> {
     let options = BrowserCreateOptions {
@@ -121,12 +155,15 @@ pub async fn handle_browser_create_command_with_client(
         title,
         text,
         port,
+        validate,
     };
 
     let mut operation = BrowserCreateOperation::with_client(options, client);
     match operation.execute().await {
         Ok(result) => {
-            if result.success {
+            if !output.is_text() {
+                print_result(&result, output)?;
+            } else if result.success {
                 info!("{}", result.message);
             } else {
                 error!("{}", result.message);
@@ -1,5 +1,7 @@
 use crate::client::RedditClient;
+use crate::output::{print_result, CommandOutput, OutputFormat};
 use log::{error, info};
+use serde::Serialize;
 
 /// Configuration options for creating a comment on Reddit
 #[derive(Debug, Clone)]
@@ -12,7 +14,7 @@ pub struct CommentOptions {
 }
 
 /// Result of a comment creation operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct CommentResult {
     /// Whether the comment was successfully created
     pub success: bool,
@@ -22,6 +24,12 @@ pub struct CommentResult {
     pub message: String,
 }
 
+impl CommandOutput for CommentResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.message)
+    }
+}
+
 /// Operation for creating a comment on a post or another comment
 pub struct CommentOperation {
     /// Configuration options for the operation
@@ -88,6 +96,7 @@ impl CommentOperation {
 pub async fn handle_comment_command_with_client(
     thing_id: String,
     text: String,
+    output: OutputFormat,
     client: RedditClient,
 ) -> Result<(), crate::client::RedditClientError> {
     let options = CommentOptions { thing_id, text };
@@ -95,8 +104,8 @@ pub async fn handle_comment_command_with_client(
     let mut operation = CommentOperation::with_client(options, client);
     match operation.execute().await {
         Ok(result) => {
-            if result.success {
-                println!("{}", result.message);
+            if result.success || !output.is_text() {
+                print_result(&result, output)?;
             } else {
                 eprintln!("{}", result.message);
             }
@@ -114,6 +123,7 @@ pub async fn handle_browser_comment_command_with_client(
     thing_id: String,
     text: String,
     _port: Option<u16>, // unused but kept for API compatibility
+    output: OutputFormat,
     client: RedditClient,
 ) -> Result<(), crate::client::RedditClientError> {
     let options = CommentOptions { thing_id, text };
@@ -121,8 +131,8 @@ pub async fn handle_browser_comment_command_with_client(
     let mut operation = CommentOperation::with_client(options, client);
     match operation.execute().await {
         Ok(result) => {
-            if result.success {
-                println!("{}", result.message);
+            if result.success || !output.is_text() {
+                print_result(&result, output)?;
             } else {
                 eprintln!("{}", result.message);
             }
@@ -139,6 +149,7 @@ pub async fn handle_browser_comment_command_with_client(
 pub async fn handle_user_comment_command_with_client(
     thing_id: String,
     text: String,
+    output: OutputFormat,
     client: RedditClient,
 ) -> Result<(), crate::client::RedditClientError> {
     let options = CommentOptions { thing_id, text };
@@ -146,8 +157,8 @@ pub async fn handle_user_comment_command_with_client(
     let mut operation = CommentOperation::with_client(options, client);
     match operation.execute().await {
         Ok(result) => {
-            if result.success {
-                println!("{}", result.message);
+            if result.success || !output.is_text() {
+                print_result(&result, output)?;
             } else {
                 eprintln!("{}", result.message);
             }
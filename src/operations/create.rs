@@ -1,5 +1,7 @@
 use crate::client::RedditClient;
+use crate::output::{print_result, CommandOutput, OutputFormat};
 use log::{error, info};
+use serde::Serialize;
 
 /// Configuration options for creating a post on Reddit
 #[derive(Debug, Clone)]
@@ -10,10 +12,12 @@ pub struct CreateOptions {
     pub title: String,
     /// Text content of the post
     pub text: String,
+    /// Check the post against the subreddit's requirements before submitting
+    pub validate: bool,
 }
 
 /// Result of a post creation operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct CreateResult {
     /// Whether the post was successfully created
     pub success: bool,
@@ -23,6 +27,12 @@ pub struct CreateResult {
     pub message: String,
 }
 
+impl CommandOutput for CreateResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.message)
+    }
+}
+
 /// Operation for creating a post on Reddit using application-only authentication
 pub struct CreateOperation {
     /// Configuration options for the operation
@@ -59,6 +69,25 @@ impl CreateOperation {
 
         // Assume client is already configured with proper authentication
 
+        // Check the post against the subreddit's requirements before submitting
+        if self.options.validate {
+            if let Some(message) = self
+                .client
+                .check_self_post(
+                    &self.options.subreddit,
+                    &self.options.title,
+                    &self.options.text,
+                )
+                .await?
+            {
+                return Ok(CreateResult {
+                    success: false,
+                    post_url: None,
+                    message,
+                });
+            }
+        }
+
         // Now create the post
         match self
             .client
@@ -103,6 +132,7 @@ pub async fn handle_create_command(
         subreddit,
         title,
         text,
+        validate: false,
     };
 
     let mut operation = CreateOperation::new(options);
@@ -127,18 +157,23 @@ pub async fn handle_create_command_with_client(
     subreddit: String,
     title: String,
     text: String,
+    validate: bool,
+    output: OutputFormat,
     client: RedditClient,
 ) -> Result<(), crate::client::RedditClientError> {
     let options = CreateOptions {
         subreddit,
         title,
         text,
+        validate,
     };
 
     let mut operation = CreateOperation::with_client(options, client);
     match operation.execute().await {
         Ok(result) => {
-            if result.success {
+            if !output.is_text() {
+                print_result(&result, output)?;
+            } else if result.success {
                 info!("{}", result.message);
             } else {
                 error!("{}", result.message);
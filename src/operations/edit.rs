@@ -0,0 +1,245 @@
+use crate::client::{RedditClient, RedditClientError};
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+use similar::TextDiff;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Configuration options for editing a post or comment
+#[derive(Debug, Clone)]
+pub struct EditOptions {
+    /// The fullname of the post ("t3_...") or comment ("t1_...") to edit
+    pub fullname: String,
+    /// The new markdown text
+    pub text: String,
+}
+
+/// Result of an edit operation
+#[derive(Debug, Serialize)]
+pub struct EditResult {
+    /// Whether the edit was applied
+    pub success: bool,
+    /// The text before the edit
+    pub old_text: String,
+    /// Unified diff between the old and new text
+    pub diff: String,
+    /// Formatted message for CLI output
+    pub message: String,
+}
+
+impl CommandOutput for EditResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.message)
+    }
+}
+
+/// Operation for editing the text of our own posts and comments
+pub struct EditOperation {
+    /// Configuration options for the operation
+    options: EditOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl EditOperation {
+    /// Create a new edit operation with the provided options
+    pub fn new(options: EditOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new edit operation with a custom Reddit client
+    pub fn with_client(options: EditOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Fetch the current text and return a diff against the new text without editing
+    pub async fn preview(&mut self) -> Result<(String, String), RedditClientError> {
+        self.client.ensure_access_token().await?;
+
+        let old_text = self.client.fetch_user_text(&self.options.fullname).await?;
+        let diff = render_diff(&old_text, &self.options.text, &self.options.fullname);
+
+        Ok((old_text, diff))
+    }
+
+    /// Execute the edit operation
+    pub async fn execute(&mut self) -> Result<EditResult, RedditClientError> {
+        info!("Editing {}", self.options.fullname);
+
+        let (old_text, diff) = self.preview().await?;
+        self.apply(old_text, diff).await
+    }
+
+    /// Send the edit, given the current text and diff from `preview`
+    pub async fn apply(
+        &self,
+        old_text: String,
+        diff: String,
+    ) -> Result<EditResult, RedditClientError> {
+        if old_text == self.options.text {
+            return Ok(EditResult {
+                success: true,
+                old_text,
+                diff,
+                message: format!("No changes to {}", self.options.fullname),
+            });
+        }
+
+        match self
+            .client
+            .edit_text(&self.options.fullname, &self.options.text)
+            .await
+        {
+            Ok(()) => Ok(EditResult {
+                success: true,
+                old_text,
+                diff,
+                message: format!("Edited {} successfully!", self.options.fullname),
+            }),
+            Err(err) => Ok(EditResult {
+                success: false,
+                old_text,
+                diff,
+                message: format!("Error editing {}: {:?}", self.options.fullname, err),
+            }),
+        }
+    }
+}
+
+/// Render a unified diff between two texts, labelled with the thing being changed
+pub fn render_diff(old_text: &str, new_text: &str, label: &str) -> String {
+    TextDiff::from_lines(old_text, new_text)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("{} (current)", label), &format!("{} (new)", label))
+        .to_string()
+}
+
+/// Read replacement text from a file, or from stdin when the path is "-"
+pub fn read_text_file(path: &Path) -> Result<String, RedditClientError> {
+    if path == Path::new("-") {
+        let mut text = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut text).map_err(|e| {
+            RedditClientError::ApiError(format!("Failed to read text from stdin: {}", e))
+        })?;
+        return Ok(text);
+    }
+
+    std::fs::read_to_string(path).map_err(|e| {
+        RedditClientError::ApiError(format!("Failed to read {}: {}", path.display(), e))
+    })
+}
+
+/// Open `$VISUAL` or `$EDITOR` on a temporary file seeded with the current text
+/// and return the edited text
+pub fn edit_in_editor(current: &str, name: &str) -> Result<String, RedditClientError> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    let mut path = std::env::temp_dir();
+    path.push(format!("redrust-{}-{}.md", name, std::process::id()));
+    std::fs::write(&path, current).map_err(|e| {
+        RedditClientError::ApiError(format!("Failed to create temporary file: {}", e))
+    })?;
+
+    // The editor command may include arguments, e.g. "code --wait"
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    let status = Command::new(program)
+        .args(parts)
+        .arg(&path)
+        .status()
+        .map_err(|e| RedditClientError::ApiError(format!("Failed to run {}: {}", editor, e)))?;
+
+    let result = if status.success() {
+        std::fs::read_to_string(&path)
+            .map_err(|e| RedditClientError::ApiError(format!("Failed to read edited text: {}", e)))
+    } else {
+        Err(RedditClientError::ApiError(format!(
+            "Editor {} exited with {}",
+            editor, status
+        )))
+    };
+
+    std::fs::remove_file(&path).ok();
+    result
+}
+
+/// Ask the user to confirm on the terminal, defaulting to no
+pub fn confirm(prompt: &str) -> bool {
+    eprint!("{} [y/N] ", prompt);
+    std::io::stderr().flush().ok();
+
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// CLI handler function for edit command with client
+///
+/// The new text comes from `file` ("-" for stdin) or, when no file is given,
+/// from `$EDITOR` seeded with the current text. A diff is shown before sending,
+/// and the edit is only applied after confirmation unless `yes` is set.
+pub async fn handle_edit_command_with_client(
+    fullname: String,
+    file: Option<PathBuf>,
+    yes: bool,
+    output: OutputFormat,
+    mut client: RedditClient,
+) -> Result<(), RedditClientError> {
+    client.ensure_access_token().await?;
+
+    let text = match &file {
+        Some(path) => read_text_file(path)?,
+        None => {
+            let current = client.fetch_user_text(&fullname).await?;
+            edit_in_editor(&current, &fullname)?
+        }
+    };
+
+    let options = EditOptions { fullname, text };
+    let mut operation = EditOperation::with_client(options, client);
+
+    let (old_text, diff) = operation.preview().await?;
+    // Keep stdout for the result when it's machine-readable
+    if old_text == operation.options.text {
+        let message = format!("No changes to {}", operation.options.fullname);
+        if output.is_text() {
+            println!("{}", message);
+        } else {
+            eprintln!("{}", message);
+        }
+        return Ok(());
+    }
+    if output.is_text() {
+        print!("{}", diff);
+    } else {
+        eprint!("{}", diff);
+    }
+
+    if !yes && !confirm("Apply this edit?") {
+        eprintln!("Edit cancelled.");
+        return Ok(());
+    }
+
+    match operation.apply(old_text, diff).await {
+        Ok(result) => {
+            if result.success || !output.is_text() {
+                print_result(&result, output)?;
+            } else {
+                eprintln!("{}", result.message);
+            }
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error executing edit operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
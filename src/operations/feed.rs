@@ -0,0 +1,108 @@
+use crate::client::{RedditClient, RedditClientError};
+use crate::feed::{render, FeedFormat, FeedSource};
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+
+/// Configuration options for generating a feed
+#[derive(Debug, Clone)]
+pub struct FeedOptions {
+    /// The listing to build the feed from
+    pub source: FeedSource,
+    /// Atom or RSS
+    pub format: FeedFormat,
+    /// The number of posts to include
+    pub limit: u32,
+}
+
+/// Result of a feed operation
+#[derive(Debug, Serialize)]
+pub struct FeedResult {
+    /// The feed's title
+    pub title: String,
+    /// The Reddit page the feed mirrors
+    pub link: String,
+    /// The feed format, "atom" or "rss"
+    pub format: String,
+    /// The number of entries in the feed
+    pub entry_count: usize,
+    /// The feed document
+    pub feed: String,
+}
+
+impl CommandOutput for FeedResult {
+    /// The feed document itself, so it can be redirected to a file
+    fn text(&self) -> String {
+        format!("{}\n", self.feed)
+    }
+}
+
+/// Operation that turns a post listing into an Atom or RSS feed
+pub struct FeedOperation {
+    /// Configuration options for the operation
+    options: FeedOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl FeedOperation {
+    /// Create a new feed operation with the provided options
+    pub fn new(options: FeedOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new feed operation with a custom Reddit client
+    pub fn with_client(options: FeedOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the feed operation
+    pub async fn execute(&mut self) -> Result<FeedResult, RedditClientError> {
+        let source = &self.options.source;
+        info!(
+            "Building {} feed of {} posts for {}",
+            self.options.format,
+            self.options.limit,
+            source.title()
+        );
+
+        let posts = source.fetch(&self.client, self.options.limit).await?;
+        let feed = render(source, &posts, self.options.format);
+
+        Ok(FeedResult {
+            title: source.title(),
+            link: source.link(),
+            format: self.options.format.to_string(),
+            entry_count: posts.len(),
+            feed,
+        })
+    }
+}
+
+/// CLI handler function for feed command that accepts a preconfigured client
+pub async fn handle_feed_command_with_client(
+    source: FeedSource,
+    format: FeedFormat,
+    limit: u32,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let options = FeedOptions {
+        source,
+        format,
+        limit,
+    };
+
+    let mut operation = FeedOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            print_result(&result, output)?;
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error executing feed operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::client::{InboxFolder, ListingParams, RedditClient};
+use crate::models::listing::Thing;
+use crate::operations::user::format_time;
+use crate::output::{print_result, thing_row, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+
+/// Configuration options for reading the account inbox
+#[derive(Debug, Clone)]
+pub struct InboxOptions {
+    /// Which inbox folder to fetch
+    pub folder: InboxFolder,
+    /// The number of items to retrieve
+    pub count: i32,
+    /// Mark the fetched unread items as read afterwards
+    pub mark_read: bool,
+}
+
+impl Default for InboxOptions {
+    fn default() -> Self {
+        Self {
+            folder: InboxFolder::Inbox,
+            count: 25,
+            mark_read: false,
+        }
+    }
+}
+
+/// Result of an inbox fetch operation
+#[derive(Debug, Serialize)]
+pub struct InboxResult {
+    /// The number of items found
+    pub item_count: usize,
+    /// The number of items that were unread when fetched
+    pub unread_count: usize,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+    /// The inbox items, newest first
+    pub items: Vec<Thing>,
+}
+
+impl CommandOutput for InboxResult {
+    fn text(&self) -> String {
+        self.formatted_output.clone()
+    }
+
+    /// One row per item
+    fn rows(&self) -> Vec<serde_json::Value> {
+        self.items.iter().map(thing_row).collect()
+    }
+}
+
+/// Operation for fetching private messages, comment replies and mentions
+pub struct InboxOperation {
+    /// Configuration options for the operation
+    options: InboxOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl InboxOperation {
+    /// Create a new inbox operation with the provided options
+    pub fn new(options: InboxOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new inbox operation with a custom Reddit client
+    pub fn with_client(options: InboxOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the inbox operation
+    pub async fn execute(&mut self) -> Result<InboxResult, crate::client::RedditClientError> {
+        info!(
+            "Fetching {} items from the {} folder",
+            self.options.count, self.options.folder
+        );
+
+        self.client.ensure_access_token().await?;
+
+        // Page through the folder until we have enough items or run out
+        let wanted = self.options.count.max(0) as usize;
+        let mut items: Vec<Thing> = Vec::new();
+        let mut after: Option<String> = None;
+
+        while items.len() < wanted {
+            let params = ListingParams {
+                limit: Some((wanted - items.len()).min(100) as u32),
+                after: after.clone(),
+                count: Some(items.len() as u32),
+                ..ListingParams::default()
+            };
+
+            let page = self
+                .client
+                .fetch_inbox(self.options.folder, &params)
+                .await?;
+            if page.data.children.is_empty() {
+                break;
+            }
+
+            items.extend(page.data.children);
+            after = page.data.after;
+            if after.is_none() {
+                break;
+            }
+        }
+        items.truncate(wanted);
+
+        let unread: Vec<String> = items
+            .iter()
+            .filter(|item| is_unread(item))
+            .filter_map(|item| item.fullname())
+            .collect();
+
+        if self.options.mark_read && !unread.is_empty() {
+            let fullnames: Vec<&str> = unread.iter().map(String::as_str).collect();
+            self.client.read_message(&fullnames).await?;
+        }
+
+        // Generate formatted output for display
+        let mut output = String::new();
+        if items.is_empty() {
+            output.push_str(&format!("No items in {}.\n", self.options.folder));
+        } else {
+            output.push_str(&format!(
+                "Found {} items in {} ({} unread)\n",
+                items.len(),
+                self.options.folder,
+                unread.len()
+            ));
+            self.format_items(&items, &mut output);
+
+            // Print a legend for the item type indicators
+            output.push_str("\nItem Type Legend:\n");
+            output.push_str("[M] = Private message\n");
+            output.push_str("[C] = Comment reply or mention\n");
+            output.push_str("* = Unread\n");
+        }
+
+        if self.options.mark_read && !unread.is_empty() {
+            output.push_str(&format!("\nMarked {} items as read.\n", unread.len()));
+        }
+
+        Ok(InboxResult {
+            item_count: items.len(),
+            unread_count: unread.len(),
+            formatted_output: output,
+            items,
+        })
+    }
+
+    // Internal helper method for the one-line-per-item output format
+    fn format_items(&self, items: &[Thing], output: &mut String) {
+        for (i, item) in items.iter().enumerate() {
+            let marker = if is_unread(item) { "*" } else { " " };
+            match item {
+                Thing::Message(message) => {
+                    output.push_str(&format!(
+                        "{:2}.{}[M] [{}] {} -> {} | {}\n",
+                        i + 1,
+                        marker,
+                        format_time(message.created_utc),
+                        message.sender(),
+                        message.dest,
+                        message.subject
+                    ));
+                    output.push_str(&format!(
+                        "      \"{}\" | ID: {}\n",
+                        message.excerpt(60),
+                        message.name
+                    ));
+                }
+                Thing::Comment(comment) => {
+                    output.push_str(&format!(
+                        "{:2}.{}[C] [{}] u/{} in r/{} | {}\n",
+                        i + 1,
+                        marker,
+                        format_time(comment.created_utc),
+                        comment.author,
+                        comment.subreddit,
+                        comment.link_title.as_deref().unwrap_or_default()
+                    ));
+                    output.push_str(&format!(
+                        "      \"{}\" | ID: {}\n",
+                        comment.excerpt(60),
+                        comment.name
+                    ));
+                }
+                other => {
+                    output.push_str(&format!(
+                        "{:2}.{}[{}] {}\n",
+                        i + 1,
+                        marker,
+                        other.kind(),
+                        other.fullname().unwrap_or_default()
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Whether an inbox item is still unread
+fn is_unread(item: &Thing) -> bool {
+    match item {
+        Thing::Message(message) => message.new,
+        Thing::Comment(comment) => comment.new,
+        _ => false,
+    }
+}
+
+/// CLI handler function for inbox command that accepts a preconfigured client
+pub async fn handle_inbox_command_with_client(
+    folder: InboxFolder,
+    count: i32,
+    mark_read: bool,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), crate::client::RedditClientError> {
+    let options = InboxOptions {
+        folder,
+        count,
+        mark_read,
+    };
+
+    let mut operation = InboxOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            print_result(&result, output)?;
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error fetching inbox: {:?}", err);
+            Err(err)
+        }
+    }
+}
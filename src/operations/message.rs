@@ -0,0 +1,116 @@
+use crate::client::{RedditClient, RedditClientError};
+use crate::operations::edit::read_text_file;
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Configuration options for sending a private message
+#[derive(Debug, Clone)]
+pub struct MessageOptions {
+    /// The recipient username, or `/r/{sub}` to message a subreddit's moderators
+    pub to: String,
+    /// The message subject
+    pub subject: String,
+    /// The message body in markdown
+    pub text: String,
+}
+
+/// Result of a message send operation
+#[derive(Debug, Serialize)]
+pub struct MessageResult {
+    /// Whether the message was successfully sent
+    pub success: bool,
+    /// Formatted message for CLI output
+    pub message: String,
+}
+
+impl CommandOutput for MessageResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.message)
+    }
+}
+
+/// Operation for sending a new private message
+pub struct MessageOperation {
+    /// Configuration options for the operation
+    options: MessageOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl MessageOperation {
+    /// Create a new message operation with the provided options
+    pub fn new(options: MessageOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new message operation with a custom Reddit client
+    pub fn with_client(options: MessageOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the message send operation
+    pub async fn execute(&mut self) -> Result<MessageResult, RedditClientError> {
+        info!("Sending a private message to {}", self.options.to);
+
+        self.client.ensure_access_token().await?;
+
+        match self
+            .client
+            .compose(&self.options.to, &self.options.subject, &self.options.text)
+            .await
+        {
+            Ok(()) => Ok(MessageResult {
+                success: true,
+                message: format!("Message sent to {}", self.options.to),
+            }),
+            Err(err) => Ok(MessageResult {
+                success: false,
+                message: format!(
+                    "Error sending message: {:?}\n\nNote: Messaging requires OAuth authentication with the 'privatemessages' scope.",
+                    err
+                ),
+            }),
+        }
+    }
+}
+
+/// CLI handler function for sending a private message with client
+pub async fn handle_message_send_command_with_client(
+    to: String,
+    subject: String,
+    text: Option<String>,
+    file: Option<PathBuf>,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let text = match (text, file) {
+        (Some(text), None) => text,
+        (None, Some(path)) => read_text_file(&path)?,
+        _ => {
+            return Err(RedditClientError::ApiError(
+                "Provide the message body either as an argument or with --file".to_string(),
+            ))
+        }
+    };
+
+    let options = MessageOptions { to, subject, text };
+
+    let mut operation = MessageOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            if result.success || !output.is_text() {
+                print_result(&result, output)?;
+            } else {
+                eprintln!("{}", result.message);
+            }
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error sending message: {:?}", err);
+            Err(err)
+        }
+    }
+}
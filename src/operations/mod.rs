@@ -1,9 +1,27 @@
 //! Operations module provides functionality for interacting with Reddit
 
+pub mod actions;
 pub mod api_create;
+pub mod archive;
+pub mod batch;
 pub mod browser_create;
 pub mod comment;
 pub mod create;
+pub mod edit;
+pub mod feed;
+pub mod inbox;
+pub mod message;
+pub mod moderation;
+pub mod modmail;
+pub mod modqueue;
 pub mod posts;
+pub mod relationships;
+pub mod rules;
+pub mod schedule;
+pub mod serve;
+pub mod subreddit;
 pub mod token_create;
+pub mod tui;
+pub mod user;
 pub mod user_create;
+pub mod wiki;
@@ -0,0 +1,192 @@
+use crate::client::{DistinguishKind, RedditClient, RedditClientError};
+use crate::operations::actions::{expand_fullnames, ActionResult};
+use crate::output::{print_result, OutputFormat};
+use log::{error, info};
+
+/// A moderator action that can be applied to posts and comments in bulk
+#[derive(Debug, Clone)]
+pub enum ModAction {
+    /// Approve, restoring removed or filtered items
+    Approve,
+    /// Remove, optionally as spam and with a removal reason or mod note
+    Remove {
+        spam: bool,
+        reason_id: Option<String>,
+        mod_note: Option<String>,
+    },
+    /// Lock so no new replies can be made
+    Lock,
+    /// Unlock a locked item
+    Unlock,
+    /// Sticky a post, optionally into a specific slot
+    Sticky { slot: Option<u8> },
+    /// Unsticky a stickied post
+    Unsticky,
+    /// Distinguish, optionally stickying a top-level comment
+    Distinguish { how: DistinguishKind, sticky: bool },
+    /// Mark a post as NSFW
+    MarkNsfw,
+    /// Unmark a post as NSFW
+    UnmarkNsfw,
+    /// Mark a post as a spoiler
+    Spoiler,
+    /// Unmark a post as a spoiler
+    Unspoiler,
+}
+
+impl ModAction {
+    /// Past-tense description of the action for output messages
+    pub fn describe(&self) -> &'static str {
+        match self {
+            ModAction::Approve => "Approved",
+            ModAction::Remove { spam: true, .. } => "Removed as spam",
+            ModAction::Remove { .. } => "Removed",
+            ModAction::Lock => "Locked",
+            ModAction::Unlock => "Unlocked",
+            ModAction::Sticky { .. } => "Stickied",
+            ModAction::Unsticky => "Unstickied",
+            ModAction::Distinguish {
+                how: DistinguishKind::None,
+                ..
+            } => "Undistinguished",
+            ModAction::Distinguish { .. } => "Distinguished",
+            ModAction::MarkNsfw => "Marked NSFW",
+            ModAction::UnmarkNsfw => "Unmarked NSFW",
+            ModAction::Spoiler => "Marked spoiler",
+            ModAction::Unspoiler => "Unmarked spoiler",
+        }
+    }
+}
+
+/// Configuration options for applying a moderator action
+#[derive(Debug, Clone)]
+pub struct ModerationOptions {
+    /// The action to apply
+    pub action: ModAction,
+    /// Fullnames of the posts ("t3_...") and comments ("t1_...") to act on
+    pub fullnames: Vec<String>,
+}
+
+/// Operation for applying moderator actions to posts and comments in bulk
+pub struct ModerationOperation {
+    /// Configuration options for the operation
+    options: ModerationOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl ModerationOperation {
+    /// Create a new moderation operation with the provided options
+    pub fn new(options: ModerationOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new moderation operation with a custom Reddit client
+    pub fn with_client(options: ModerationOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the action against every fullname, continuing past individual failures
+    pub async fn execute(&mut self) -> Result<ActionResult, RedditClientError> {
+        info!(
+            "Applying moderator action {:?} to {} item(s)",
+            self.options.action,
+            self.options.fullnames.len()
+        );
+
+        // Refresh the access token once up front rather than per item
+        self.client.ensure_access_token().await?;
+
+        let mut succeeded = Vec::new();
+        let mut failed = Vec::new();
+        let mut message = String::new();
+
+        for fullname in &self.options.fullnames {
+            match self.apply(fullname).await {
+                Ok(()) => {
+                    message.push_str(&format!(
+                        "{} {}\n",
+                        self.options.action.describe(),
+                        fullname
+                    ));
+                    succeeded.push(fullname.clone());
+                }
+                Err(err) => {
+                    message.push_str(&format!("Failed on {}: {}\n", fullname, err));
+                    failed.push((fullname.clone(), err.to_string()));
+                }
+            }
+        }
+
+        Ok(ActionResult {
+            succeeded,
+            failed,
+            message,
+        })
+    }
+
+    // Internal helper method applying the action to a single item
+    async fn apply(&self, fullname: &str) -> Result<(), RedditClientError> {
+        match &self.options.action {
+            ModAction::Approve => self.client.approve(fullname).await,
+            ModAction::Remove {
+                spam,
+                reason_id,
+                mod_note,
+            } => {
+                self.client.remove(fullname, *spam).await?;
+                if reason_id.is_some() || mod_note.is_some() {
+                    self.client
+                        .set_removal_reason(&[fullname], reason_id.as_deref(), mod_note.as_deref())
+                        .await?;
+                }
+                Ok(())
+            }
+            ModAction::Lock => self.client.set_locked(fullname, true).await,
+            ModAction::Unlock => self.client.set_locked(fullname, false).await,
+            ModAction::Sticky { slot } => self.client.set_sticky(fullname, true, *slot).await,
+            ModAction::Unsticky => self.client.set_sticky(fullname, false, None).await,
+            ModAction::Distinguish { how, sticky } => {
+                self.client.distinguish(fullname, *how, *sticky).await
+            }
+            ModAction::MarkNsfw => self.client.set_nsfw(fullname, true).await,
+            ModAction::UnmarkNsfw => self.client.set_nsfw(fullname, false).await,
+            ModAction::Spoiler => self.client.set_spoiler(fullname, true).await,
+            ModAction::Unspoiler => self.client.set_spoiler(fullname, false).await,
+        }
+    }
+}
+
+/// CLI handler function for moderator action commands with client
+pub async fn handle_mod_command_with_client(
+    action: ModAction,
+    ids: Vec<String>,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let options = ModerationOptions {
+        action,
+        fullnames: expand_fullnames(ids)?,
+    };
+
+    let mut operation = ModerationOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            print_result(&result, output)?;
+            if result.failed.is_empty() {
+                Ok(())
+            } else {
+                Err(RedditClientError::ApiError(format!(
+                    "{} of {} moderator actions failed",
+                    result.failed.len(),
+                    result.failed.len() + result.succeeded.len()
+                )))
+            }
+        }
+        Err(err) => {
+            error!("Error executing moderator action: {:?}", err);
+            Err(err)
+        }
+    }
+}
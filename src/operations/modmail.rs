@@ -0,0 +1,384 @@
+use crate::client::{ModmailState, RedditClient, RedditClientError};
+use crate::models::modmail::{ModmailConversation, ModmailMessage};
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+
+/// A new modmail request: listing, reading, replying or changing a conversation's state
+#[derive(Debug, Clone)]
+pub enum ModmailRequest {
+    /// List conversations, newest activity first
+    List {
+        /// Subreddits to include; empty for every subreddit we moderate
+        subreddits: Vec<String>,
+        /// Which conversations to include
+        state: ModmailState,
+        /// The number of conversations to retrieve
+        count: u32,
+    },
+    /// Show a conversation with all of its messages
+    Show { conversation_id: String },
+    /// Reply to a conversation
+    Reply {
+        conversation_id: String,
+        body: String,
+        /// Reply as the subreddit instead of as ourselves
+        as_subreddit: bool,
+        /// Leave a private moderator note
+        internal: bool,
+    },
+    /// Archive conversations
+    Archive { conversation_ids: Vec<String> },
+    /// Move archived conversations back to the inbox
+    Unarchive { conversation_ids: Vec<String> },
+    /// Highlight conversations
+    Highlight { conversation_ids: Vec<String> },
+    /// Remove the highlight from conversations
+    Unhighlight { conversation_ids: Vec<String> },
+    /// Mute the user in a conversation for 3, 7 or 28 days
+    Mute { conversation_id: String, days: u32 },
+    /// Unmute the user in a conversation
+    Unmute { conversation_id: String },
+}
+
+/// Configuration options for a new modmail operation
+#[derive(Debug, Clone)]
+pub struct ModmailOptions {
+    /// What to do
+    pub request: ModmailRequest,
+}
+
+/// Result of a new modmail operation
+#[derive(Debug, Serialize)]
+pub struct ModmailResult {
+    /// Whether every part of the request succeeded
+    pub success: bool,
+    /// The conversations listed, shown or replied to
+    pub conversations: Vec<ModmailConversation>,
+    /// The messages of a shown conversation, oldest first
+    pub messages: Vec<ModmailMessage>,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+}
+
+impl ModmailResult {
+    // Internal helper for requests that return no conversations
+    fn message(success: bool, formatted_output: String) -> Self {
+        Self {
+            success,
+            conversations: Vec::new(),
+            messages: Vec::new(),
+            formatted_output,
+        }
+    }
+}
+
+impl CommandOutput for ModmailResult {
+    fn text(&self) -> String {
+        self.formatted_output.clone()
+    }
+
+    /// The messages of a shown conversation, or the conversations listed
+    fn rows(&self) -> Vec<serde_json::Value> {
+        let rows = if self.messages.is_empty() {
+            serde_json::to_value(&self.conversations)
+        } else {
+            serde_json::to_value(&self.messages)
+        };
+        match rows {
+            Ok(serde_json::Value::Array(rows)) if !rows.is_empty() => rows,
+            _ => vec![serde_json::json!({ "success": self.success })],
+        }
+    }
+}
+
+/// Operation for reading and acting on new modmail conversations
+pub struct ModmailOperation {
+    /// Configuration options for the operation
+    options: ModmailOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl ModmailOperation {
+    /// Create a new modmail operation with the provided options
+    pub fn new(options: ModmailOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new modmail operation with a custom Reddit client
+    pub fn with_client(options: ModmailOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the modmail operation
+    pub async fn execute(&mut self) -> Result<ModmailResult, RedditClientError> {
+        info!("Executing modmail request {:?}", self.options.request);
+
+        // Refresh the access token up front; modmail sessions are often long-running scripts
+        self.client.ensure_access_token().await?;
+
+        match self.options.request.clone() {
+            ModmailRequest::List {
+                subreddits,
+                state,
+                count,
+            } => self.list(&subreddits, state, count).await,
+            ModmailRequest::Show { conversation_id } => self.show(&conversation_id).await,
+            ModmailRequest::Reply {
+                conversation_id,
+                body,
+                as_subreddit,
+                internal,
+            } => {
+                let response = self
+                    .client
+                    .reply_modmail(&conversation_id, &body, as_subreddit, internal)
+                    .await?;
+                let kind = if internal { "private note" } else { "reply" };
+                let formatted_output = format!(
+                    "Sent {} to \"{}\" ({} messages)\n",
+                    kind, response.conversation.subject, response.conversation.num_messages
+                );
+                Ok(ModmailResult {
+                    success: true,
+                    conversations: vec![response.conversation],
+                    messages: Vec::new(),
+                    formatted_output,
+                })
+            }
+            ModmailRequest::Archive { conversation_ids } => {
+                self.update_each(&conversation_ids, "Archived").await
+            }
+            ModmailRequest::Unarchive { conversation_ids } => {
+                self.update_each(&conversation_ids, "Unarchived").await
+            }
+            ModmailRequest::Highlight { conversation_ids } => {
+                self.update_each(&conversation_ids, "Highlighted").await
+            }
+            ModmailRequest::Unhighlight { conversation_ids } => {
+                self.update_each(&conversation_ids, "Unhighlighted").await
+            }
+            ModmailRequest::Mute {
+                conversation_id,
+                days,
+            } => {
+                self.client
+                    .mute_modmail(&conversation_id, days * 24)
+                    .await?;
+                Ok(ModmailResult::message(
+                    true,
+                    format!(
+                        "Muted the user in conversation {} for {} days\n",
+                        conversation_id, days
+                    ),
+                ))
+            }
+            ModmailRequest::Unmute { conversation_id } => {
+                self.client.unmute_modmail(&conversation_id).await?;
+                Ok(ModmailResult::message(
+                    true,
+                    format!("Unmuted the user in conversation {}\n", conversation_id),
+                ))
+            }
+        }
+    }
+
+    // Internal helper method listing conversations across pages
+    async fn list(
+        &self,
+        subreddits: &[String],
+        state: ModmailState,
+        count: u32,
+    ) -> Result<ModmailResult, RedditClientError> {
+        let subreddits: Vec<&str> = subreddits.iter().map(String::as_str).collect();
+        let wanted = count as usize;
+        let mut conversations: Vec<(ModmailConversation, Option<ModmailMessage>)> = Vec::new();
+        let mut after: Option<String> = None;
+
+        while conversations.len() < wanted {
+            let limit = (wanted - conversations.len()).min(100) as u32;
+            let mut page = self
+                .client
+                .fetch_modmail_conversations(&subreddits, state, Some(limit), after.as_deref())
+                .await?;
+            if page.conversation_ids.is_empty() {
+                break;
+            }
+
+            after = page.conversation_ids.last().cloned();
+            let page_len = page.conversation_ids.len();
+            for id in std::mem::take(&mut page.conversation_ids) {
+                if let Some(conversation) = page.conversations.remove(&id) {
+                    // The listing includes only the latest message of each conversation
+                    let latest = conversation
+                        .message_ids()
+                        .last()
+                        .and_then(|message_id| page.messages.remove(*message_id));
+                    conversations.push((conversation, latest));
+                }
+            }
+
+            if page_len < limit as usize {
+                break;
+            }
+        }
+        conversations.truncate(wanted);
+
+        let mut output = String::new();
+        if conversations.is_empty() {
+            output.push_str(&format!("No {} modmail conversations.\n", state));
+        } else {
+            output.push_str(&format!(
+                "Found {} {} modmail conversations\n",
+                conversations.len(),
+                state
+            ));
+            for (i, (conversation, latest)) in conversations.iter().enumerate() {
+                let mut flags = Vec::new();
+                if conversation.is_unread() {
+                    flags.push("unread");
+                }
+                if conversation.is_highlighted {
+                    flags.push("highlighted");
+                }
+                if conversation.is_internal {
+                    flags.push("mod discussion");
+                }
+
+                output.push_str(&format!(
+                    "{:2}. [r/{}] {} | ID: {} | {} | {} messages",
+                    i + 1,
+                    conversation.owner.display_name,
+                    conversation.subject,
+                    conversation.id,
+                    conversation.state_name(),
+                    conversation.num_messages
+                ));
+                if !flags.is_empty() {
+                    output.push_str(&format!(" [{}]", flags.join(", ")));
+                }
+                output.push('\n');
+
+                if let Some(message) = latest {
+                    let excerpt = message.body_markdown.trim().replace('\n', " ");
+                    let excerpt: String = excerpt.chars().take(60).collect();
+                    output.push_str(&format!(
+                        "      last from u/{}: \"{}\"\n",
+                        message.author.name, excerpt
+                    ));
+                }
+            }
+        }
+
+        Ok(ModmailResult {
+            success: true,
+            conversations: conversations
+                .into_iter()
+                .map(|(conversation, _)| conversation)
+                .collect(),
+            messages: Vec::new(),
+            formatted_output: output,
+        })
+    }
+
+    // Internal helper method showing one conversation in full
+    async fn show(&self, conversation_id: &str) -> Result<ModmailResult, RedditClientError> {
+        let mut response = self
+            .client
+            .fetch_modmail_conversation(conversation_id, true)
+            .await?;
+        let conversation = &response.conversation;
+
+        let mut output = format!(
+            "Subject: {}\nSubreddit: r/{}\nState: {}\n",
+            conversation.subject,
+            conversation.owner.display_name,
+            conversation.state_name()
+        );
+        if let Some(participant) = &conversation.participant {
+            output.push_str(&format!("Participant: u/{}\n", participant.name));
+        }
+        output.push('\n');
+
+        for message in response.ordered_messages() {
+            output.push_str(&message.format_summary());
+            output.push('\n');
+        }
+
+        let message_ids: Vec<String> = conversation
+            .message_ids()
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let messages = message_ids
+            .iter()
+            .filter_map(|id| response.messages.remove(id))
+            .collect();
+
+        Ok(ModmailResult {
+            success: true,
+            conversations: vec![response.conversation],
+            messages,
+            formatted_output: output,
+        })
+    }
+
+    // Internal helper method applying a state change to each conversation
+    async fn update_each(
+        &self,
+        conversation_ids: &[String],
+        verb: &str,
+    ) -> Result<ModmailResult, RedditClientError> {
+        let mut output = String::new();
+        let mut success = true;
+
+        for id in conversation_ids {
+            let outcome = match &self.options.request {
+                ModmailRequest::Archive { .. } => self.client.archive_modmail(id).await,
+                ModmailRequest::Unarchive { .. } => self.client.unarchive_modmail(id).await,
+                ModmailRequest::Highlight { .. } => self.client.highlight_modmail(id).await,
+                _ => self.client.unhighlight_modmail(id).await,
+            };
+
+            match outcome {
+                Ok(()) => output.push_str(&format!("{} conversation {}\n", verb, id)),
+                Err(err) => {
+                    success = false;
+                    output.push_str(&format!("Failed on {}: {}\n", id, err));
+                }
+            }
+        }
+
+        Ok(ModmailResult::message(success, output))
+    }
+}
+
+/// CLI handler function for modmail commands with client
+pub async fn handle_modmail_command_with_client(
+    request: ModmailRequest,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let options = ModmailOptions { request };
+
+    let mut operation = ModmailOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            print_result(&result, output)?;
+            if result.success {
+                Ok(())
+            } else {
+                Err(RedditClientError::ApiError(
+                    "Some modmail updates failed".to_string(),
+                ))
+            }
+        }
+        Err(err) => {
+            error!("Error executing modmail request: {:?}", err);
+            Err(err)
+        }
+    }
+}
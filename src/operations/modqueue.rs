@@ -0,0 +1,219 @@
+use crate::client::{ListingParams, ModListing, RedditClient};
+use crate::models::listing::Thing;
+use crate::models::report::format_reports;
+use crate::operations::user::format_time;
+use crate::output::{print_result, thing_row, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+
+/// Configuration options for fetching a subreddit's moderation listing
+#[derive(Debug, Clone)]
+pub struct ModqueueOptions {
+    /// The subreddit to moderate (with or without the `r/` prefix)
+    pub subreddit: String,
+    /// Which moderation listing to fetch
+    pub listing: ModListing,
+    /// The number of items to retrieve
+    pub count: i32,
+}
+
+impl Default for ModqueueOptions {
+    fn default() -> Self {
+        Self {
+            subreddit: String::new(),
+            listing: ModListing::Modqueue,
+            count: 25,
+        }
+    }
+}
+
+/// Result of a moderation listing fetch operation
+#[derive(Debug, Serialize)]
+pub struct ModqueueResult {
+    /// The number of items found
+    pub item_count: usize,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+    /// The listing items, in the order Reddit returned them
+    pub items: Vec<Thing>,
+}
+
+impl CommandOutput for ModqueueResult {
+    fn text(&self) -> String {
+        self.formatted_output.clone()
+    }
+
+    /// One row per item
+    fn rows(&self) -> Vec<serde_json::Value> {
+        self.items.iter().map(thing_row).collect()
+    }
+}
+
+/// Operation for fetching the modqueue, reports, spam, edited and unmoderated listings
+pub struct ModqueueOperation {
+    /// Configuration options for the operation
+    options: ModqueueOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl ModqueueOperation {
+    /// Create a new modqueue operation with the provided options
+    pub fn new(options: ModqueueOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new modqueue operation with a custom Reddit client
+    pub fn with_client(options: ModqueueOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the modqueue operation
+    pub async fn execute(&mut self) -> Result<ModqueueResult, crate::client::RedditClientError> {
+        info!(
+            "Fetching {} {} items for r/{}",
+            self.options.count, self.options.listing, self.options.subreddit
+        );
+
+        self.client.ensure_access_token().await?;
+
+        // Page through the listing until we have enough items or run out
+        let wanted = self.options.count.max(0) as usize;
+        let mut items: Vec<Thing> = Vec::new();
+        let mut after: Option<String> = None;
+
+        while items.len() < wanted {
+            let params = ListingParams {
+                limit: Some((wanted - items.len()).min(100) as u32),
+                after: after.clone(),
+                count: Some(items.len() as u32),
+                ..ListingParams::default()
+            };
+
+            let page = self
+                .client
+                .fetch_mod_listing(&self.options.subreddit, self.options.listing, &params)
+                .await?;
+
+            if page.data.children.is_empty() {
+                break;
+            }
+
+            items.extend(page.data.children);
+            after = page.data.after;
+            if after.is_none() {
+                break;
+            }
+        }
+        items.truncate(wanted);
+
+        // Generate formatted output for display
+        let mut output = String::new();
+        if items.is_empty() {
+            output.push_str(&format!(
+                "No items in the {} for r/{}.\n",
+                self.options.listing, self.options.subreddit
+            ));
+        } else {
+            output.push_str(&format!(
+                "Found {} items in the {} for r/{}\n",
+                items.len(),
+                self.options.listing,
+                self.options.subreddit
+            ));
+            self.format_items(&items, &mut output);
+
+            // Print a legend for the item type indicators
+            output.push_str("\nItem Type Legend:\n");
+            output.push_str("[P] = Post\n");
+            output.push_str("[C] = Comment\n");
+        }
+
+        Ok(ModqueueResult {
+            item_count: items.len(),
+            formatted_output: output,
+            items,
+        })
+    }
+
+    // Internal helper method listing each item with its ID and reports
+    fn format_items(&self, items: &[Thing], output: &mut String) {
+        for (i, item) in items.iter().enumerate() {
+            match item {
+                Thing::Link(post) => {
+                    output.push_str(&format!(
+                        "{:2}. [P] ID: t3_{} | [{}] {} - by u/{} | {} pts\n",
+                        i + 1,
+                        post.id,
+                        format_time(post.created_utc),
+                        post.title,
+                        post.author,
+                        post.score
+                    ));
+                    output.push_str(&format!("    https://reddit.com{}\n", post.permalink));
+                }
+                Thing::Comment(comment) => {
+                    output.push_str(&format!(
+                        "{:2}. [C] ID: {} | [{}] \"{}\" - by u/{} | {} pts\n",
+                        i + 1,
+                        comment.name,
+                        format_time(comment.created_utc),
+                        comment.excerpt(60),
+                        comment.author,
+                        comment.score
+                    ));
+                    output.push_str(&format!("    https://reddit.com{}\n", comment.permalink));
+                }
+                other => {
+                    output.push_str(&format!(
+                        "{:2}. [{}] ID: {}\n",
+                        i + 1,
+                        other.kind(),
+                        other.fullname().unwrap_or_default()
+                    ));
+                }
+            }
+
+            output.push_str(&format_reports(item.user_reports(), item.mod_reports()));
+        }
+    }
+}
+
+/// CLI handler function for modqueue command that accepts a preconfigured client
+pub async fn handle_modqueue_command_with_client(
+    subreddit: String,
+    listing: ModListing,
+    count: i32,
+    ids_only: bool,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), crate::client::RedditClientError> {
+    let options = ModqueueOptions {
+        subreddit,
+        listing,
+        count,
+    };
+
+    let mut operation = ModqueueOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            if ids_only && output.is_text() {
+                // One fullname per line, ready to pipe into `redrust mod approve -`
+                for item in &result.items {
+                    if let Some(fullname) = item.fullname() {
+                        println!("{}", fullname);
+                    }
+                }
+            } else {
+                print_result(&result, output)?;
+            }
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error fetching moderation listing: {:?}", err);
+            Err(err)
+        }
+    }
+}
@@ -1,8 +1,17 @@
-use crate::client::RedditClient;
-use crate::models::RedditRNewResponse;
-use chrono::DateTime;
-use chrono_tz::America::Los_Angeles;
-use log::{error, info};
+use crate::client::{RedditClient, RedditClientError, StreamConfig, SubmissionStream};
+use crate::filter::PostFilter;
+use crate::models::{RedditPostData, RedditRNewResponse};
+use crate::output::time::time_display;
+use crate::output::{print_result, render_ndjson, CommandOutput, OutputFormat};
+use log::{debug, error, info};
+use serde::Serialize;
+
+/// Posts requested per page while looking for posts matching a filter
+const FILTER_PAGE_SIZE: i32 = 100;
+
+/// Most pages scanned while looking for posts matching a filter; Reddit
+/// stops paginating listings at about 1000 items anyway
+const MAX_FILTER_PAGES: usize = 10;
 
 /// Configuration options for fetching posts
 #[derive(Debug, Clone)]
@@ -13,8 +22,12 @@ pub struct PostsOptions {
     pub subreddit: Option<String>,
     /// Display posts in a brief, one-line format
     pub brief: bool,
+    /// Keep polling for new posts after the initial fetch
+    pub follow: bool,
     /// Custom user agent for the Reddit client (optional)
     pub user_agent: Option<String>,
+    /// Only show posts matching these conditions, fetching more pages as needed
+    pub filter: PostFilter,
 }
 
 impl Default for PostsOptions {
@@ -23,22 +36,43 @@ impl Default for PostsOptions {
             count: 10,
             subreddit: None,
             brief: false,
+            follow: false,
             user_agent: None,
+            filter: PostFilter::default(),
         }
     }
 }
 
 /// Result of a posts fetch operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct PostsResult {
     /// The number of posts found
     pub post_count: usize,
+    /// The number of posts fetched, including those the filter hid
+    pub scanned: usize,
     /// Formatted output (for CLI display)
+    #[serde(skip)]
     pub formatted_output: String,
     /// The raw API response data
     pub raw_response: RedditRNewResponse,
 }
 
+impl CommandOutput for PostsResult {
+    fn text(&self) -> String {
+        self.formatted_output.clone()
+    }
+
+    /// One row per post
+    fn rows(&self) -> Vec<serde_json::Value> {
+        self.raw_response
+            .data
+            .children
+            .iter()
+            .filter_map(|post| serde_json::to_value(&post.data).ok())
+            .collect()
+    }
+}
+
 /// Operation for fetching posts from Reddit
 pub struct PostsOperation {
     /// Configuration options for the operation
@@ -67,7 +101,7 @@ impl PostsOperation {
     }
 
     /// Execute the posts operation
-    pub async fn execute(&self) -> Result<PostsResult, crate::client::RedditClientError> {
+    pub async fn execute(&self) -> Result<PostsResult, RedditClientError> {
         // Fetch posts from either a specific subreddit or the public frontpage
         info!(
             "Fetching {} posts from {}",
@@ -78,10 +112,13 @@ impl PostsOperation {
                 .unwrap_or("public frontpage")
         );
 
-        let posts_result = match &self.options.subreddit {
-            Some(sub) => self.client.fetch_new_posts(sub, self.options.count).await,
-            None => self.client.fetch_public_new_posts(self.options.count).await,
-        }?;
+        let (posts_result, scanned) = if self.options.filter.is_active() {
+            self.fetch_filtered().await?
+        } else {
+            let response = self.fetch_page(self.options.count, None).await?;
+            let scanned = response.data.children.len();
+            (response, scanned)
+        };
 
         // Generate formatted output for display
         let mut output = String::new();
@@ -93,11 +130,23 @@ impl PostsOperation {
                 "Found {} posts\n",
                 posts_result.data.children.len()
             ));
+            if self.options.filter.is_active() {
+                output.push_str(&format!(
+                    "({} matched the filters out of {} fetched)\n",
+                    posts_result.data.children.len(),
+                    scanned
+                ));
+            }
 
-            if self.options.brief {
-                // Implementation of brief format output
-                self.format_brief_output(&posts_result, &mut output);
+            let posts: Vec<&RedditPostData> = posts_result
+                .data
+                .children
+                .iter()
+                .map(|post| &post.data)
+                .collect();
+            output.push_str(&self.format_posts(&posts, 0));
 
+            if self.options.brief {
                 // Print a legend for the post type indicators
                 output.push_str("\nPost Type Legend:\n");
                 output.push_str("[T] = Text post\n");
@@ -105,56 +154,139 @@ impl PostsOperation {
                 output.push_str("[I] = Image\n");
                 output.push_str("[G] = Gallery\n");
                 output.push_str("[L] = Link\n");
-            } else {
-                // Implementation of detailed format output
-                self.format_detailed_output(&posts_result, &mut output);
             }
         }
 
         Ok(PostsResult {
             post_count: posts_result.data.children.len(),
+            scanned,
             formatted_output: output,
             raw_response: posts_result,
         })
     }
 
+    /// Fetch one page of new posts from the subreddit or the public frontpage
+    async fn fetch_page(
+        &self,
+        limit: i32,
+        after: Option<&str>,
+    ) -> Result<RedditRNewResponse, RedditClientError> {
+        match &self.options.subreddit {
+            Some(sub) => self.client.fetch_new_posts_after(sub, limit, after).await,
+            None => self.client.fetch_public_new_posts_after(limit, after).await,
+        }
+    }
+
+    /// Fetch pages until `count` posts match the filter, the listing ends, or
+    /// the posts get older than `--max-age`.
+    ///
+    /// Returns the matching posts and the number of posts fetched.
+    async fn fetch_filtered(&self) -> Result<(RedditRNewResponse, usize), RedditClientError> {
+        let filter = &self.options.filter;
+        let wanted = self.options.count.max(0) as usize;
+        let mut response = self.fetch_page(FILTER_PAGE_SIZE, None).await?;
+        let mut matched = Vec::new();
+        let mut scanned = 0;
+
+        for page in 1..=MAX_FILTER_PAGES {
+            let children = std::mem::take(&mut response.data.children);
+            scanned += children.len();
+
+            // The listing is newest first, so once a page ends with a post that is
+            // too old, later pages can't match (stickied posts aside)
+            let exhausted = children
+                .last()
+                .is_none_or(|post| filter.is_too_old(&post.data));
+            matched.extend(
+                children
+                    .into_iter()
+                    .filter(|post| filter.matches(&post.data)),
+            );
+            debug!(
+                "Filtered page {}: {} of {} posts match so far",
+                page,
+                matched.len(),
+                scanned
+            );
+
+            if matched.len() >= wanted || exhausted {
+                break;
+            }
+            let Some(after) = response.data.after.clone() else {
+                break;
+            };
+            if page == MAX_FILTER_PAGES {
+                info!(
+                    "Stopped after {} posts with {} of {} matches",
+                    scanned,
+                    matched.len(),
+                    wanted
+                );
+                break;
+            }
+            response = self.fetch_page(FILTER_PAGE_SIZE, Some(&after)).await?;
+        }
+
+        matched.truncate(wanted);
+        response.data.children = matched;
+        Ok((response, scanned))
+    }
+
+    /// Format posts in the configured brief or detailed style.
+    ///
+    /// `first_index` offsets the brief format's numbering, so batches from a
+    /// followed stream continue where the previous output left off.
+    pub fn format_posts(&self, posts: &[&RedditPostData], first_index: usize) -> String {
+        let mut output = String::new();
+        if self.options.brief {
+            self.format_brief_output(posts, first_index, &mut output);
+        } else {
+            self.format_detailed_output(posts, &mut output);
+        }
+        output
+    }
+
+    /// Start a stream of posts newer than `shown`, the posts `execute` returned.
+    ///
+    /// Returns `None` for the public frontpage, which can't be followed.
+    pub fn stream(&self, shown: &[RedditPostData]) -> Option<SubmissionStream> {
+        let subreddit = self.options.subreddit.as_deref()?;
+        let mut stream = self
+            .client
+            .stream_submissions(subreddit, StreamConfig::default());
+        stream.seed(shown);
+        Some(stream)
+    }
+
     // Internal helper method for brief output format
-    fn format_brief_output(&self, response: &RedditRNewResponse, output: &mut String) {
-        for (i, post) in response.data.children.iter().enumerate() {
-            let local_time = DateTime::from_timestamp(post.data.created_utc as i64, 0)
-                .unwrap()
-                .with_timezone(&Los_Angeles);
-            let timestamp_str = local_time.format("%H:%M").to_string();
+    fn format_brief_output(
+        &self,
+        posts: &[&RedditPostData],
+        first_index: usize,
+        output: &mut String,
+    ) {
+        for (i, post) in posts.iter().enumerate() {
+            let timestamp_str = time_display().format_timestamp(post.created_utc, "%H:%M");
 
             // Create the API thing_id (t3_ prefix for posts)
-            let thing_id = format!("t3_{}", post.data.id);
+            let thing_id = format!("t3_{}", post.id);
 
             // Determine post type indicator with a single character
-            let (post_type, _type_code) = if post.data.is_self {
-                ("T", "Text") // Text post
-            } else if post.data.is_video {
-                ("V", "Video") // Video
-            } else if post.data.url.contains("i.redd.it") || post.data.url.contains("imgur.com") {
-                ("I", "Image") // Image
-            } else if post.data.url.contains("reddit.com/gallery") {
-                ("G", "Gallery") // Gallery
-            } else {
-                ("L", "Link") // Link
-            };
+            let post_type = post.type_indicator();
 
             // Truncate the title if necessary (30 chars), safely handling UTF-8
-            let title = if post.data.title.chars().count() > 30 {
-                let mut chars = post.data.title.chars().take(27).collect::<String>();
+            let title = if post.title.chars().count() > 30 {
+                let mut chars = post.title.chars().take(27).collect::<String>();
                 chars.push_str("...");
                 chars
             } else {
-                post.data.title.clone()
+                post.title.clone()
             };
 
             // Get content excerpt or URL
-            let content = if post.data.is_self {
+            let content = if post.is_self {
                 // For text posts, get a brief excerpt
-                let text = post.data.selftext.trim();
+                let text = post.selftext.trim();
                 if text.is_empty() {
                     "[No content]".to_string()
                 } else if text.chars().count() > 30 {
@@ -165,13 +297,13 @@ impl PostsOperation {
                 }
             } else {
                 // For non-text posts, get shortened URL
-                let url_display = if post.data.url.len() > 30 {
-                    let shortened_url = if post.data.url.starts_with("https://") {
-                        post.data.url[8..].to_string() // Remove https:// for display
-                    } else if post.data.url.starts_with("http://") {
-                        post.data.url[7..].to_string() // Remove http:// for display
+                let url_display = if post.url.len() > 30 {
+                    let shortened_url = if post.url.starts_with("https://") {
+                        post.url[8..].to_string() // Remove https:// for display
+                    } else if post.url.starts_with("http://") {
+                        post.url[7..].to_string() // Remove http:// for display
                     } else {
-                        post.data.url.clone()
+                        post.url.clone()
                     };
 
                     if shortened_url.len() > 30 {
@@ -180,23 +312,23 @@ impl PostsOperation {
                         shortened_url
                     }
                 } else {
-                    post.data.url.clone()
+                    post.url.clone()
                 };
 
                 url_display
             };
 
             // Construct permalink URL
-            let permalink = format!("https://reddit.com{}", post.data.permalink);
+            let permalink = format!("https://reddit.com{}", post.permalink);
 
             output.push_str(&format!(
                 "{:2}. [{}] [{}] {} ({}) r/{} | ID: {} | {}\n",
-                i + 1,
+                first_index + i + 1,
                 post_type,
                 timestamp_str,
                 title,
                 content,
-                post.data.subreddit,
+                post.subreddit,
                 thing_id,
                 permalink
             ));
@@ -204,24 +336,26 @@ impl PostsOperation {
     }
 
     // Internal helper method for detailed output format
-    fn format_detailed_output(&self, response: &RedditRNewResponse, output: &mut String) {
-        for post in &response.data.children {
-            let local_time = DateTime::from_timestamp(post.data.created_utc as i64, 0)
-                .unwrap()
-                .with_timezone(&Los_Angeles);
-            let timestamp_str = local_time.format("%Y-%m-%d %H:%M:%S").to_string();
+    fn format_detailed_output(&self, posts: &[&RedditPostData], output: &mut String) {
+        let display = time_display();
+        for post in posts {
+            let timestamp_str = display.format_timestamp(post.created_utc, "%Y-%m-%d %H:%M:%S");
 
             // Create the API thing_id (t3_ prefix for posts)
-            let thing_id = format!("t3_{}", post.data.id);
+            let thing_id = format!("t3_{}", post.id);
 
             // Display post with more details
             output.push_str("\n============ POST =============\n");
-            output.push_str(&format!("[{}] [Los Angeles time]\n", timestamp_str));
+            if display.relative {
+                output.push_str(&format!("[{}]\n", timestamp_str));
+            } else {
+                output.push_str(&format!("[{}] [{}]\n", timestamp_str, display.zone_label()));
+            }
             output.push_str(&format!(
                 "Thing ID: {} (use this for commenting)\n",
                 thing_id
             ));
-            output.push_str(&post.data.format_summary());
+            output.push_str(&post.format_summary());
             output.push_str("\n================================\n\n");
         }
     }
@@ -232,12 +366,14 @@ pub async fn handle_posts_command(
     count: i32,
     subreddit: Option<String>,
     brief: bool,
-) -> Result<(), crate::client::RedditClientError> {
+) -> Result<(), RedditClientError> {
     let options = PostsOptions {
         count,
         subreddit,
         brief,
+        follow: false,
         user_agent: None,
+        filter: PostFilter::default(),
     };
 
     // Create a new operation with the default client
@@ -255,26 +391,96 @@ pub async fn handle_posts_command(
     }
 }
 
+/// Print new posts as they arrive until interrupted with Ctrl-C.
+///
+/// `shown` are the posts already printed. With NDJSON output each new post is
+/// printed as one more line.
+async fn follow_posts(operation: &PostsOperation, shown: &[RedditPostData], output: OutputFormat) {
+    let Some(mut stream) = operation.stream(shown) else {
+        error!("Following requires a subreddit");
+        return;
+    };
+
+    if output.is_text() {
+        println!("\nFollowing new posts (Ctrl-C to stop)...");
+    }
+    let mut shown = shown.len();
+    loop {
+        tokio::select! {
+            batch = stream.next_batch() => {
+                let filter = &operation.options.filter;
+                let posts: Vec<&RedditPostData> =
+                    batch.iter().filter(|post| filter.matches(post)).collect();
+                if output.is_text() {
+                    print!("{}", operation.format_posts(&posts, shown));
+                } else {
+                    let rows: Vec<serde_json::Value> = posts
+                        .iter()
+                        .filter_map(|post| serde_json::to_value(post).ok())
+                        .collect();
+                    match render_ndjson(&rows) {
+                        Ok(lines) => print!("{}", lines),
+                        Err(err) => error!("Failed to print new posts: {}", err),
+                    }
+                }
+                shown += posts.len();
+            }
+            _ = tokio::signal::ctrl_c() => {
+                if output.is_text() {
+                    println!("\nStopped following.");
+                }
+                return;
+            }
+        }
+    }
+}
+
 /// CLI handler function for posts command that accepts a preconfigured client
 pub async fn handle_posts_command_with_client(
     count: i32,
     subreddit: Option<String>,
     brief: bool,
+    follow: bool,
+    filter: PostFilter,
+    output: OutputFormat,
     client: RedditClient,
-) -> Result<(), crate::client::RedditClientError> {
+) -> Result<(), RedditClientError> {
+    if follow && matches!(output, OutputFormat::Json | OutputFormat::Csv) {
+        return Err(RedditClientError::ApiError(
+            "--follow supports text and ndjson output".to_string(),
+        ));
+    }
+    if follow && subreddit.is_none() {
+        return Err(RedditClientError::ApiError(
+            "--follow requires a subreddit; the frontpage can't be followed".to_string(),
+        ));
+    }
+
     let options = PostsOptions {
         count,
         subreddit,
         brief,
+        follow,
         user_agent: None,
+        filter,
     };
 
     // Create a new operation with the provided client
     let operation = PostsOperation::with_client(options, client);
     match operation.execute().await {
         Ok(result) => {
-            // Print the formatted output to the console
-            print!("{}", result.formatted_output);
+            print_result(&result, output)?;
+
+            if operation.options.follow {
+                let shown: Vec<RedditPostData> = result
+                    .raw_response
+                    .data
+                    .children
+                    .into_iter()
+                    .map(|post| post.data)
+                    .collect();
+                follow_posts(&operation, &shown, output).await;
+            }
             Ok(())
         }
         Err(err) => {
@@ -0,0 +1,244 @@
+use crate::client::{
+    ListingParams, RedditClient, RedditClientError, RelationshipDetails, RelationshipKind,
+};
+use crate::models::relationship::Relationship;
+use crate::operations::user::format_time;
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+
+/// A change to, or lookup of, a subreddit's user relationships
+#[derive(Debug, Clone)]
+pub enum RelationshipRequest {
+    /// Add a relationship, e.g. ban or approve a user
+    Add {
+        username: String,
+        kind: RelationshipKind,
+        details: RelationshipDetails,
+    },
+    /// Remove a relationship, e.g. unban a user
+    Remove {
+        username: String,
+        kind: RelationshipKind,
+    },
+    /// List the users with a relationship, e.g. the banned users
+    List { kind: RelationshipKind, count: i32 },
+}
+
+/// Configuration options for managing a subreddit's user relationships
+#[derive(Debug, Clone)]
+pub struct RelationshipOptions {
+    /// The subreddit to manage (with or without the `r/` prefix)
+    pub subreddit: String,
+    /// What to do
+    pub request: RelationshipRequest,
+}
+
+/// Result of a relationship operation
+#[derive(Debug, Serialize)]
+pub struct RelationshipResult {
+    /// The users found, for list requests
+    pub relationships: Vec<Relationship>,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+}
+
+impl CommandOutput for RelationshipResult {
+    fn text(&self) -> String {
+        self.formatted_output.clone()
+    }
+
+    /// One row per user
+    fn rows(&self) -> Vec<serde_json::Value> {
+        self.relationships
+            .iter()
+            .filter_map(|row| serde_json::to_value(row).ok())
+            .collect()
+    }
+}
+
+/// Operation for banning, muting and approving users, and listing those lists
+pub struct RelationshipOperation {
+    /// Configuration options for the operation
+    options: RelationshipOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl RelationshipOperation {
+    /// Create a new relationship operation with the provided options
+    pub fn new(options: RelationshipOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new relationship operation with a custom Reddit client
+    pub fn with_client(options: RelationshipOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the relationship operation
+    pub async fn execute(&mut self) -> Result<RelationshipResult, RedditClientError> {
+        info!(
+            "Executing relationship request {:?} in r/{}",
+            self.options.request, self.options.subreddit
+        );
+
+        self.client.ensure_access_token().await?;
+
+        let subreddit = &self.options.subreddit;
+        match &self.options.request {
+            RelationshipRequest::Add {
+                username,
+                kind,
+                details,
+            } => {
+                self.client
+                    .friend(subreddit, username, *kind, details)
+                    .await?;
+                Ok(RelationshipResult {
+                    relationships: Vec::new(),
+                    formatted_output: format!(
+                        "{}\n",
+                        describe_add(*kind, username, subreddit, details)
+                    ),
+                })
+            }
+            RelationshipRequest::Remove { username, kind } => {
+                self.client.unfriend(subreddit, username, *kind).await?;
+                Ok(RelationshipResult {
+                    relationships: Vec::new(),
+                    formatted_output: format!(
+                        "Removed u/{} from the {} list of r/{}\n",
+                        username, kind, subreddit
+                    ),
+                })
+            }
+            RelationshipRequest::List { kind, count } => self.list(*kind, *count).await,
+        }
+    }
+
+    // Internal helper method paging through a user list
+    async fn list(
+        &self,
+        kind: RelationshipKind,
+        count: i32,
+    ) -> Result<RelationshipResult, RedditClientError> {
+        let wanted = count.max(0) as usize;
+        let mut relationships: Vec<Relationship> = Vec::new();
+        let mut after: Option<String> = None;
+
+        while relationships.len() < wanted {
+            let params = ListingParams {
+                limit: Some((wanted - relationships.len()).min(100) as u32),
+                after: after.clone(),
+                count: Some(relationships.len() as u32),
+                ..ListingParams::default()
+            };
+
+            let page = self
+                .client
+                .fetch_relationships(&self.options.subreddit, kind, &params)
+                .await?;
+            if page.children.is_empty() {
+                break;
+            }
+
+            relationships.extend(page.children);
+            after = page.after;
+            if after.is_none() {
+                break;
+            }
+        }
+        relationships.truncate(wanted);
+
+        let mut output = String::new();
+        if relationships.is_empty() {
+            output.push_str(&format!(
+                "No {} users in r/{}.\n",
+                kind, self.options.subreddit
+            ));
+        } else {
+            output.push_str(&format!(
+                "Found {} {} users in r/{}\n",
+                relationships.len(),
+                kind,
+                self.options.subreddit
+            ));
+            for (i, relationship) in relationships.iter().enumerate() {
+                output.push_str(&format!(
+                    "{:2}. u/{} | since {}",
+                    i + 1,
+                    relationship.name,
+                    format_time(relationship.date)
+                ));
+                if kind == RelationshipKind::Banned {
+                    match relationship.days_left {
+                        Some(days) => output.push_str(&format!(" | {} days left", days)),
+                        None => output.push_str(" | permanent"),
+                    }
+                }
+                if let Some(note) = relationship.note.as_deref().filter(|n| !n.is_empty()) {
+                    output.push_str(&format!(" | note: {}", note));
+                }
+                output.push('\n');
+            }
+        }
+
+        Ok(RelationshipResult {
+            relationships,
+            formatted_output: output,
+        })
+    }
+}
+
+/// Describe a successfully added relationship for CLI output
+fn describe_add(
+    kind: RelationshipKind,
+    username: &str,
+    subreddit: &str,
+    details: &RelationshipDetails,
+) -> String {
+    match kind {
+        RelationshipKind::Banned => {
+            let duration = match details.duration_days {
+                Some(days) => format!("for {} days", days),
+                None => "permanently".to_string(),
+            };
+            format!("Banned u/{} from r/{} {}", username, subreddit, duration)
+        }
+        RelationshipKind::Muted => format!("Muted u/{} in r/{}", username, subreddit),
+        RelationshipKind::Contributor => {
+            format!("Approved u/{} as a submitter in r/{}", username, subreddit)
+        }
+        RelationshipKind::ModeratorInvite => {
+            format!("Invited u/{} to moderate r/{}", username, subreddit)
+        }
+        RelationshipKind::WikiBanned => {
+            format!("Banned u/{} from the r/{} wiki", username, subreddit)
+        }
+    }
+}
+
+/// CLI handler function for ban, mute and approved-user commands with client
+pub async fn handle_relationship_command_with_client(
+    subreddit: String,
+    request: RelationshipRequest,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let options = RelationshipOptions { subreddit, request };
+
+    let mut operation = RelationshipOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            print_result(&result, output)?;
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error managing subreddit users: {:?}", err);
+            Err(err)
+        }
+    }
+}
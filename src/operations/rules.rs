@@ -0,0 +1,506 @@
+use crate::bot::{Bot, BotConfig, Filter};
+use crate::client::{RedditClient, RedditClientError};
+use crate::models::account::Account;
+use crate::models::listing::Thing;
+use crate::output::{print_result, render_ndjson, CommandOutput, OutputFormat};
+use crate::rules::{RuleItem, RuleSet, RuleTarget};
+use log::{error, info};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// How many author accounts a rules run remembers before starting over
+const AUTHOR_CACHE_CAPACITY: usize = 5000;
+
+/// Configuration options for running rules against new posts and comments
+#[derive(Debug, Clone)]
+pub struct RulesRunOptions {
+    /// Path to the YAML or TOML rules file
+    pub rules_path: PathBuf,
+    /// Subreddits to watch; the rules file's `subreddits` list is used when empty
+    pub subreddits: Vec<String>,
+    /// Print what the rules would do instead of doing it
+    pub dry_run: bool,
+    /// Text, or NDJSON with one line per action
+    pub output: OutputFormat,
+}
+
+/// One action taken, or that would be taken in a dry run, printed as an NDJSON row
+#[derive(Debug, Serialize)]
+pub struct RuleActionRow<'a> {
+    pub fullname: &'a str,
+    pub rule: &'a str,
+    pub action: &'a str,
+    pub dry_run: bool,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Operation for applying a rules file to new posts and comments until stopped
+pub struct RulesRunOperation {
+    /// Configuration options for the operation
+    options: RulesRunOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl RulesRunOperation {
+    /// Create a new rules run operation with the provided options
+    pub fn new(options: RulesRunOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new rules run operation with a custom Reddit client
+    pub fn with_client(options: RulesRunOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Watch the subreddits and apply the rules until SIGTERM or Ctrl-C
+    pub async fn execute(mut self) -> Result<(), RedditClientError> {
+        let rules = RuleSet::load(&self.options.rules_path)?;
+        let subreddits = if self.options.subreddits.is_empty() {
+            rules.subreddits.clone()
+        } else {
+            self.options.subreddits.clone()
+        };
+        if subreddits.is_empty() {
+            return Err(RedditClientError::ApiError(
+                "No subreddits to watch; pass --subreddit or list them under 'subreddits' in the rules file"
+                    .to_string(),
+            ));
+        }
+
+        // Dry runs keep their own checkpoint so they don't hide items from a real run
+        let stem = self
+            .options
+            .rules_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("rules");
+        let name = if self.options.dry_run {
+            format!("rules-{}-dry-run", stem)
+        } else {
+            format!("rules-{}", stem)
+        };
+        let mut config = BotConfig::new(&name);
+        config.subreddits = subreddits;
+
+        info!(
+            "Applying {} rules from {} to r/{}{}",
+            rules.rules.len(),
+            self.options.rules_path.display(),
+            config.subreddits.join("+"),
+            if self.options.dry_run {
+                " (dry run)"
+            } else {
+                ""
+            }
+        );
+
+        // Skip the account's own posts and comments, so reply rules can't answer themselves
+        let mut filter = Filter::new();
+        let has_tokens = self.client.access_token.is_some()
+            || self
+                .client
+                .token_storage
+                .as_ref()
+                .is_some_and(|storage| storage.has_refresh_token());
+        if has_tokens {
+            self.client.ensure_access_token().await?;
+            let me = self.client.fetch_me().await?;
+            info!("Ignoring posts and comments by u/{}", me.name);
+            filter = filter.exclude_author(&me.name);
+        }
+
+        let runner = Arc::new(RuleRunner {
+            dry_run: self.options.dry_run,
+            output: self.options.output,
+            authors: Mutex::new(HashMap::new()),
+            rules,
+        });
+        let mut bot = Bot::new(self.client, config);
+
+        if runner.rules.targets(RuleTarget::Submission) {
+            let runner = runner.clone();
+            bot.on_submission(filter.clone(), move |client, post| {
+                let runner = runner.clone();
+                async move {
+                    runner.apply(&client, RuleItem::Submission(&post)).await;
+                    Ok(())
+                }
+            });
+        }
+        if runner.rules.targets(RuleTarget::Comment) {
+            let runner = runner.clone();
+            bot.on_comment(filter, move |client, comment| {
+                let runner = runner.clone();
+                async move {
+                    runner.apply(&client, RuleItem::Comment(&comment)).await;
+                    Ok(())
+                }
+            });
+        }
+
+        bot.run().await
+    }
+}
+
+/// Applies the rules to items from the bot, caching author accounts
+struct RuleRunner {
+    rules: RuleSet,
+    dry_run: bool,
+    output: OutputFormat,
+    authors: Mutex<HashMap<String, Option<Arc<Account>>>>,
+}
+
+impl RuleRunner {
+    /// Run the actions of every rule matching an item, printing each one
+    async fn apply(&self, client: &RedditClient, item: RuleItem<'_>) {
+        let author = if self.rules.needs_author(&item) {
+            self.author(client, item.author()).await
+        } else {
+            None
+        };
+
+        let fullname = item.fullname();
+        for rule in self.rules.matching(&item, author.as_deref()) {
+            for action in &rule.actions {
+                let description = action.describe(&item);
+                let mut row = RuleActionRow {
+                    fullname: &fullname,
+                    rule: &rule.name,
+                    action: &description,
+                    dry_run: self.dry_run,
+                    ok: true,
+                    error: None,
+                };
+                if !self.dry_run {
+                    if let Err(err) = action.apply(client, &item).await {
+                        error!(
+                            "Rule '{}' failed to {} on {}: {}",
+                            rule.name, description, fullname, err
+                        );
+                        row.ok = false;
+                        row.error = Some(err.to_string());
+                    }
+                }
+                self.print(&row);
+            }
+        }
+    }
+
+    /// Print an action as a line of text, or as an NDJSON row
+    fn print(&self, row: &RuleActionRow) {
+        if !self.output.is_text() {
+            match serde_json::to_value(row)
+                .map_err(RedditClientError::from)
+                .and_then(|row| render_ndjson(&[row]))
+            {
+                Ok(line) => print!("{}", line),
+                Err(err) => error!("Failed to print rule action: {}", err),
+            }
+        } else if row.dry_run {
+            println!("[dry-run] {} {}: {}", row.fullname, row.rule, row.action);
+        } else if row.ok {
+            println!("{} {}: {}", row.fullname, row.rule, row.action);
+        }
+    }
+
+    /// Look up an author's account, remembering deleted and suspended accounts as missing.
+    /// Failed lookups aren't remembered, so the next item by the author tries again.
+    async fn author(&self, client: &RedditClient, name: &str) -> Option<Arc<Account>> {
+        if name.is_empty() || name == "[deleted]" {
+            return None;
+        }
+        if let Some(account) = self.authors.lock().unwrap().get(name) {
+            return account.clone();
+        }
+
+        let account = match client.fetch_user_about(name).await {
+            Ok(account) if !account.is_suspended => Some(Arc::new(account)),
+            Ok(_) => None,
+            Err(err) => {
+                error!("Failed to look up u/{}: {}", name, err);
+                return None;
+            }
+        };
+
+        let mut authors = self.authors.lock().unwrap();
+        if authors.len() >= AUTHOR_CACHE_CAPACITY {
+            authors.clear();
+        }
+        authors.insert(name.to_string(), account.clone());
+        account
+    }
+}
+
+/// Configuration options for checking rules against saved JSON fixtures
+#[derive(Debug, Clone)]
+pub struct RulesTestOptions {
+    /// Path to the YAML or TOML rules file
+    pub rules_path: PathBuf,
+    /// Fixture files, each holding a post or comment, an array of them, or a listing
+    pub fixtures: Vec<PathBuf>,
+}
+
+/// Result of checking rules against fixtures
+#[derive(Debug, Serialize)]
+pub struct RulesTestResult {
+    /// The number of posts and comments checked
+    pub item_count: usize,
+    /// The number of items whose matched rules differed from their `expect` list
+    pub failures: usize,
+    /// The outcome for each post and comment checked
+    pub cases: Vec<RulesTestCase>,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+}
+
+/// The rules that matched one fixture item
+#[derive(Debug, Serialize)]
+pub struct RulesTestCase {
+    /// The fixture file the item came from
+    pub fixture: PathBuf,
+    pub fullname: String,
+    /// Names of the rules that matched, in file order
+    pub matched: Vec<String>,
+    /// Names of the rules the fixture expected to match, if it says
+    pub expected: Option<Vec<String>>,
+    /// Whether `matched` equals `expected`, if the fixture has expectations
+    pub passed: Option<bool>,
+}
+
+impl CommandOutput for RulesTestResult {
+    fn text(&self) -> String {
+        self.formatted_output.clone()
+    }
+
+    /// One row per item checked
+    fn rows(&self) -> Vec<serde_json::Value> {
+        self.cases
+            .iter()
+            .filter_map(|case| serde_json::to_value(case).ok())
+            .collect()
+    }
+}
+
+/// A post or comment from a fixture file
+struct Fixture {
+    thing: Thing,
+    /// The author's account, for rules with age or karma conditions
+    author: Option<Account>,
+    /// Names of the rules expected to match, if the fixture says
+    expect: Option<Vec<String>>,
+}
+
+/// Operation for checking which rules match saved posts and comments, without calling Reddit
+pub struct RulesTestOperation {
+    /// Configuration options for the operation
+    options: RulesTestOptions,
+}
+
+impl RulesTestOperation {
+    /// Create a new rules test operation with the provided options
+    pub fn new(options: RulesTestOptions) -> Self {
+        Self { options }
+    }
+
+    /// Execute the rules test operation
+    pub fn execute(&self) -> Result<RulesTestResult, RedditClientError> {
+        let rules = RuleSet::load(&self.options.rules_path)?;
+
+        let mut output = String::new();
+        let mut item_count = 0;
+        let mut failures = 0;
+        let mut cases = Vec::new();
+
+        for path in &self.options.fixtures {
+            for fixture in load_fixtures(path)? {
+                let item = match &fixture.thing {
+                    Thing::Link(post) => RuleItem::Submission(post),
+                    Thing::Comment(comment) => RuleItem::Comment(comment),
+                    other => {
+                        output.push_str(&format!(
+                            "{}: skipping {} item\n",
+                            path.display(),
+                            other.kind()
+                        ));
+                        continue;
+                    }
+                };
+                item_count += 1;
+
+                output.push_str(&format!(
+                    "{} r/{} u/{}\n",
+                    item.fullname(),
+                    item.subreddit(),
+                    item.author()
+                ));
+
+                let matched = rules.matching(&item, fixture.author.as_ref());
+                if matched.is_empty() {
+                    output.push_str("  (no rules matched)\n");
+                }
+                for rule in &matched {
+                    for action in &rule.actions {
+                        output.push_str(&format!("  {}: {}\n", rule.name, action.describe(&item)));
+                    }
+                }
+
+                let names: Vec<String> = matched.iter().map(|rule| rule.name.clone()).collect();
+                let passed = fixture.expect.as_ref().map(|expected| names == *expected);
+                if let Some(expected) = &fixture.expect {
+                    if names == *expected {
+                        output.push_str("  PASS\n");
+                    } else {
+                        failures += 1;
+                        output.push_str(&format!(
+                            "  FAIL: expected [{}], matched [{}]\n",
+                            expected.join(", "),
+                            names.join(", ")
+                        ));
+                    }
+                }
+
+                cases.push(RulesTestCase {
+                    fixture: path.clone(),
+                    fullname: item.fullname(),
+                    matched: names,
+                    expected: fixture.expect.clone(),
+                    passed,
+                });
+            }
+        }
+
+        output.push_str(&format!(
+            "\nChecked {} items against {} rules",
+            item_count,
+            rules.rules.len()
+        ));
+        if failures > 0 {
+            output.push_str(&format!(", {} failed", failures));
+        }
+        output.push('\n');
+
+        Ok(RulesTestResult {
+            item_count,
+            failures,
+            cases,
+            formatted_output: output,
+        })
+    }
+}
+
+/// Read a fixture file holding one thing, an array of things, or a listing.
+///
+/// Each thing is a `{"kind": ..., "data": ...}` object as returned by Reddit,
+/// optionally with an `author` account object and an `expect` list of rule names.
+fn load_fixtures(path: &Path) -> Result<Vec<Fixture>, RedditClientError> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        RedditClientError::ApiError(format!("Failed to read {}: {}", path.display(), e))
+    })?;
+    let json: serde_json::Value = serde_json::from_str(&contents)?;
+
+    let entries = match json {
+        serde_json::Value::Array(entries) => entries,
+        json if json["kind"] == "Listing" => json["data"]["children"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default(),
+        json => vec![json],
+    };
+
+    let invalid = |e: serde_json::Error| {
+        RedditClientError::ApiError(format!("Invalid fixture in {}: {}", path.display(), e))
+    };
+
+    entries
+        .into_iter()
+        .map(|mut entry| {
+            let author = match entry.get_mut("author").map(serde_json::Value::take) {
+                Some(author) => Some(serde_json::from_value(author).map_err(invalid)?),
+                None => None,
+            };
+            let expect = match entry.get_mut("expect").map(serde_json::Value::take) {
+                Some(expect) => Some(serde_json::from_value(expect).map_err(invalid)?),
+                None => None,
+            };
+            let thing = serde_json::from_value(entry).map_err(invalid)?;
+
+            Ok(Fixture {
+                thing,
+                author,
+                expect,
+            })
+        })
+        .collect()
+}
+
+/// CLI handler function for the rules run command with client
+pub async fn handle_rules_run_command_with_client(
+    rules_path: PathBuf,
+    subreddits: Vec<String>,
+    dry_run: bool,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    if matches!(output, OutputFormat::Json | OutputFormat::Csv) {
+        return Err(RedditClientError::ApiError(
+            "rules run supports text and ndjson output".to_string(),
+        ));
+    }
+
+    let options = RulesRunOptions {
+        rules_path,
+        subreddits,
+        dry_run,
+        output,
+    };
+
+    match RulesRunOperation::with_client(options, client)
+        .execute()
+        .await
+    {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            error!("Error executing rules run operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
+
+/// CLI handler function for the rules test command
+///
+/// Returns an error when any fixture's `expect` list doesn't match, so the
+/// command can be used in CI.
+pub fn handle_rules_test_command(
+    rules_path: PathBuf,
+    fixtures: Vec<PathBuf>,
+    output: OutputFormat,
+) -> Result<(), RedditClientError> {
+    let options = RulesTestOptions {
+        rules_path,
+        fixtures,
+    };
+
+    match RulesTestOperation::new(options).execute() {
+        Ok(result) => {
+            print_result(&result, output)?;
+            if result.failures > 0 {
+                return Err(RedditClientError::ApiError(format!(
+                    "{} of {} fixtures did not match their expected rules",
+                    result.failures, result.item_count
+                )));
+            }
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error executing rules test operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
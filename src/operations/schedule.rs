@@ -0,0 +1,421 @@
+use crate::bot::shutdown_signal;
+use crate::client::{RedditClient, RedditClientError};
+use crate::operations::edit::read_text_file;
+use crate::output::{print_result, render_ndjson, CommandOutput, OutputFormat};
+use crate::schedule::{parse_publish_time, ScheduleStore, ScheduledPost, HEARTBEAT_SECS};
+use chrono::Utc;
+use log::{error, info, warn};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Longest the daemon sleeps before checking the queue again, so jobs added
+/// while it is running are picked up
+const MAX_POLL_SECS: i64 = 30;
+
+/// The schedule command to run against the queue
+#[derive(Debug, Clone)]
+pub enum ScheduleRequest {
+    /// Queue a new text post
+    Add {
+        subreddit: String,
+        title: String,
+        /// The post text, or `None` to read it from `text_file`
+        text: Option<String>,
+        /// File holding the post text ("-" for stdin)
+        text_file: Option<PathBuf>,
+        /// The publish time, e.g. "2026-11-01T09:00"
+        at: String,
+        /// IANA timezone for `at`; local time when omitted
+        timezone: Option<String>,
+    },
+    /// List the queue
+    List {
+        /// Include published, failed and cancelled jobs
+        all: bool,
+    },
+    /// Show one job with its status history
+    Show { id: u64 },
+    /// Cancel a job that hasn't been published yet
+    Cancel { id: u64 },
+}
+
+/// Configuration options for managing the schedule queue
+#[derive(Debug, Clone)]
+pub struct ScheduleOptions {
+    /// The queue file
+    pub store_path: PathBuf,
+    /// What to do
+    pub request: ScheduleRequest,
+}
+
+/// Result of a schedule operation
+#[derive(Debug, Serialize)]
+pub struct ScheduleResult {
+    /// The jobs added, cancelled or listed
+    pub jobs: Vec<ScheduledPost>,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+}
+
+impl CommandOutput for ScheduleResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.formatted_output)
+    }
+
+    /// One row per job
+    fn rows(&self) -> Vec<serde_json::Value> {
+        self.jobs
+            .iter()
+            .filter_map(|job| serde_json::to_value(job).ok())
+            .collect()
+    }
+}
+
+/// Operation for adding, listing, showing and cancelling scheduled posts
+pub struct ScheduleOperation {
+    /// Configuration options for the operation
+    options: ScheduleOptions,
+}
+
+impl ScheduleOperation {
+    /// Create a new schedule operation with the provided options
+    pub fn new(options: ScheduleOptions) -> Self {
+        Self { options }
+    }
+
+    /// Execute the schedule operation
+    pub fn execute(&self) -> Result<ScheduleResult, RedditClientError> {
+        let path = &self.options.store_path;
+
+        match &self.options.request {
+            ScheduleRequest::Add {
+                subreddit,
+                title,
+                text,
+                text_file,
+                at,
+                timezone,
+            } => {
+                let text = match (text, text_file) {
+                    (_, Some(file)) => read_text_file(file)?,
+                    (Some(text), None) => text.clone(),
+                    (None, None) => {
+                        return Err(RedditClientError::ApiError(
+                            "A scheduled post needs --text or --text-file".to_string(),
+                        ))
+                    }
+                };
+
+                let publish_at = parse_publish_time(at, timezone.as_deref())?;
+                if publish_at < Utc::now() {
+                    return Err(RedditClientError::ApiError(format!(
+                        "{} is in the past",
+                        at
+                    )));
+                }
+
+                let timezone = timezone.as_deref().unwrap_or("local");
+                let job = ScheduleStore::update(path, |store| {
+                    Ok(store
+                        .add(subreddit, title, &text, publish_at, timezone)
+                        .clone())
+                })?;
+                info!("Scheduled post #{} for {}", job.id, job.publish_at);
+
+                Ok(ScheduleResult {
+                    formatted_output: format!(
+                        "Scheduled post #{} in r/{} for {}",
+                        job.id,
+                        job.subreddit,
+                        job.local_publish_time()
+                    ),
+                    jobs: vec![job],
+                })
+            }
+            ScheduleRequest::List { all } => {
+                let mut jobs: Vec<ScheduledPost> = ScheduleStore::load(path)?
+                    .jobs
+                    .into_iter()
+                    .filter(|job| *all || job.status.is_waiting())
+                    .collect();
+                jobs.sort_by_key(|job| job.publish_at);
+
+                let formatted_output = if jobs.is_empty() {
+                    "No scheduled posts.".to_string()
+                } else {
+                    jobs.iter()
+                        .map(|job| job.format_summary())
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                };
+
+                Ok(ScheduleResult {
+                    jobs,
+                    formatted_output,
+                })
+            }
+            ScheduleRequest::Show { id } => {
+                let mut store = ScheduleStore::load(path)?;
+                let job = store.get_mut(*id)?.clone();
+
+                Ok(ScheduleResult {
+                    formatted_output: job.format_details(),
+                    jobs: vec![job],
+                })
+            }
+            ScheduleRequest::Cancel { id } => {
+                let job = ScheduleStore::update(path, |store| Ok(store.cancel(*id)?.clone()))?;
+
+                Ok(ScheduleResult {
+                    formatted_output: format!("Cancelled scheduled post #{}", job.id),
+                    jobs: vec![job],
+                })
+            }
+        }
+    }
+}
+
+/// Configuration options for the schedule daemon
+#[derive(Debug, Clone)]
+pub struct ScheduleRunOptions {
+    /// The queue file
+    pub store_path: PathBuf,
+    /// Publish whatever is due and exit instead of running until stopped
+    pub once: bool,
+    /// Attempts before a job is marked failed
+    pub max_attempts: u32,
+    /// Text, or NDJSON with one line per publish attempt
+    pub output: OutputFormat,
+}
+
+/// The outcome of one publish attempt, printed as an NDJSON row
+#[derive(Debug, Serialize)]
+pub struct PublishRow<'a> {
+    pub id: u64,
+    pub subreddit: &'a str,
+    pub title: &'a str,
+    pub attempt: u32,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Operation that publishes scheduled posts when they are due
+pub struct ScheduleRunOperation {
+    /// Configuration options for the operation
+    options: ScheduleRunOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+    /// ID this daemon claims jobs under
+    daemon: String,
+}
+
+impl ScheduleRunOperation {
+    /// Create a new schedule daemon operation with the provided options
+    pub fn new(options: ScheduleRunOptions) -> Self {
+        let client = RedditClient::new();
+        Self::with_client(options, client)
+    }
+
+    /// Create a new schedule daemon operation with a custom Reddit client
+    pub fn with_client(options: ScheduleRunOptions, client: RedditClient) -> Self {
+        let daemon = format!("{}-{:08x}", std::process::id(), rand::random::<u32>());
+        Self {
+            options,
+            client,
+            daemon,
+        }
+    }
+
+    /// Publish due posts until SIGTERM or Ctrl-C, or once if `once` is set.
+    ///
+    /// Returns the number of posts published.
+    pub async fn execute(&mut self) -> Result<usize, RedditClientError> {
+        let path = self.options.store_path.clone();
+        let shutdown = shutdown_signal();
+        tokio::pin!(shutdown);
+        let mut published = 0;
+
+        loop {
+            published += self.publish_due().await?;
+            if self.options.once {
+                break;
+            }
+
+            let now = Utc::now();
+            let wait = ScheduleStore::load(&path)?
+                .next_due()
+                .map(|due| (due - now).num_seconds().clamp(1, MAX_POLL_SECS))
+                .unwrap_or(MAX_POLL_SECS);
+
+            tokio::select! {
+                _ = &mut shutdown => {
+                    info!("Shutdown requested, stopping schedule daemon");
+                    break;
+                }
+                _ = tokio::time::sleep(std::time::Duration::from_secs(wait as u64)) => {}
+            }
+        }
+
+        Ok(published)
+    }
+
+    /// Publish every job that is due now, recording each outcome in the queue
+    async fn publish_due(&mut self) -> Result<usize, RedditClientError> {
+        let path = self.options.store_path.clone();
+        let daemon = self.daemon.clone();
+        let (recovered, jobs) = ScheduleStore::update_async(&path, move |store| {
+            let now = Utc::now();
+            Ok((
+                store.recover_interrupted(now),
+                store.claim_due(now, &daemon),
+            ))
+        })
+        .await?;
+        if recovered > 0 {
+            warn!(
+                "{} scheduled post(s) were interrupted while publishing and will be retried",
+                recovered
+            );
+        }
+        let mut published = 0;
+
+        for job in jobs {
+            info!(
+                "Publishing scheduled post #{} to r/{} (attempt {})",
+                job.id, job.subreddit, job.attempts
+            );
+
+            let client = &mut self.client;
+            let publish = async {
+                client.ensure_access_token().await?;
+                client
+                    .create_post(&job.subreddit, &job.title, &job.text)
+                    .await
+            };
+            tokio::pin!(publish);
+
+            // Keep our claims fresh while Reddit answers, so other daemons don't take them over
+            let mut heartbeat =
+                tokio::time::interval(std::time::Duration::from_secs(HEARTBEAT_SECS as u64));
+            let outcome = loop {
+                tokio::select! {
+                    outcome = &mut publish => break outcome,
+                    _ = heartbeat.tick() => {
+                        let daemon = self.daemon.clone();
+                        ScheduleStore::update_async(&path, move |store| {
+                            store.heartbeat(Utc::now(), &daemon);
+                            Ok(())
+                        })
+                        .await?;
+                    }
+                }
+            };
+
+            let max_attempts = self.options.max_attempts;
+            let result = outcome
+                .as_ref()
+                .map(String::clone)
+                .map_err(ToString::to_string);
+            ScheduleStore::update_async(&path, move |store| {
+                let stored = store.get_mut(job.id)?;
+                match result {
+                    Ok(url) => stored.mark_published(url),
+                    Err(err) => stored.mark_failed(err, max_attempts),
+                }
+                Ok(())
+            })
+            .await?;
+
+            match &outcome {
+                Ok(url) => {
+                    if self.options.output.is_text() {
+                        println!("Published scheduled post #{}: {}", job.id, url);
+                    }
+                    published += 1;
+                }
+                Err(err) => error!("Scheduled post #{} failed: {}", job.id, err),
+            }
+            if !self.options.output.is_text() {
+                let row = PublishRow {
+                    id: job.id,
+                    subreddit: &job.subreddit,
+                    title: &job.title,
+                    attempt: job.attempts,
+                    ok: outcome.is_ok(),
+                    post_url: outcome.as_ref().ok().map(String::as_str),
+                    error: outcome.as_ref().err().map(ToString::to_string),
+                };
+                match serde_json::to_value(&row)
+                    .map_err(RedditClientError::from)
+                    .and_then(|row| render_ndjson(&[row]))
+                {
+                    Ok(line) => print!("{}", line),
+                    Err(err) => error!("Failed to print publish result: {}", err),
+                }
+            }
+        }
+
+        Ok(published)
+    }
+}
+
+/// CLI handler function for the schedule add, list, show and cancel commands
+pub fn handle_schedule_command(
+    store_path: Option<PathBuf>,
+    request: ScheduleRequest,
+    output: OutputFormat,
+) -> Result<(), RedditClientError> {
+    let options = ScheduleOptions {
+        store_path: store_path.unwrap_or_else(ScheduleStore::default_path),
+        request,
+    };
+
+    match ScheduleOperation::new(options).execute() {
+        Ok(result) => {
+            print_result(&result, output)?;
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error executing schedule operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
+
+/// CLI handler function for the schedule run daemon with client
+pub async fn handle_schedule_run_command_with_client(
+    store_path: Option<PathBuf>,
+    once: bool,
+    max_attempts: u32,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    if matches!(output, OutputFormat::Json | OutputFormat::Csv) {
+        return Err(RedditClientError::ApiError(
+            "schedule run supports text and ndjson output".to_string(),
+        ));
+    }
+
+    let options = ScheduleRunOptions {
+        store_path: store_path.unwrap_or_else(ScheduleStore::default_path),
+        once,
+        max_attempts,
+        output,
+    };
+
+    let mut operation = ScheduleRunOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(published) => {
+            info!("Published {} scheduled post(s)", published);
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error executing schedule run operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
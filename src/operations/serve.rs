@@ -0,0 +1,124 @@
+use crate::bot::shutdown_signal;
+use crate::client::{RedditClient, RedditClientError};
+use crate::serve::{serve, Gateway};
+use log::{error, info, warn};
+use rand::{distributions::Alphanumeric, Rng};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Configuration options for the REST gateway
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    /// Address to listen on, e.g. 127.0.0.1:7777
+    pub bind: String,
+    /// Key callers must send; a random one is generated and printed when unset
+    pub api_key: Option<String>,
+    /// JSON lines file every write is recorded in
+    pub audit_log: PathBuf,
+    /// API requests per minute across all callers, unless the client is already rate limited
+    pub requests_per_minute: u32,
+}
+
+impl ServeOptions {
+    /// The default audit log, ~/.redrust/audit.jsonl
+    pub fn default_audit_log() -> PathBuf {
+        let mut path = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push(".redrust");
+        path.push("audit.jsonl");
+        path
+    }
+}
+
+/// Operation that runs the REST gateway until stopped
+pub struct ServeOperation {
+    /// Configuration options for the operation
+    options: ServeOptions,
+    /// Reddit client shared by all requests
+    client: RedditClient,
+}
+
+impl ServeOperation {
+    /// Create a new gateway operation with the provided options
+    pub fn new(options: ServeOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new gateway operation with a custom Reddit client
+    pub fn with_client(options: ServeOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Serve requests until SIGTERM or Ctrl-C
+    pub async fn execute(&mut self) -> Result<(), RedditClientError> {
+        let bind = &self.options.bind;
+        if bind
+            .parse::<SocketAddr>()
+            .is_ok_and(|addr| !addr.ip().is_loopback())
+        {
+            warn!(
+                "Listening on {}, which other machines can reach; anyone with the API key can act as your account",
+                bind
+            );
+        }
+
+        let client = if self.client.rate_limiter.is_none() {
+            self.client
+                .clone()
+                .with_rate_limit(self.options.requests_per_minute)
+        } else {
+            self.client.clone()
+        };
+
+        let api_key = match &self.options.api_key {
+            Some(key) if !key.is_empty() => key.clone(),
+            _ => {
+                let key: String = rand::thread_rng()
+                    .sample_iter(&Alphanumeric)
+                    .take(32)
+                    .map(char::from)
+                    .collect();
+                println!("API key for this session: {}", key);
+                key
+            }
+        };
+
+        let gateway = Arc::new(Gateway::new(
+            client,
+            api_key,
+            self.options.audit_log.clone(),
+        )?);
+        info!(
+            "Gateway listening on http://{} (writes are logged to {})",
+            bind,
+            self.options.audit_log.display()
+        );
+        serve(gateway, bind, shutdown_signal()).await
+    }
+}
+
+/// CLI handler function for the gateway with client
+pub async fn handle_serve_command_with_client(
+    bind: String,
+    api_key: Option<String>,
+    audit_log: Option<PathBuf>,
+    requests_per_minute: u32,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let options = ServeOptions {
+        bind,
+        api_key,
+        audit_log: audit_log.unwrap_or_else(ServeOptions::default_audit_log),
+        requests_per_minute,
+    };
+
+    let mut operation = ServeOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            error!("Error executing serve operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::client::RedditClient;
+use crate::models::subreddit::{Moderator, PostRequirements, SubredditAbout, SubredditRules};
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use log::{error, info, warn};
+use serde::Serialize;
+
+/// Configuration options for fetching subreddit information
+#[derive(Debug, Clone)]
+pub struct SubredditInfoOptions {
+    /// The name of the subreddit to describe
+    pub subreddit: String,
+    /// Include the full sidebar markdown in the output
+    pub sidebar: bool,
+}
+
+/// Result of a subreddit information operation
+#[derive(Debug, Serialize)]
+pub struct SubredditInfoResult {
+    /// The subreddit's metadata
+    pub about: SubredditAbout,
+    /// The subreddit's rules
+    pub rules: SubredditRules,
+    /// Submission requirements (only available with an access token)
+    pub requirements: Option<PostRequirements>,
+    /// The subreddit's moderators (may be unavailable without authentication)
+    pub moderators: Option<Vec<Moderator>>,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+}
+
+impl CommandOutput for SubredditInfoResult {
+    fn text(&self) -> String {
+        self.formatted_output.clone()
+    }
+}
+
+/// Operation for fetching a subreddit's metadata, rules and posting requirements
+pub struct SubredditInfoOperation {
+    /// Configuration options for the operation
+    options: SubredditInfoOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl SubredditInfoOperation {
+    /// Create a new subreddit info operation with the provided options
+    pub fn new(options: SubredditInfoOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new subreddit info operation with a custom Reddit client
+    pub fn with_client(options: SubredditInfoOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the subreddit info operation
+    pub async fn execute(&self) -> Result<SubredditInfoResult, crate::client::RedditClientError> {
+        info!("Fetching information for r/{}", self.options.subreddit);
+
+        let about = self
+            .client
+            .fetch_subreddit_about(&self.options.subreddit)
+            .await?;
+        let rules = self
+            .client
+            .fetch_subreddit_rules(&self.options.subreddit)
+            .await?;
+
+        // Requirements and moderators need authentication, so treat them as optional
+        let requirements = if self.client.access_token.is_some() {
+            match self
+                .client
+                .fetch_post_requirements(&self.options.subreddit)
+                .await
+            {
+                Ok(requirements) => Some(requirements),
+                Err(err) => {
+                    warn!("Could not fetch post requirements: {}", err);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let moderators = match self
+            .client
+            .fetch_subreddit_moderators(&self.options.subreddit)
+            .await
+        {
+            Ok(moderators) => Some(moderators),
+            Err(err) => {
+                warn!("Could not fetch moderators: {}", err);
+                None
+            }
+        };
+
+        // Generate formatted output for display
+        let mut output = about.format_summary();
+
+        if self.options.sidebar && !about.description.is_empty() {
+            output.push_str("\nSidebar:\n---------\n");
+            output.push_str(about.description.trim());
+            output.push_str("\n---------\n");
+        }
+
+        output.push_str("\nRules:\n");
+        if rules.rules.is_empty() {
+            output.push_str("  (none)\n");
+        }
+        for (i, rule) in rules.rules.iter().enumerate() {
+            output.push_str(&format!(
+                "{:2}. [{}] {}\n",
+                i + 1,
+                rule.kind,
+                rule.short_name
+            ));
+            if !rule.description.is_empty() {
+                for line in rule.description.trim().lines() {
+                    output.push_str(&format!("      {}\n", line));
+                }
+            }
+        }
+
+        output.push_str("\nPost Requirements:\n");
+        match &requirements {
+            Some(requirements) => Self::format_requirements(requirements, &mut output),
+            None => output.push_str("  (unavailable - requires authentication)\n"),
+        }
+
+        output.push_str("\nModerators:\n");
+        match &moderators {
+            Some(moderators) => {
+                for moderator in moderators {
+                    output.push_str(&format!(
+                        "  u/{} [{}]\n",
+                        moderator.name,
+                        moderator.mod_permissions.join(", ")
+                    ));
+                }
+            }
+            None => output.push_str("  (unavailable)\n"),
+        }
+
+        Ok(SubredditInfoResult {
+            about,
+            rules,
+            requirements,
+            moderators,
+            formatted_output: output,
+        })
+    }
+
+    // Internal helper method for listing the constraints a subreddit sets
+    fn format_requirements(requirements: &PostRequirements, output: &mut String) {
+        let mut lines = Vec::new();
+
+        match (
+            requirements.title_text_min_length,
+            requirements.title_text_max_length,
+        ) {
+            (Some(min), Some(max)) => lines.push(format!("Title length: {}-{}", min, max)),
+            (Some(min), None) => lines.push(format!("Title length: at least {}", min)),
+            (None, Some(max)) => lines.push(format!("Title length: at most {}", max)),
+            (None, None) => {}
+        }
+        if !requirements.title_required_strings.is_empty() {
+            lines.push(format!(
+                "Title must contain one of: {}",
+                requirements.title_required_strings.join(", ")
+            ));
+        }
+        if !requirements.title_blacklisted_strings.is_empty() {
+            lines.push(format!(
+                "Title must not contain: {}",
+                requirements.title_blacklisted_strings.join(", ")
+            ));
+        }
+        if let Some(policy) = &requirements.body_restriction_policy {
+            lines.push(format!("Body: {}", policy));
+        }
+        match (
+            requirements.body_text_min_length,
+            requirements.body_text_max_length,
+        ) {
+            (Some(min), Some(max)) => lines.push(format!("Body length: {}-{}", min, max)),
+            (Some(min), None) => lines.push(format!("Body length: at least {}", min)),
+            (None, Some(max)) => lines.push(format!("Body length: at most {}", max)),
+            (None, None) => {}
+        }
+        if requirements.is_flair_required {
+            lines.push("Flair is required".to_string());
+        }
+        if !requirements.domain_whitelist.is_empty() {
+            lines.push(format!(
+                "Allowed domains: {}",
+                requirements.domain_whitelist.join(", ")
+            ));
+        }
+        if !requirements.domain_blacklist.is_empty() {
+            lines.push(format!(
+                "Blocked domains: {}",
+                requirements.domain_blacklist.join(", ")
+            ));
+        }
+
+        if lines.is_empty() {
+            output.push_str("  (none)\n");
+        }
+        for line in lines {
+            output.push_str(&format!("  {}\n", line));
+        }
+    }
+}
+
+/// CLI handler function for subreddit info command that accepts a preconfigured client
+pub async fn handle_subreddit_info_command_with_client(
+    subreddit: String,
+    sidebar: bool,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), crate::client::RedditClientError> {
+    let options = SubredditInfoOptions { subreddit, sidebar };
+
+    let operation = SubredditInfoOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            print_result(&result, output)?;
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error fetching subreddit info: {:?}", err);
+            Err(err)
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::client::RedditClient;
+use crate::output::{print_result, CommandOutput, OutputFormat};
 use log::{error, info};
+use serde::Serialize;
 
 /// Configuration options for creating a post with manual tokens
 #[derive(Debug, Clone)]
@@ -12,10 +14,12 @@ pub struct TokenCreateOptions {
     pub text: String,
     /// Time in seconds until the access token expires (when using with_client)
     pub expires_in: u64,
+    /// Check the post against the subreddit's requirements before submitting
+    pub validate: bool,
 }
 
 /// Result of a token-authenticated post creation operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct TokenCreateResult {
     /// Whether the post was successfully created
     pub success: bool,
@@ -25,6 +29,12 @@ pub struct TokenCreateResult {
     pub message: String,
 }
 
+impl CommandOutput for TokenCreateResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.message)
+    }
+}
+
 /// Operation for creating a post on Reddit using manual tokens
 pub struct TokenCreateOperation {
     /// Configuration options for the operation
@@ -59,6 +69,25 @@ impl TokenCreateOperation {
             display_sub, self.options.title
         );
 
+        // Check the post against the subreddit's requirements before submitting
+        if self.options.validate {
+            if let Some(message) = self
+                .client
+                .check_self_post(
+                    &self.options.subreddit,
+                    &self.options.title,
+                    &self.options.text,
+                )
+                .await?
+            {
+                return Ok(TokenCreateResult {
+                    success: false,
+                    post_url: None,
+                    message,
+                });
+            }
+        }
+
         // Now create the post
         info!("Using provided token to create post...");
         match self
@@ -100,6 +129,8 @@ pub async fn handle_token_create_command_with_client(
     title: String,
     text: String,
     expires_in: u64,
+    validate: bool,
+    output: OutputFormat,
     client: RedditClient,
 ) -> Result<(), crate::client::RedditClientError> {
     let options = TokenCreateOptions {
@@ -107,12 +138,15 @@ pub async fn handle_token_create_command_with_client(
         title,
         text,
         expires_in,
+        validate,
     };
 
     let mut operation = TokenCreateOperation::with_client(options, client);
     match operation.execute().await {
         Ok(result) => {
-            if result.success {
+            if !output.is_text() {
+                print_result(&result, output)?;
+            } else if result.success {
                 info!("{}", result.message);
             } else {
                 error!("{}", result.message);
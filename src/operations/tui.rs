@@ -0,0 +1,56 @@
+use crate::client::{RedditClient, RedditClientError};
+use crate::tui;
+use log::error;
+
+/// Configuration options for the terminal UI
+#[derive(Debug, Clone)]
+pub struct TuiOptions {
+    /// Subreddit to open (None for the public frontpage)
+    pub subreddit: Option<String>,
+    /// The number of posts to load
+    pub count: i32,
+}
+
+/// Operation for browsing Reddit in an interactive terminal UI
+pub struct TuiOperation {
+    /// Configuration options for the operation
+    options: TuiOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl TuiOperation {
+    /// Create a new terminal UI operation with the provided options
+    pub fn new(options: TuiOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new terminal UI operation with a custom Reddit client
+    pub fn with_client(options: TuiOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Run the terminal UI until the user quits
+    pub async fn execute(self) -> Result<(), RedditClientError> {
+        tui::run(self.client, self.options.subreddit, self.options.count).await
+    }
+}
+
+/// CLI handler function for the tui command with client
+pub async fn handle_tui_command_with_client(
+    subreddit: Option<String>,
+    count: i32,
+    client: RedditClient,
+) -> Result<(), RedditClientError> {
+    let options = TuiOptions { subreddit, count };
+
+    let operation = TuiOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            error!("Error executing tui operation: {:?}", err);
+            Err(err)
+        }
+    }
+}
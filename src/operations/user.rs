@@ -0,0 +1,233 @@
+use crate::client::{ListingParams, RedditClient, Sort, TimeFilter, UserListing};
+use crate::models::account::Account;
+use crate::models::listing::Thing;
+use crate::output::time::time_display;
+use crate::output::{print_result, thing_row, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+
+/// Configuration options for fetching a user's profile and history
+#[derive(Debug, Clone)]
+pub struct UserOptions {
+    /// The username to look up (with or without the `u/` prefix)
+    pub username: String,
+    /// Which profile listing to fetch
+    pub listing: UserListing,
+    /// The number of items to retrieve
+    pub count: i32,
+    /// Sort order for the listing (optional)
+    pub sort: Option<Sort>,
+    /// Time window for top/controversial sorts (optional)
+    pub time: Option<TimeFilter>,
+}
+
+impl Default for UserOptions {
+    fn default() -> Self {
+        Self {
+            username: String::new(),
+            listing: UserListing::Overview,
+            count: 10,
+            sort: None,
+            time: None,
+        }
+    }
+}
+
+/// Result of a user profile fetch operation
+#[derive(Debug, Serialize)]
+pub struct UserResult {
+    /// The user's account information
+    pub account: Account,
+    /// The number of listing items found
+    pub item_count: usize,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+    /// The listing items, in the order Reddit returned them
+    pub items: Vec<Thing>,
+}
+
+impl CommandOutput for UserResult {
+    fn text(&self) -> String {
+        self.formatted_output.clone()
+    }
+
+    /// One row per listing item
+    fn rows(&self) -> Vec<serde_json::Value> {
+        self.items.iter().map(thing_row).collect()
+    }
+}
+
+/// Operation for fetching a user's profile and history listings
+pub struct UserOperation {
+    /// Configuration options for the operation
+    options: UserOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl UserOperation {
+    /// Create a new user operation with the provided options
+    pub fn new(options: UserOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new user operation with a custom Reddit client
+    pub fn with_client(options: UserOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the user operation
+    pub async fn execute(&self) -> Result<UserResult, crate::client::RedditClientError> {
+        info!(
+            "Fetching {} {} items for u/{}",
+            self.options.count, self.options.listing, self.options.username
+        );
+
+        let account = self.client.fetch_user_about(&self.options.username).await?;
+
+        // Page through the listing until we have enough items or run out
+        let wanted = self.options.count.max(0) as usize;
+        let mut items: Vec<Thing> = Vec::new();
+        let mut after: Option<String> = None;
+
+        while items.len() < wanted {
+            let params = ListingParams {
+                limit: Some((wanted - items.len()).min(100) as u32),
+                after: after.clone(),
+                count: Some(items.len() as u32),
+                sort: self.options.sort,
+                time: self.options.time,
+                ..ListingParams::default()
+            };
+
+            let page = self
+                .client
+                .fetch_user_listing(&self.options.username, self.options.listing, &params)
+                .await?;
+
+            if page.data.children.is_empty() {
+                break;
+            }
+
+            items.extend(page.data.children);
+            after = page.data.after;
+            if after.is_none() {
+                break;
+            }
+        }
+        items.truncate(wanted);
+
+        // Generate formatted output for display
+        let mut output = account.format_summary();
+        output.push('\n');
+
+        if items.is_empty() {
+            output.push_str(&format!("No {} items found.\n", self.options.listing));
+        } else {
+            output.push_str(&format!(
+                "Found {} {} items\n",
+                items.len(),
+                self.options.listing
+            ));
+            self.format_items(&items, &mut output);
+
+            // Print a legend for the item type indicators
+            output.push_str("\nItem Type Legend:\n");
+            output.push_str("[P] = Post\n");
+            output.push_str("[C] = Comment\n");
+        }
+
+        Ok(UserResult {
+            account,
+            item_count: items.len(),
+            formatted_output: output,
+            items,
+        })
+    }
+
+    // Internal helper method for the one-line-per-item output format
+    fn format_items(&self, items: &[Thing], output: &mut String) {
+        for (i, item) in items.iter().enumerate() {
+            match item {
+                Thing::Link(post) => {
+                    let title = if post.title.chars().count() > 40 {
+                        let mut chars = post.title.chars().take(37).collect::<String>();
+                        chars.push_str("...");
+                        chars
+                    } else {
+                        post.title.clone()
+                    };
+
+                    output.push_str(&format!(
+                        "{:2}. [P] [{}] {} r/{} | {} pts | ID: t3_{} | https://reddit.com{}\n",
+                        i + 1,
+                        format_time(post.created_utc),
+                        title,
+                        post.subreddit,
+                        post.score,
+                        post.id,
+                        post.permalink
+                    ));
+                }
+                Thing::Comment(comment) => {
+                    output.push_str(&format!(
+                        "{:2}. [C] [{}] \"{}\" r/{} | {} pts | ID: {} | https://reddit.com{}\n",
+                        i + 1,
+                        format_time(comment.created_utc),
+                        comment.excerpt(40),
+                        comment.subreddit,
+                        comment.score,
+                        comment.name,
+                        comment.permalink
+                    ));
+                }
+                other => {
+                    output.push_str(&format!(
+                        "{:2}. [{}] {}\n",
+                        i + 1,
+                        other.kind(),
+                        other.fullname().unwrap_or_default()
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Format a UTC timestamp for item lists, in the configured timezone and format
+pub(crate) fn format_time(created_utc: f64) -> String {
+    time_display().format_timestamp(created_utc, "%Y-%m-%d %H:%M")
+}
+
+/// CLI handler function for user command that accepts a preconfigured client
+pub async fn handle_user_command_with_client(
+    username: String,
+    listing: UserListing,
+    count: i32,
+    sort: Option<Sort>,
+    time: Option<TimeFilter>,
+    output: OutputFormat,
+    client: RedditClient,
+) -> Result<(), crate::client::RedditClientError> {
+    let options = UserOptions {
+        username,
+        listing,
+        count,
+        sort,
+        time,
+    };
+
+    let operation = UserOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            print_result(&result, output)?;
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error fetching user: {:?}", err);
+            Err(err)
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::client::RedditClient;
+use crate::output::{print_result, CommandOutput, OutputFormat};
 use log::{error, info};
+use serde::Serialize;
 
 /// Configuration options for creating a post with user authentication
 #[derive(Debug, Clone)]
@@ -10,10 +12,12 @@ pub struct UserCreateOptions {
     pub title: String,
     /// Text content of the post
     pub text: String,
+    /// Check the post against the subreddit's requirements before submitting
+    pub validate: bool,
 }
 
 /// Result of a user-authenticated post creation operation
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct UserCreateResult {
     /// Whether the post was successfully created
     pub success: bool,
@@ -23,6 +27,12 @@ pub struct UserCreateResult {
     pub message: String,
 }
 
+impl CommandOutput for UserCreateResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.message)
+    }
+}
+
 /// Operation for creating a post on Reddit using user authentication (username/password)
 pub struct UserCreateOperation {
     /// Configuration options for the operation
@@ -59,6 +69,25 @@ impl UserCreateOperation {
 
         // Assume client is already properly authenticated
 
+        // Check the post against the subreddit's requirements before submitting
+        if self.options.validate {
+            if let Some(message) = self
+                .client
+                .check_self_post(
+                    &self.options.subreddit,
+                    &self.options.title,
+                    &self.options.text,
+                )
+                .await?
+            {
+                return Ok(UserCreateResult {
+                    success: false,
+                    post_url: None,
+                    message,
+                });
+            }
+        }
+
         // Now create the post
         match self
             .client
@@ -98,18 +127,23 @@ pub async fn handle_user_create_command_with_client(
     subreddit: String,
     title: String,
     text: String,
+    validate: bool,
+    output: OutputFormat,
     client: RedditClient,
 ) -> Result<(), crate::client::RedditClientError> {
     let options = UserCreateOptions {
         subreddit,
         title,
         text,
+        validate,
     };
 
     let mut operation = UserCreateOperation::with_client(options, client);
     match operation.execute().await {
         Ok(result) => {
-            if result.success {
+            if !output.is_text() {
+                print_result(&result, output)?;
+            } else if result.success {
                 info!("{}", result.message);
             } else {
                 error!("{}", result.message);
@@ -0,0 +1,456 @@
+use crate::client::{ListingParams, RedditClient, RedditClientError};
+use crate::models::wiki::{WikiPage, WikiRevision};
+use crate::operations::edit::{confirm, edit_in_editor, read_text_file, render_diff};
+use crate::operations::user::format_time;
+use crate::output::{print_result, CommandOutput, OutputFormat};
+use log::{error, info};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// A read-only wiki request
+#[derive(Debug, Clone)]
+pub enum WikiRequest {
+    /// List the subreddit's wiki pages
+    List,
+    /// Fetch a page, optionally at a specific revision
+    Get {
+        page: String,
+        revision: Option<String>,
+    },
+    /// Show a page's revision history
+    History { page: String, count: i32 },
+}
+
+/// Configuration options for reading a subreddit's wiki
+#[derive(Debug, Clone)]
+pub struct WikiOptions {
+    /// The subreddit whose wiki to read (with or without the `r/` prefix)
+    pub subreddit: String,
+    /// What to read
+    pub request: WikiRequest,
+}
+
+/// Result of a wiki read operation
+#[derive(Debug, Serialize)]
+pub struct WikiResult {
+    /// Page names, for list requests
+    pub pages: Vec<String>,
+    /// The fetched page, for get requests
+    pub page: Option<WikiPage>,
+    /// The page's revisions, for history requests
+    pub revisions: Vec<WikiRevision>,
+    /// Formatted output (for CLI display)
+    #[serde(skip)]
+    pub formatted_output: String,
+}
+
+impl CommandOutput for WikiResult {
+    fn text(&self) -> String {
+        self.formatted_output.clone()
+    }
+
+    /// One row per page name or revision, or the fetched page
+    fn rows(&self) -> Vec<serde_json::Value> {
+        if let Some(page) = &self.page {
+            return serde_json::to_value(page).into_iter().collect();
+        }
+        if !self.revisions.is_empty() {
+            return self
+                .revisions
+                .iter()
+                .filter_map(|revision| serde_json::to_value(revision).ok())
+                .collect();
+        }
+        self.pages
+            .iter()
+            .map(|page| serde_json::json!({ "page": page }))
+            .collect()
+    }
+}
+
+/// Operation for listing wiki pages and reading pages and their history
+pub struct WikiOperation {
+    /// Configuration options for the operation
+    options: WikiOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl WikiOperation {
+    /// Create a new wiki operation with the provided options
+    pub fn new(options: WikiOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new wiki operation with a custom Reddit client
+    pub fn with_client(options: WikiOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Execute the wiki operation
+    pub async fn execute(&self) -> Result<WikiResult, RedditClientError> {
+        info!(
+            "Executing wiki request {:?} for r/{}",
+            self.options.request, self.options.subreddit
+        );
+
+        let subreddit = &self.options.subreddit;
+        match &self.options.request {
+            WikiRequest::List => {
+                let pages = self.client.wiki_pages(subreddit).await?;
+
+                let mut output = format!("Found {} wiki pages in r/{}\n", pages.len(), subreddit);
+                for page in &pages {
+                    output.push_str(&format!("  {}\n", page));
+                }
+
+                Ok(WikiResult {
+                    pages,
+                    page: None,
+                    revisions: Vec::new(),
+                    formatted_output: output,
+                })
+            }
+            WikiRequest::Get { page, revision } => {
+                let wiki_page = self
+                    .client
+                    .wiki_page(subreddit, page, revision.as_deref())
+                    .await?;
+
+                let output = wiki_page.content_md.clone();
+                Ok(WikiResult {
+                    pages: Vec::new(),
+                    page: Some(wiki_page),
+                    revisions: Vec::new(),
+                    formatted_output: output,
+                })
+            }
+            WikiRequest::History { page, count } => self.history(page, *count).await,
+        }
+    }
+
+    // Internal helper method paging through a page's revisions
+    async fn history(&self, page: &str, count: i32) -> Result<WikiResult, RedditClientError> {
+        let wanted = count.max(0) as usize;
+        let mut revisions: Vec<WikiRevision> = Vec::new();
+        let mut after: Option<String> = None;
+
+        while revisions.len() < wanted {
+            let params = ListingParams {
+                limit: Some((wanted - revisions.len()).min(100) as u32),
+                after: after.clone(),
+                count: Some(revisions.len() as u32),
+                ..ListingParams::default()
+            };
+
+            let listing = self
+                .client
+                .wiki_revisions(&self.options.subreddit, page, &params)
+                .await?;
+            if listing.data.children.is_empty() {
+                break;
+            }
+
+            revisions.extend(listing.data.children);
+            after = listing.data.after;
+            if after.is_none() {
+                break;
+            }
+        }
+        revisions.truncate(wanted);
+
+        let mut output = format!(
+            "Found {} revisions of {} in r/{}\n",
+            revisions.len(),
+            page,
+            self.options.subreddit
+        );
+        for (i, revision) in revisions.iter().enumerate() {
+            output.push_str(&format!(
+                "{:2}. [{}] u/{} | ID: {}",
+                i + 1,
+                format_time(revision.timestamp),
+                revision.author_name(),
+                revision.id
+            ));
+            if let Some(reason) = revision.reason.as_deref().filter(|r| !r.is_empty()) {
+                output.push_str(&format!(" | {}", reason));
+            }
+            if revision.revision_hidden {
+                output.push_str(" [hidden]");
+            }
+            output.push('\n');
+        }
+
+        Ok(WikiResult {
+            pages: Vec::new(),
+            page: None,
+            revisions,
+            formatted_output: output,
+        })
+    }
+}
+
+/// Configuration options for editing a wiki page
+#[derive(Debug, Clone)]
+pub struct WikiEditOptions {
+    /// The subreddit whose wiki to edit (with or without the `r/` prefix)
+    pub subreddit: String,
+    /// The page name, e.g. "index" or "config/automoderator"
+    pub page: String,
+    /// The new page content in markdown
+    pub content: String,
+    /// Edit reason shown in the page history (optional)
+    pub reason: Option<String>,
+}
+
+/// Current state of a wiki page, fetched before an edit
+#[derive(Debug)]
+pub struct WikiEditPreview {
+    /// The current content; empty for a page that doesn't exist yet
+    pub old_content: String,
+    /// The revision the edit is based on, used to detect conflicting edits
+    pub revision_id: Option<String>,
+    /// Unified diff between the current and new content
+    pub diff: String,
+}
+
+/// Result of a wiki edit operation
+#[derive(Debug, Serialize)]
+pub struct WikiEditResult {
+    /// Whether the edit was applied
+    pub success: bool,
+    /// Unified diff between the old and new content
+    pub diff: String,
+    /// Formatted message for CLI output
+    pub message: String,
+}
+
+impl CommandOutput for WikiEditResult {
+    fn text(&self) -> String {
+        format!("{}\n", self.message)
+    }
+}
+
+/// Operation for replacing the content of a wiki page with a diff preview
+pub struct WikiEditOperation {
+    /// Configuration options for the operation
+    options: WikiEditOptions,
+    /// Reddit client for API interactions
+    client: RedditClient,
+}
+
+impl WikiEditOperation {
+    /// Create a new wiki edit operation with the provided options
+    pub fn new(options: WikiEditOptions) -> Self {
+        let client = RedditClient::new();
+        Self { options, client }
+    }
+
+    /// Create a new wiki edit operation with a custom Reddit client
+    pub fn with_client(options: WikiEditOptions, client: RedditClient) -> Self {
+        Self { options, client }
+    }
+
+    /// Fetch the current page and return a diff against the new content without editing
+    pub async fn preview(&mut self) -> Result<WikiEditPreview, RedditClientError> {
+        self.client.ensure_access_token().await?;
+
+        let label = format!("r/{}/wiki/{}", self.options.subreddit, self.options.page);
+        let (old_content, revision_id) = match self
+            .client
+            .wiki_page(&self.options.subreddit, &self.options.page, None)
+            .await
+        {
+            Ok(page) => (page.content_md, page.revision_id),
+            // A missing page is created by the edit
+            Err(RedditClientError::ApiError(message)) if message.contains("404") => {
+                info!("{} doesn't exist yet and will be created", label);
+                (String::new(), None)
+            }
+            Err(err) => return Err(err),
+        };
+        let diff = render_diff(&old_content, &self.options.content, &label);
+
+        Ok(WikiEditPreview {
+            old_content,
+            revision_id,
+            diff,
+        })
+    }
+
+    /// Execute the wiki edit operation
+    pub async fn execute(&mut self) -> Result<WikiEditResult, RedditClientError> {
+        info!(
+            "Editing wiki page {} in r/{}",
+            self.options.page, self.options.subreddit
+        );
+
+        let preview = self.preview().await?;
+        self.apply(preview).await
+    }
+
+    /// Send the edit, given the current state from `preview`
+    pub async fn apply(
+        &self,
+        preview: WikiEditPreview,
+    ) -> Result<WikiEditResult, RedditClientError> {
+        let label = format!("r/{}/wiki/{}", self.options.subreddit, self.options.page);
+        if preview.old_content == self.options.content {
+            return Ok(WikiEditResult {
+                success: true,
+                diff: preview.diff,
+                message: format!("No changes to {}", label),
+            });
+        }
+
+        match self
+            .client
+            .wiki_edit(
+                &self.options.subreddit,
+                &self.options.page,
+                &self.options.content,
+                self.options.reason.as_deref(),
+                preview.revision_id.as_deref(),
+            )
+            .await
+        {
+            Ok(()) => Ok(WikiEditResult {
+                success: true,
+                diff: preview.diff,
+                message: format!("Edited {} successfully!", label),
+            }),
+            Err(err) => Ok(WikiEditResult {
+                success: false,
+                diff: preview.diff,
+                message: format!(
+                    "Error editing {}: {:?}\n\nNote: Editing the wiki requires OAuth authentication with the 'wikiedit' scope.",
+                    label, err
+                ),
+            }),
+        }
+    }
+}
+
+/// CLI handler function for wiki list, get and history commands with client
+///
+/// For get requests the page content is written to `file` when given, so
+/// pages can be kept in version control, and printed otherwise.
+pub async fn handle_wiki_command_with_client(
+    subreddit: String,
+    request: WikiRequest,
+    file: Option<PathBuf>,
+    output: OutputFormat,
+    mut client: RedditClient,
+) -> Result<(), RedditClientError> {
+    // Private wikis need a user token, but public ones can be read without one
+    if let Err(err) = client.ensure_access_token().await {
+        info!("Reading the wiki without user authentication: {}", err);
+    }
+
+    let options = WikiOptions { subreddit, request };
+    let operation = WikiOperation::with_client(options, client);
+    match operation.execute().await {
+        Ok(result) => {
+            match file {
+                Some(path) => {
+                    std::fs::write(&path, &result.formatted_output).map_err(|e| {
+                        RedditClientError::ApiError(format!(
+                            "Failed to write {}: {}",
+                            path.display(),
+                            e
+                        ))
+                    })?;
+                    println!("Wrote {}", path.display());
+                }
+                None => print_result(&result, output)?,
+            }
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error reading wiki: {:?}", err);
+            Err(err)
+        }
+    }
+}
+
+/// CLI handler function for wiki edit command with client
+///
+/// The new content comes from `file` ("-" for stdin) or, when no file is given,
+/// from `$EDITOR` seeded with the current content. A diff is shown before sending,
+/// and the edit is only applied after confirmation unless `yes` is set.
+pub async fn handle_wiki_edit_command_with_client(
+    subreddit: String,
+    page: String,
+    file: Option<PathBuf>,
+    reason: Option<String>,
+    yes: bool,
+    output: OutputFormat,
+    mut client: RedditClient,
+) -> Result<(), RedditClientError> {
+    client.ensure_access_token().await?;
+
+    let content = match &file {
+        Some(path) => read_text_file(path)?,
+        None => {
+            let current = match client.wiki_page(&subreddit, &page, None).await {
+                Ok(current) => current.content_md,
+                // Start a new page from scratch
+                Err(RedditClientError::ApiError(message)) if message.contains("404") => {
+                    String::new()
+                }
+                Err(err) => return Err(err),
+            };
+            edit_in_editor(&current, &page.replace('/', "-"))?
+        }
+    };
+
+    let options = WikiEditOptions {
+        subreddit,
+        page,
+        content,
+        reason,
+    };
+    let mut operation = WikiEditOperation::with_client(options, client);
+
+    let preview = operation.preview().await?;
+    // Keep stdout for the result when it's machine-readable
+    if preview.old_content == operation.options.content {
+        let message = format!(
+            "No changes to r/{}/wiki/{}",
+            operation.options.subreddit, operation.options.page
+        );
+        if output.is_text() {
+            println!("{}", message);
+        } else {
+            eprintln!("{}", message);
+        }
+        return Ok(());
+    }
+    if output.is_text() {
+        print!("{}", preview.diff);
+    } else {
+        eprint!("{}", preview.diff);
+    }
+
+    if !yes && !confirm("Apply this wiki edit?") {
+        eprintln!("Edit cancelled.");
+        return Ok(());
+    }
+
+    match operation.apply(preview).await {
+        Ok(result) => {
+            if result.success || !output.is_text() {
+                print_result(&result, output)?;
+            } else {
+                eprintln!("{}", result.message);
+            }
+            Ok(())
+        }
+        Err(err) => {
+            error!("Error executing wiki edit: {:?}", err);
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Machine-readable output for command results
+//!
+//! Every command result implements [`CommandOutput`], so handlers can print
+//! it as the usual text or as JSON, NDJSON or CSV for use in shell pipelines.
+//! Structured output goes to stdout; logs stay on stderr.
+
+pub mod time;
+
+use crate::client::RedditClientError;
+use crate::models::listing::Thing;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::str::FromStr;
+
+/// How a command prints its result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// The whole result as one pretty-printed JSON document
+    Json,
+    /// One JSON object per line, one line per row of the result
+    Ndjson,
+    /// One CSV record per row of the result, with nested fields flattened
+    Csv,
+}
+
+impl OutputFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::Ndjson => "ndjson",
+            OutputFormat::Csv => "csv",
+        }
+    }
+
+    /// Whether this is the default human-readable format
+    pub fn is_text(&self) -> bool {
+        *self == OutputFormat::Text
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "ndjson" | "jsonl" => Ok(OutputFormat::Ndjson),
+            "csv" => Ok(OutputFormat::Csv),
+            other => Err(format!(
+                "Unknown output format '{}', expected one of: text, json, ndjson, csv",
+                other
+            )),
+        }
+    }
+}
+
+/// A command result that can be printed in any [`OutputFormat`]
+pub trait CommandOutput: Serialize {
+    /// The human-readable text printed by default
+    fn text(&self) -> String;
+
+    /// The rows printed for NDJSON and CSV, e.g. one per post in a listing.
+    ///
+    /// Defaults to the whole result as a single row.
+    fn rows(&self) -> Vec<Value> {
+        vec![serde_json::to_value(self).unwrap_or(Value::Null)]
+    }
+}
+
+/// Render a result in the given format
+pub fn render<T: CommandOutput>(
+    result: &T,
+    format: OutputFormat,
+) -> Result<String, RedditClientError> {
+    match format {
+        OutputFormat::Text => Ok(result.text()),
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(result)? + "\n"),
+        OutputFormat::Ndjson => render_ndjson(&result.rows()),
+        OutputFormat::Csv => render_csv(&result.rows()),
+    }
+}
+
+/// Print a result to stdout in the given format
+pub fn print_result<T: CommandOutput>(
+    result: &T,
+    format: OutputFormat,
+) -> Result<(), RedditClientError> {
+    print!("{}", render(result, format)?);
+    Ok(())
+}
+
+/// A listing item as a row: its data with the `kind` added, so fields like
+/// `title` and `author` are top-level columns
+pub fn thing_row(thing: &Thing) -> Value {
+    let mut row = serde_json::to_value(thing).unwrap_or(Value::Null);
+    match row.get_mut("data").map(Value::take) {
+        Some(Value::Object(mut data)) => {
+            data.insert("kind".to_string(), Value::String(thing.kind().to_string()));
+            Value::Object(data)
+        }
+        _ => row,
+    }
+}
+
+/// Render rows as newline-delimited JSON
+pub fn render_ndjson(rows: &[Value]) -> Result<String, RedditClientError> {
+    let mut output = String::new();
+    for row in rows {
+        output.push_str(&serde_json::to_string(row)?);
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+/// Render rows as CSV.
+///
+/// Nested objects become dotted columns (e.g. `data.title`), arrays are written
+/// as JSON, and the columns are every key seen in any row, in first-seen order.
+pub fn render_csv(rows: &[Value]) -> Result<String, RedditClientError> {
+    if rows.is_empty() {
+        return Ok(String::new());
+    }
+
+    let flattened: Vec<Map<String, Value>> = rows
+        .iter()
+        .map(|row| {
+            let mut fields = Map::new();
+            flatten_into("", row, &mut fields);
+            fields
+        })
+        .collect();
+
+    let mut columns: Vec<&String> = Vec::new();
+    for fields in &flattened {
+        for key in fields.keys() {
+            if !columns.contains(&key) {
+                columns.push(key);
+            }
+        }
+    }
+
+    let failed = |e: csv::Error| RedditClientError::ApiError(format!("Failed to write CSV: {}", e));
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(&columns).map_err(failed)?;
+    for fields in &flattened {
+        writer
+            .write_record(
+                columns
+                    .iter()
+                    .map(|column| fields.get(*column).map(csv_cell).unwrap_or_default()),
+            )
+            .map_err(failed)?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| RedditClientError::ApiError(format!("Failed to write CSV: {}", e)))?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Flatten nested objects into dotted keys
+fn flatten_into(prefix: &str, value: &Value, fields: &mut Map<String, Value>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_into(&key, value, fields);
+            }
+        }
+        value => {
+            let key = if prefix.is_empty() { "value" } else { prefix };
+            fields.insert(key.to_string(), value.clone());
+        }
+    }
+}
+
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
@@ -0,0 +1,129 @@
+//! How timestamps are shown in command output
+//!
+//! The timezone, strftime pattern and relative display are chosen once at
+//! startup from `--tz`, `--time-format` and `--relative-time` (or the matching
+//! `AppConfig` settings) and used by every command's text output.
+
+use crate::client::RedditClientError;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local, Utc};
+use chrono_tz::Tz;
+use std::sync::OnceLock;
+
+static TIME_DISPLAY: OnceLock<TimeDisplay> = OnceLock::new();
+
+/// The timezone that times are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayZone {
+    /// The system's local timezone
+    #[default]
+    Local,
+    /// An IANA timezone such as Europe/Berlin
+    Named(Tz),
+}
+
+/// Settings for showing timestamps
+#[derive(Debug, Clone, Default)]
+pub struct TimeDisplay {
+    pub zone: DisplayZone,
+    /// strftime pattern used instead of each view's default pattern
+    pub format: Option<String>,
+    /// Show times as "3h ago" instead of a date and time
+    pub relative: bool,
+}
+
+impl TimeDisplay {
+    /// Build display settings, checking the timezone name and strftime pattern.
+    ///
+    /// `timezone` is an IANA name, "UTC", or "local"; `None` means local time.
+    pub fn new(
+        timezone: Option<&str>,
+        format: Option<String>,
+        relative: bool,
+    ) -> Result<Self, RedditClientError> {
+        let zone = match timezone {
+            None => DisplayZone::Local,
+            Some(name) if name.eq_ignore_ascii_case("local") => DisplayZone::Local,
+            Some(name) => DisplayZone::Named(name.parse::<Tz>().map_err(|_| {
+                RedditClientError::ApiError(format!(
+                    "Unknown timezone '{}', expected an IANA name like Europe/Berlin, UTC or local",
+                    name
+                ))
+            })?),
+        };
+
+        if let Some(format) = &format {
+            if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+                return Err(RedditClientError::ApiError(format!(
+                    "Invalid time format '{}', expected a strftime pattern like %Y-%m-%d %H:%M",
+                    format
+                )));
+            }
+        }
+
+        Ok(Self {
+            zone,
+            format,
+            relative,
+        })
+    }
+
+    /// Format a time, using `default_format` unless a pattern was configured
+    pub fn format(&self, time: DateTime<Utc>, default_format: &str) -> String {
+        if self.relative {
+            return format_relative(time, Utc::now());
+        }
+
+        let format = self.format.as_deref().unwrap_or(default_format);
+        match self.zone {
+            DisplayZone::Local => time.with_timezone(&Local).format(format).to_string(),
+            DisplayZone::Named(tz) => time.with_timezone(&tz).format(format).to_string(),
+        }
+    }
+
+    /// Format a Reddit `created_utc` timestamp, or nothing if it is out of range
+    pub fn format_timestamp(&self, created_utc: f64, default_format: &str) -> String {
+        DateTime::from_timestamp(created_utc as i64, 0)
+            .map(|time| self.format(time, default_format))
+            .unwrap_or_default()
+    }
+
+    /// A label for the timezone, e.g. "Europe/Berlin time" or "local time"
+    pub fn zone_label(&self) -> String {
+        match self.zone {
+            DisplayZone::Local => "local time".to_string(),
+            DisplayZone::Named(tz) => format!("{} time", tz.name()),
+        }
+    }
+}
+
+/// Set the display settings for this process; only the first call has an effect
+pub fn set_time_display(display: TimeDisplay) {
+    let _ = TIME_DISPLAY.set(display);
+}
+
+/// The display settings for this process, local time by default
+pub fn time_display() -> &'static TimeDisplay {
+    TIME_DISPLAY.get_or_init(TimeDisplay::default)
+}
+
+/// Format a time relative to `now`, e.g. "3h ago" or "in 2d"
+pub fn format_relative(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - time).num_seconds();
+    let magnitude = seconds.unsigned_abs();
+
+    let amount = match magnitude {
+        0..=59 => return "just now".to_string(),
+        60..=3_599 => format!("{}m", magnitude / 60),
+        3_600..=86_399 => format!("{}h", magnitude / 3_600),
+        86_400..=2_591_999 => format!("{}d", magnitude / 86_400),
+        2_592_000..=31_535_999 => format!("{}mo", magnitude / 2_592_000),
+        _ => format!("{}y", magnitude / 31_536_000),
+    };
+
+    if seconds >= 0 {
+        format!("{} ago", amount)
+    } else {
+        format!("in {}", amount)
+    }
+}
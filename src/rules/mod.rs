@@ -0,0 +1,498 @@
+//! Declarative rules for auto-replies and auto-moderation
+//!
+//! A rules file (YAML or TOML) lists rules that are checked against posts and
+//! comments. Every condition in a rule's `match` block must hold for the rule
+//! to apply, and its actions run in the order they are written:
+//!
+//! ```yaml
+//! subreddits: [mysubreddit]
+//! rules:
+//!   - name: link-shorteners
+//!     on: submission
+//!     match:
+//!       domain: [bit.ly, tinyurl.com]
+//!     actions:
+//!       - remove
+//!       - reply: "Please link to the original site instead of a URL shortener."
+//!   - name: new-accounts
+//!     match:
+//!       author_max_age_days: 2
+//!       author_max_karma: 10
+//!     actions:
+//!       - report: "New account, check for spam"
+//! ```
+
+use crate::client::{RedditClient, RedditClientError};
+use crate::models::account::Account;
+use crate::models::comment::CommentData;
+use crate::models::RedditPostData;
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// A rules file: the subreddits to watch and the rules to apply
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleSet {
+    /// Subreddits watched by `rules run` when none are given on the command line
+    #[serde(default)]
+    pub subreddits: Vec<String>,
+    /// Rules in the order they are checked
+    pub rules: Vec<Rule>,
+}
+
+/// A named set of conditions and the actions to take when they all match
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    pub name: String,
+    /// Which kinds of items the rule applies to
+    #[serde(default, rename = "on")]
+    pub target: RuleTarget,
+    /// Conditions that must all hold; an empty block matches everything
+    #[serde(default, rename = "match")]
+    pub conditions: Conditions,
+    pub actions: Vec<RuleAction>,
+}
+
+/// The kinds of items a rule applies to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleTarget {
+    /// Posts and comments
+    #[default]
+    Any,
+    Submission,
+    Comment,
+}
+
+/// The conditions of a rule.
+///
+/// Title, domain and flair only exist on posts, so a rule using them never
+/// matches a comment.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Conditions {
+    /// Regex matched against the post title
+    pub title: Option<Pattern>,
+    /// Regex matched against the post's self text or the comment body
+    pub body: Option<Pattern>,
+    /// Link domains; subdomains match too, and self posts have the domain `self.{subreddit}`
+    #[serde(default)]
+    pub domain: Vec<String>,
+    /// Regex matched against the post flair text
+    pub flair: Option<Pattern>,
+    /// Whether the post (or the comment's post) is marked NSFW
+    pub nsfw: Option<bool>,
+    /// Minimum author account age in days
+    pub author_min_age_days: Option<f64>,
+    /// Maximum author account age in days
+    pub author_max_age_days: Option<f64>,
+    /// Minimum total author karma
+    pub author_min_karma: Option<i64>,
+    /// Maximum total author karma
+    pub author_max_karma: Option<i64>,
+}
+
+/// A regular expression compiled when the rules file is loaded
+#[derive(Debug, Clone)]
+pub struct Pattern(Regex);
+
+impl Pattern {
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.is_match(text)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        Regex::new(&pattern)
+            .map(Pattern)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Something a rule does to a matching item.
+///
+/// Text fields may use the placeholders `{author}`, `{subreddit}`, `{title}`,
+/// `{permalink}` and `{fullname}`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleAction {
+    /// Reply with a comment
+    Reply(String),
+    /// Report the item to the subreddit's moderators with a reason
+    Report(String),
+    /// Remove the item
+    Remove,
+    /// Remove the item as spam
+    Spam,
+    /// Approve the item
+    Approve,
+    /// Set the post flair by text, template ID, or both
+    Flair {
+        text: Option<String>,
+        template_id: Option<String>,
+    },
+    /// Send a private message, to the item's author unless `to` is given
+    Message {
+        to: Option<String>,
+        subject: String,
+        body: String,
+    },
+}
+
+/// A post or comment being checked against the rules
+#[derive(Debug, Clone, Copy)]
+pub enum RuleItem<'a> {
+    Submission(&'a RedditPostData),
+    Comment(&'a CommentData),
+}
+
+impl RuleItem<'_> {
+    /// The item's fullname (`t3_...` or `t1_...`)
+    pub fn fullname(&self) -> String {
+        match self {
+            RuleItem::Submission(post) => format!("t3_{}", post.id),
+            RuleItem::Comment(comment) => comment.name.clone(),
+        }
+    }
+
+    pub fn author(&self) -> &str {
+        match self {
+            RuleItem::Submission(post) => &post.author,
+            RuleItem::Comment(comment) => &comment.author,
+        }
+    }
+
+    pub fn subreddit(&self) -> &str {
+        match self {
+            RuleItem::Submission(post) => &post.subreddit,
+            RuleItem::Comment(comment) => &comment.subreddit,
+        }
+    }
+
+    pub fn permalink(&self) -> &str {
+        match self {
+            RuleItem::Submission(post) => &post.permalink,
+            RuleItem::Comment(comment) => &comment.permalink,
+        }
+    }
+
+    /// The post title, or the title of the comment's post when Reddit includes it
+    pub fn title(&self) -> Option<&str> {
+        match self {
+            RuleItem::Submission(post) => Some(&post.title),
+            RuleItem::Comment(comment) => comment.link_title.as_deref(),
+        }
+    }
+
+    /// The post's self text or the comment body
+    pub fn body(&self) -> &str {
+        match self {
+            RuleItem::Submission(post) => &post.selftext,
+            RuleItem::Comment(comment) => &comment.body,
+        }
+    }
+
+    /// The domain a post links to, without any `www.` prefix
+    pub fn domain(&self) -> Option<String> {
+        match self {
+            RuleItem::Submission(post) if post.is_self => {
+                Some(format!("self.{}", post.subreddit.to_lowercase()))
+            }
+            RuleItem::Submission(post) => url::Url::parse(&post.url).ok().and_then(|url| {
+                url.host_str()
+                    .map(|host| host.trim_start_matches("www.").to_lowercase())
+            }),
+            RuleItem::Comment(_) => None,
+        }
+    }
+
+    /// The post flair text
+    pub fn flair(&self) -> Option<&str> {
+        match self {
+            RuleItem::Submission(post) => post.link_flair_text.as_deref(),
+            RuleItem::Comment(_) => None,
+        }
+    }
+
+    /// Whether the post, or the post a comment belongs to, is marked NSFW
+    pub fn nsfw(&self) -> Option<bool> {
+        match self {
+            RuleItem::Submission(post) => Some(post.over_18),
+            RuleItem::Comment(comment) => comment
+                .additional_fields
+                .get("over_18")
+                .and_then(|value| value.as_bool()),
+        }
+    }
+
+    /// Fill in the placeholders of an action's text
+    pub fn render(&self, template: &str) -> String {
+        template
+            .replace("{author}", self.author())
+            .replace("{subreddit}", self.subreddit())
+            .replace("{title}", self.title().unwrap_or_default())
+            .replace("{permalink}", self.permalink())
+            .replace("{fullname}", &self.fullname())
+    }
+}
+
+impl RuleSet {
+    /// Load rules from a `.yaml`, `.yml` or `.toml` file
+    pub fn load(path: &Path) -> Result<Self, RedditClientError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            RedditClientError::ApiError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        let rules: RuleSet = match extension.as_str() {
+            // serde_yaml only reads enums written as YAML tags, so go through JSON
+            // to also accept `- reply: text` and `- remove`
+            "yaml" | "yml" => serde_yaml::from_str::<serde_json::Value>(&contents)
+                .map_err(|e| e.to_string())
+                .and_then(|value| serde_json::from_value(value).map_err(|e| e.to_string())),
+            "toml" => toml::from_str(&contents).map_err(|e| e.to_string()),
+            _ => Err("expected a .yaml, .yml or .toml file".to_string()),
+        }
+        .map_err(|e| {
+            RedditClientError::ApiError(format!("Invalid rules file {}: {}", path.display(), e))
+        })?;
+
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    /// Check that rule names are unique and every rule does something
+    fn validate(&self) -> Result<(), RedditClientError> {
+        let mut names = HashSet::new();
+        for rule in &self.rules {
+            if !names.insert(rule.name.as_str()) {
+                return Err(RedditClientError::ApiError(format!(
+                    "Duplicate rule name '{}'",
+                    rule.name
+                )));
+            }
+            if rule.actions.is_empty() {
+                return Err(RedditClientError::ApiError(format!(
+                    "Rule '{}' has no actions",
+                    rule.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether any rule applies to the given kind of item
+    pub fn targets(&self, target: RuleTarget) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| rule.target == RuleTarget::Any || rule.target == target)
+    }
+
+    /// The rules matching an item, in file order.
+    ///
+    /// `author` is the item author's account, needed by rules with age or karma
+    /// conditions; without it those rules don't match.
+    pub fn matching<'a>(&'a self, item: &RuleItem, author: Option<&Account>) -> Vec<&'a Rule> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(item, author))
+            .collect()
+    }
+
+    /// Whether checking an item may need its author's account
+    pub fn needs_author(&self, item: &RuleItem) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| rule.needs_author() && rule.matches_content(item))
+    }
+}
+
+impl Rule {
+    /// Whether the rule has author age or karma conditions
+    pub fn needs_author(&self) -> bool {
+        let conditions = &self.conditions;
+        conditions.author_min_age_days.is_some()
+            || conditions.author_max_age_days.is_some()
+            || conditions.author_min_karma.is_some()
+            || conditions.author_max_karma.is_some()
+    }
+
+    /// Check every condition against an item and its author's account
+    pub fn matches(&self, item: &RuleItem, author: Option<&Account>) -> bool {
+        if !self.matches_content(item) {
+            return false;
+        }
+        if !self.needs_author() {
+            return true;
+        }
+
+        let Some(account) = author else {
+            return false;
+        };
+        let conditions = &self.conditions;
+
+        let age_days = (chrono::Utc::now().timestamp() as f64 - account.created_utc) / 86400.0;
+        if conditions
+            .author_min_age_days
+            .is_some_and(|min| age_days < min)
+            || conditions
+                .author_max_age_days
+                .is_some_and(|max| age_days > max)
+        {
+            return false;
+        }
+
+        // Older API responses don't include total_karma
+        let karma = if account.total_karma != 0 {
+            account.total_karma
+        } else {
+            account.link_karma + account.comment_karma
+        };
+        !(conditions.author_min_karma.is_some_and(|min| karma < min)
+            || conditions.author_max_karma.is_some_and(|max| karma > max))
+    }
+
+    /// Check the conditions that only need the item itself
+    fn matches_content(&self, item: &RuleItem) -> bool {
+        let target_matches = matches!(
+            (self.target, item),
+            (RuleTarget::Any, _)
+                | (RuleTarget::Submission, RuleItem::Submission(_))
+                | (RuleTarget::Comment, RuleItem::Comment(_))
+        );
+        if !target_matches {
+            return false;
+        }
+
+        let conditions = &self.conditions;
+        if let Some(title) = &conditions.title {
+            if !matches!(item, RuleItem::Submission(_))
+                || !title.is_match(item.title().unwrap_or_default())
+            {
+                return false;
+            }
+        }
+        if let Some(body) = &conditions.body {
+            if !body.is_match(item.body()) {
+                return false;
+            }
+        }
+        if !conditions.domain.is_empty() {
+            let Some(domain) = item.domain() else {
+                return false;
+            };
+            let listed = conditions.domain.iter().any(|listed| {
+                let listed = listed.trim_start_matches("www.").to_lowercase();
+                domain == listed || domain.ends_with(&format!(".{}", listed))
+            });
+            if !listed {
+                return false;
+            }
+        }
+        if let Some(flair) = &conditions.flair {
+            if !item.flair().is_some_and(|text| flair.is_match(text)) {
+                return false;
+            }
+        }
+        if let Some(nsfw) = conditions.nsfw {
+            if item.nsfw() != Some(nsfw) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl RuleAction {
+    /// Describe what the action would do to an item, for dry runs and logs
+    pub fn describe(&self, item: &RuleItem) -> String {
+        match self {
+            RuleAction::Reply(text) => format!("reply: {}", excerpt(&item.render(text))),
+            RuleAction::Report(reason) => format!("report: {}", item.render(reason)),
+            RuleAction::Remove => "remove".to_string(),
+            RuleAction::Spam => "remove as spam".to_string(),
+            RuleAction::Approve => "approve".to_string(),
+            RuleAction::Flair { text, template_id } => match (text, template_id) {
+                (Some(text), _) => format!("set flair: {}", item.render(text)),
+                (None, Some(template_id)) => format!("set flair template: {}", template_id),
+                (None, None) => "set flair".to_string(),
+            },
+            RuleAction::Message { to, subject, .. } => format!(
+                "message {}: {}",
+                to.as_deref()
+                    .map(|to| item.render(to))
+                    .unwrap_or_else(|| format!("u/{}", item.author())),
+                item.render(subject)
+            ),
+        }
+    }
+
+    /// Carry out the action on an item
+    pub async fn apply(
+        &self,
+        client: &RedditClient,
+        item: &RuleItem<'_>,
+    ) -> Result<(), RedditClientError> {
+        let fullname = item.fullname();
+        match self {
+            RuleAction::Reply(text) => {
+                client.create_comment(&fullname, &item.render(text)).await?;
+            }
+            RuleAction::Report(reason) => client.report(&fullname, &item.render(reason)).await?,
+            RuleAction::Remove => client.remove(&fullname, false).await?,
+            RuleAction::Spam => client.remove(&fullname, true).await?,
+            RuleAction::Approve => client.approve(&fullname).await?,
+            RuleAction::Flair { text, template_id } => {
+                if !matches!(item, RuleItem::Submission(_)) {
+                    return Err(RedditClientError::ApiError(
+                        "Flair can only be set on posts".to_string(),
+                    ));
+                }
+                let text = text.as_deref().map(|text| item.render(text));
+                client
+                    .set_link_flair(
+                        &fullname,
+                        item.subreddit(),
+                        text.as_deref(),
+                        template_id.as_deref(),
+                    )
+                    .await?
+            }
+            RuleAction::Message { to, subject, body } => {
+                let to = to
+                    .as_deref()
+                    .map(|to| item.render(to))
+                    .unwrap_or_else(|| item.author().to_string());
+                client
+                    .compose(&to, &item.render(subject), &item.render(body))
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Shorten text to one line for display
+fn excerpt(text: &str) -> String {
+    let text = text.trim().replace('\n', " ");
+    if text.chars().count() > 60 {
+        format!("{}...", text.chars().take(57).collect::<String>())
+    } else {
+        text
+    }
+}
@@ -0,0 +1,638 @@
+//! Persistent queue of posts to publish at a later time
+//!
+//! Jobs live in a JSON file (`~/.redrust/schedule.json` by default) that is
+//! shared between the `schedule` commands and the `schedule run` daemon.
+//! Every change goes through `ScheduleStore::update`, which holds a lock file
+//! while reading, modifying and writing the queue.
+//!
+//! Several daemons can share a queue. A daemon claims due jobs by marking
+//! them `publishing` under its own ID and refreshes a heartbeat on them while
+//! it works; jobs whose heartbeat has stopped are handed back to the queue.
+
+use crate::client::RedditClientError;
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+
+/// How long to wait for another process to release the lock file
+const LOCK_TIMEOUT_SECS: u64 = 10;
+
+/// Delay before the first retry; doubled after each further failure
+const RETRY_BASE_SECS: i64 = 60;
+
+/// Longest delay between retries
+const RETRY_MAX_SECS: i64 = 3600;
+
+/// How often a daemon refreshes the heartbeat on the jobs it has claimed
+pub const HEARTBEAT_SECS: i64 = 60;
+
+/// A claim whose heartbeat is older than this belongs to a daemon that stopped
+const STALE_CLAIM_SECS: i64 = 5 * HEARTBEAT_SECS;
+
+/// Where a scheduled post is in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Waiting for its publish time
+    Pending,
+    /// A publish attempt failed and another is scheduled
+    Retrying,
+    /// Currently being submitted by a `schedule run` daemon
+    Publishing,
+    /// Submitted successfully
+    Published,
+    /// Gave up after the maximum number of attempts
+    Failed,
+    /// Cancelled before it was published
+    Cancelled,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Retrying => "retrying",
+            JobStatus::Publishing => "publishing",
+            JobStatus::Published => "published",
+            JobStatus::Failed => "failed",
+            JobStatus::Cancelled => "cancelled",
+        }
+    }
+
+    /// Whether the job is still waiting to be published
+    pub fn is_waiting(&self) -> bool {
+        matches!(self, JobStatus::Pending | JobStatus::Retrying)
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// One entry in a job's status history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub at: DateTime<Utc>,
+    pub status: JobStatus,
+    /// Error message, post URL or other detail
+    pub message: Option<String>,
+}
+
+/// The daemon publishing a job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claim {
+    /// ID of the daemon run, unique per process start
+    pub daemon: String,
+    /// When the daemon last showed it was still working on the job
+    pub heartbeat_at: DateTime<Utc>,
+}
+
+/// A post waiting to be published, or the record of one that was
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledPost {
+    pub id: u64,
+    pub subreddit: String,
+    pub title: String,
+    pub text: String,
+    /// When the post should be published
+    pub publish_at: DateTime<Utc>,
+    /// The timezone the publish time was given in, used for display
+    pub timezone: String,
+    pub status: JobStatus,
+    /// Publish attempts made so far
+    pub attempts: u32,
+    /// When the next attempt is due
+    pub next_attempt_at: DateTime<Utc>,
+    /// URL of the published post
+    pub post_url: Option<String>,
+    /// The daemon publishing the job, while it is `publishing`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claim: Option<Claim>,
+    /// Every status the job has been through, oldest first
+    pub history: Vec<StatusChange>,
+}
+
+impl ScheduledPost {
+    /// Move the job to a new status and record it in the history
+    pub fn set_status(&mut self, status: JobStatus, message: Option<String>) {
+        self.status = status;
+        if status != JobStatus::Publishing {
+            self.claim = None;
+        }
+        self.history.push(StatusChange {
+            at: Utc::now(),
+            status,
+            message,
+        });
+    }
+
+    /// Record a successful publish
+    pub fn mark_published(&mut self, post_url: String) {
+        self.post_url = Some(post_url.clone());
+        self.set_status(JobStatus::Published, Some(post_url));
+    }
+
+    /// Record a failed attempt, scheduling a retry with exponential backoff
+    /// until `max_attempts` is reached
+    pub fn mark_failed(&mut self, error: String, max_attempts: u32) {
+        if self.attempts >= max_attempts {
+            self.set_status(JobStatus::Failed, Some(error));
+            return;
+        }
+
+        let delay =
+            (RETRY_BASE_SECS << self.attempts.saturating_sub(1).min(16)).min(RETRY_MAX_SECS);
+        self.next_attempt_at = Utc::now() + Duration::seconds(delay);
+        self.set_status(
+            JobStatus::Retrying,
+            Some(format!("{} (retrying in {}m)", error, delay / 60)),
+        );
+    }
+
+    /// The publish time in the timezone it was scheduled in
+    pub fn local_publish_time(&self) -> String {
+        format_in_timezone(self.publish_at, &self.timezone)
+    }
+
+    /// Format the job as a one-line summary
+    pub fn format_summary(&self) -> String {
+        let mut line = format!(
+            "#{:<4} {:<10} {}  r/{}: {}",
+            self.id,
+            self.status.as_str(),
+            self.local_publish_time(),
+            self.subreddit,
+            self.title
+        );
+        if let Some(url) = &self.post_url {
+            line.push_str(&format!("\n      {}", url));
+        } else if let Some(message) = self
+            .history
+            .last()
+            .and_then(|change| change.message.as_ref())
+        {
+            line.push_str(&format!("\n      {}", message));
+        }
+        line
+    }
+
+    /// Format the job with its full status history
+    pub fn format_details(&self) -> String {
+        let mut content = format!(
+            "Job #{}\nSubreddit: r/{}\nTitle: {}\nPublish at: {}\nStatus: {} ({} attempt(s))\n",
+            self.id,
+            self.subreddit,
+            self.title,
+            self.local_publish_time(),
+            self.status,
+            self.attempts
+        );
+        if let Some(url) = &self.post_url {
+            content.push_str(&format!("URL: {}\n", url));
+        }
+
+        content.push_str("\nHistory:\n");
+        for change in &self.history {
+            content.push_str(&format!(
+                "  {}  {}",
+                format_in_timezone(change.at, &self.timezone),
+                change.status
+            ));
+            if let Some(message) = &change.message {
+                content.push_str(&format!(": {}", message));
+            }
+            content.push('\n');
+        }
+
+        content.push_str("\nText:\n---------\n");
+        content.push_str(&self.text);
+        content.push_str("\n---------\n");
+        content
+    }
+}
+
+/// The queue of scheduled posts, as stored on disk
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ScheduleStore {
+    next_id: u64,
+    pub jobs: Vec<ScheduledPost>,
+}
+
+impl ScheduleStore {
+    /// The default queue file, `~/.redrust/schedule.json`
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push(".redrust");
+        path.push("schedule.json");
+        path
+    }
+
+    /// Read the queue, or start an empty one if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self, RedditClientError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path).map_err(|e| {
+            RedditClientError::ApiError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Write the queue, replacing the previous file atomically
+    fn save(&self, path: &Path) -> Result<(), RedditClientError> {
+        let json = serde_json::to_string_pretty(self)?;
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, json)
+            .and_then(|_| fs::rename(&temp_path, path))
+            .map_err(|e| {
+                RedditClientError::ApiError(format!("Failed to write {}: {}", path.display(), e))
+            })
+    }
+
+    /// Load the queue, apply a change and save it, holding the lock file throughout
+    pub fn update<T>(
+        path: &Path,
+        change: impl FnOnce(&mut ScheduleStore) -> Result<T, RedditClientError>,
+    ) -> Result<T, RedditClientError> {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent).ok();
+            }
+        }
+
+        let _lock = StoreLock::acquire(path)?;
+        let mut store = Self::load(path)?;
+        let result = change(&mut store)?;
+        store.save(path)?;
+
+        Ok(result)
+    }
+
+    /// `update` on a blocking thread, for callers on the async runtime
+    pub async fn update_async<T, F>(path: &Path, change: F) -> Result<T, RedditClientError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut ScheduleStore) -> Result<T, RedditClientError> + Send + 'static,
+    {
+        let path = path.to_path_buf();
+        tokio::task::spawn_blocking(move || Self::update(&path, change))
+            .await
+            .map_err(|e| {
+                RedditClientError::ApiError(format!("Schedule queue update failed: {}", e))
+            })?
+    }
+
+    /// Add a new pending job and return it
+    pub fn add(
+        &mut self,
+        subreddit: &str,
+        title: &str,
+        text: &str,
+        publish_at: DateTime<Utc>,
+        timezone: &str,
+    ) -> &ScheduledPost {
+        self.next_id += 1;
+        let mut job = ScheduledPost {
+            id: self.next_id,
+            subreddit: subreddit.trim_start_matches("r/").to_string(),
+            title: title.to_string(),
+            text: text.to_string(),
+            publish_at,
+            timezone: timezone.to_string(),
+            status: JobStatus::Pending,
+            attempts: 0,
+            next_attempt_at: publish_at,
+            post_url: None,
+            claim: None,
+            history: Vec::new(),
+        };
+        job.set_status(JobStatus::Pending, None);
+
+        self.jobs.push(job);
+        self.jobs.last().unwrap()
+    }
+
+    /// Find a job by ID
+    pub fn get_mut(&mut self, id: u64) -> Result<&mut ScheduledPost, RedditClientError> {
+        self.jobs
+            .iter_mut()
+            .find(|job| job.id == id)
+            .ok_or_else(|| RedditClientError::ApiError(format!("No scheduled post #{}", id)))
+    }
+
+    /// Cancel a job that hasn't been published yet
+    pub fn cancel(&mut self, id: u64) -> Result<&ScheduledPost, RedditClientError> {
+        let job = self.get_mut(id)?;
+        if !job.status.is_waiting() {
+            return Err(RedditClientError::ApiError(format!(
+                "Scheduled post #{} is {} and can't be cancelled",
+                id, job.status
+            )));
+        }
+
+        job.set_status(JobStatus::Cancelled, None);
+        Ok(job)
+    }
+
+    /// Claim the jobs due at `now` for the daemon `daemon`, so other daemons skip them
+    pub fn claim_due(&mut self, now: DateTime<Utc>, daemon: &str) -> Vec<ScheduledPost> {
+        let mut claimed = Vec::new();
+        for job in &mut self.jobs {
+            if job.status.is_waiting() && job.next_attempt_at <= now {
+                job.attempts += 1;
+                job.set_status(
+                    JobStatus::Publishing,
+                    Some(format!("attempt {}", job.attempts)),
+                );
+                job.claim = Some(Claim {
+                    daemon: daemon.to_string(),
+                    heartbeat_at: now,
+                });
+                claimed.push(job.clone());
+            }
+        }
+        claimed
+    }
+
+    /// Refresh the heartbeat on every job `daemon` is still publishing
+    pub fn heartbeat(&mut self, now: DateTime<Utc>, daemon: &str) {
+        for job in &mut self.jobs {
+            if let Some(claim) = job.claim.as_mut().filter(|claim| claim.daemon == daemon) {
+                claim.heartbeat_at = now;
+            }
+        }
+    }
+
+    /// Return jobs left in `publishing` by a daemon that stopped mid-publish to the queue.
+    ///
+    /// Jobs whose daemon refreshed its heartbeat recently are left alone.
+    pub fn recover_interrupted(&mut self, now: DateTime<Utc>) -> usize {
+        let stale_before = now - Duration::seconds(STALE_CLAIM_SECS);
+        let mut recovered = 0;
+        for job in &mut self.jobs {
+            let stale = job
+                .claim
+                .as_ref()
+                .is_none_or(|claim| claim.heartbeat_at < stale_before);
+            if job.status == JobStatus::Publishing && stale {
+                job.set_status(
+                    JobStatus::Retrying,
+                    Some(
+                        "interrupted while publishing; check the subreddit for a duplicate"
+                            .to_string(),
+                    ),
+                );
+                recovered += 1;
+            }
+        }
+        recovered
+    }
+
+    /// When the next waiting job is due, if any
+    pub fn next_due(&self) -> Option<DateTime<Utc>> {
+        self.jobs
+            .iter()
+            .filter(|job| job.status.is_waiting())
+            .map(|job| job.next_attempt_at)
+            .min()
+    }
+}
+
+/// A lock file next to the queue, removed when dropped
+struct StoreLock {
+    path: PathBuf,
+}
+
+impl StoreLock {
+    /// Create the lock file, waiting for another process to remove it.
+    /// Blocks the thread, so async callers use `ScheduleStore::update_async`.
+    fn acquire(store_path: &Path) -> Result<Self, RedditClientError> {
+        let path = store_path.with_extension("json.lock");
+        let deadline =
+            std::time::Instant::now() + std::time::Duration::from_secs(LOCK_TIMEOUT_SECS);
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Self { path }),
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                    if std::time::Instant::now() >= deadline {
+                        return Err(RedditClientError::ApiError(format!(
+                            "Timed out waiting for {}; remove it if no other redrust process is running",
+                            path.display()
+                        )));
+                    }
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                }
+                Err(err) => {
+                    return Err(RedditClientError::ApiError(format!(
+                        "Failed to create {}: {}",
+                        path.display(),
+                        err
+                    )))
+                }
+            }
+        }
+    }
+}
+
+impl Drop for StoreLock {
+    fn drop(&mut self) {
+        if let Err(err) = fs::remove_file(&self.path) {
+            debug!("Failed to remove {}: {}", self.path.display(), err);
+        }
+    }
+}
+
+/// Parse a publish time such as "2026-11-01T09:00" in the given timezone.
+///
+/// `timezone` is an IANA name like "Europe/Berlin", or `None` for local time.
+/// Times with an explicit offset ("2026-11-01T09:00:00+01:00") ignore the timezone.
+pub fn parse_publish_time(
+    at: &str,
+    timezone: Option<&str>,
+) -> Result<DateTime<Utc>, RedditClientError> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(at) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    let naive = [
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%d %H:%M:%S",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(at, format).ok())
+    .ok_or_else(|| {
+        RedditClientError::ApiError(format!(
+            "Invalid time '{}', expected e.g. 2026-11-01T09:00",
+            at
+        ))
+    })?;
+
+    // Times in a DST gap don't exist; ambiguous times in the overlap use the earlier one
+    let resolved = match timezone {
+        Some(name) => parse_timezone(name)?
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|time| time.with_timezone(&Utc)),
+        None => chrono::Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|time| time.with_timezone(&Utc)),
+    };
+
+    resolved.ok_or_else(|| {
+        RedditClientError::ApiError(format!(
+            "{} doesn't exist in {} (skipped by a daylight saving change)",
+            at,
+            timezone.unwrap_or("local time")
+        ))
+    })
+}
+
+/// Parse an IANA timezone name such as "Europe/Berlin"
+pub fn parse_timezone(name: &str) -> Result<Tz, RedditClientError> {
+    name.parse::<Tz>().map_err(|_| {
+        RedditClientError::ApiError(format!(
+            "Unknown timezone '{}', expected an IANA name like Europe/Berlin",
+            name
+        ))
+    })
+}
+
+/// Format a time in a named timezone, falling back to local time
+fn format_in_timezone(time: DateTime<Utc>, timezone: &str) -> String {
+    match timezone.parse::<Tz>() {
+        Ok(tz) => time
+            .with_timezone(&tz)
+            .format("%Y-%m-%d %H:%M %Z")
+            .to_string(),
+        Err(_) => time
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M %Z")
+            .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A queue with two jobs due at `now` and one due in an hour
+    fn store(now: DateTime<Utc>) -> ScheduleStore {
+        let mut store = ScheduleStore::default();
+        store.add("rust", "First", "Body", now - Duration::minutes(5), "UTC");
+        store.add("r/rust", "Second", "Body", now, "UTC");
+        store.add("rust", "Later", "Body", now + Duration::hours(1), "UTC");
+        store
+    }
+
+    fn status(store: &ScheduleStore, id: u64) -> JobStatus {
+        store.jobs.iter().find(|job| job.id == id).unwrap().status
+    }
+
+    #[test]
+    fn claiming_is_exclusive() {
+        let now = Utc::now();
+        let mut store = store(now);
+
+        let claimed = store.claim_due(now, "daemon-a");
+        assert_eq!(claimed.iter().map(|job| job.id).collect::<Vec<_>>(), [1, 2]);
+        for job in &claimed {
+            assert_eq!(job.status, JobStatus::Publishing);
+            assert_eq!(job.attempts, 1);
+            assert_eq!(job.claim.as_ref().unwrap().daemon, "daemon-a");
+        }
+        assert_eq!(status(&store, 3), JobStatus::Pending);
+
+        assert!(store.claim_due(now, "daemon-b").is_empty());
+    }
+
+    #[test]
+    fn stale_claims_are_recovered_and_fresh_ones_are_not() {
+        let now = Utc::now();
+        let mut store = store(now);
+        store.claim_due(now, "stopped");
+        store
+            .get_mut(1)
+            .unwrap()
+            .claim
+            .as_mut()
+            .unwrap()
+            .heartbeat_at = now - Duration::minutes(10);
+        store.get_mut(2).unwrap().claim.as_mut().unwrap().daemon = "running".to_string();
+        store.heartbeat(now - Duration::minutes(1), "running");
+
+        assert_eq!(store.recover_interrupted(now), 1);
+        assert_eq!(status(&store, 1), JobStatus::Retrying);
+        assert!(store.get_mut(1).unwrap().claim.is_none());
+        assert_eq!(status(&store, 2), JobStatus::Publishing);
+
+        // The recovered job can be claimed again; the one still publishing can't
+        let reclaimed = store.claim_due(now, "daemon-b");
+        assert_eq!(reclaimed.iter().map(|job| job.id).collect::<Vec<_>>(), [1]);
+        assert_eq!(reclaimed[0].attempts, 2);
+    }
+
+    #[test]
+    fn heartbeat_keeps_a_long_publish_claimed() {
+        let now = Utc::now();
+        let mut store = store(now);
+        store.claim_due(now, "daemon-a");
+
+        let later = now + Duration::seconds(STALE_CLAIM_SECS + 60);
+        store.heartbeat(later - Duration::seconds(HEARTBEAT_SECS), "daemon-a");
+        // Another daemon's heartbeat doesn't refresh these claims
+        store.heartbeat(later, "daemon-b");
+
+        assert_eq!(store.recover_interrupted(later), 0);
+        assert_eq!(
+            store.recover_interrupted(later + Duration::seconds(STALE_CLAIM_SECS)),
+            2
+        );
+    }
+
+    #[test]
+    fn publishing_without_a_claim_is_recovered() {
+        // Queues written before claims existed have none
+        let now = Utc::now();
+        let mut store = store(now);
+        store.claim_due(now, "daemon-a");
+        store.get_mut(1).unwrap().claim = None;
+
+        assert_eq!(store.recover_interrupted(now), 1);
+        assert_eq!(status(&store, 1), JobStatus::Retrying);
+        assert_eq!(status(&store, 2), JobStatus::Publishing);
+    }
+
+    #[test]
+    fn finishing_a_job_releases_its_claim() {
+        let now = Utc::now();
+        let mut store = store(now);
+        store.claim_due(now, "daemon-a");
+
+        store
+            .get_mut(1)
+            .unwrap()
+            .mark_published("https://redd.it/abc".to_string());
+        store
+            .get_mut(2)
+            .unwrap()
+            .mark_failed("RATELIMIT".to_string(), 3);
+
+        for id in [1, 2] {
+            assert!(store.get_mut(id).unwrap().claim.is_none());
+        }
+        assert_eq!(status(&store, 1), JobStatus::Published);
+        assert_eq!(status(&store, 2), JobStatus::Retrying);
+        assert!(store.get_mut(2).unwrap().next_attempt_at > now);
+    }
+}
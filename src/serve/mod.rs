@@ -0,0 +1,622 @@
+//! Local REST gateway to Reddit.
+//!
+//! `redrust serve` lets other programs on this machine read listings and the
+//! inbox, submit, comment and vote through one shared client, so they don't
+//! each need Reddit credentials or their own rate limiting.
+//!
+//! Every request except `GET /health` needs the gateway's API key, either as
+//! `Authorization: Bearer <key>` or as an `X-Api-Key` header. Request and
+//! response bodies are JSON, except for feeds:
+//!
+//! | Method | Path | Body or query | Response |
+//! |--------|------|---------------|----------|
+//! | GET | `/health` | | `{"status": "ok"}` |
+//! | GET | `/r/{subreddit}/new` | `limit`, `after` | `{"subreddit", "after", "posts": [...]}` |
+//! | GET | `/inbox` | `folder` (inbox, unread, sent, mentions), `limit`, `after` | `{"folder", "after", "items": [...]}` |
+//! | POST | `/submit` | `{"subreddit", "title", "text"}` | `{"url"}` |
+//! | POST | `/comment` | `{"parent", "text"}` | `{"result"}` |
+//! | POST | `/vote` | `{"id", "direction"}` (up, down, clear) | `{"id", "direction"}` |
+//! | GET | `/feed/r/{subreddit}` | `format` (atom, rss), `limit` | Atom or RSS document |
+//! | GET | `/feed/user/{username}` | `format`, `limit` | Atom or RSS document |
+//! | GET | `/feed/search` | `q`, `subreddit`, `format`, `limit` | Atom or RSS document |
+//!
+//! Feed readers usually can't send headers, so the feed routes also take the
+//! key as a `key` query parameter.
+//!
+//! Errors are `{"error": "..."}` with a 4xx status for bad requests and 502
+//! when Reddit refuses. Each write is appended to the audit log as a JSON
+//! line, whether it succeeded or not.
+
+use crate::client::listing::ListingParams;
+use crate::client::{InboxFolder, RedditClient, RedditClientError, VoteDirection};
+use crate::feed::{render, FeedFormat, FeedSource};
+use chrono::{DateTime, Utc};
+use log::{debug, error, info};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs::OpenOptions;
+use std::future::Future;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tiny_http::{Header, Server};
+
+/// Largest request body the gateway reads
+const MAX_BODY_BYTES: u64 = 1024 * 1024;
+
+/// Listing size when the caller doesn't ask for one
+const DEFAULT_LIMIT: u32 = 25;
+
+/// An HTTP request, as the gateway sees it
+#[derive(Debug, Clone, Default)]
+pub struct ApiRequest {
+    pub method: String,
+    /// Path without the query string
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    /// Header names are lowercase
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    /// Address of the caller, for the audit log
+    pub remote: Option<String>,
+}
+
+impl ApiRequest {
+    fn query(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The API key sent as a Bearer token or in `X-Api-Key`, or for feeds in `?key=`
+    fn api_key(&self) -> Option<&str> {
+        self.header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .or_else(|| self.header("x-api-key"))
+            .or_else(|| {
+                self.path
+                    .trim_start_matches('/')
+                    .starts_with("feed/")
+                    .then(|| self.query("key"))
+                    .flatten()
+            })
+    }
+}
+
+/// A response from the gateway
+#[derive(Debug, Clone)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl ApiResponse {
+    fn json(status: u16, body: &Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: body.to_string(),
+        }
+    }
+
+    fn ok(body: &impl Serialize) -> Self {
+        match serde_json::to_value(body) {
+            Ok(body) => Self::json(200, &body),
+            Err(err) => Self::error(500, &format!("Failed to serialize response: {}", err)),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, &json!({ "error": message }))
+    }
+
+    /// Reddit or the client failed; the caller's request itself was fine
+    fn upstream(err: &RedditClientError) -> Self {
+        Self::error(502, &err.to_string())
+    }
+}
+
+/// One line of the audit log
+#[derive(Debug, Serialize)]
+struct AuditEntry<'a> {
+    time: DateTime<Utc>,
+    client: Option<&'a str>,
+    action: &'a str,
+    request: &'a Value,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<&'a Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Append-only JSON lines log of writes made through the gateway
+#[derive(Debug)]
+struct AuditLog {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl AuditLog {
+    /// Open the log, creating it and its directory if needed
+    fn open(path: PathBuf) -> Result<Self, RedditClientError> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| audit_error(&path, e))?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| audit_error(&path, e))?;
+
+        Ok(Self {
+            path,
+            lock: Mutex::new(()),
+        })
+    }
+
+    fn record(&self, entry: &AuditEntry) {
+        let _guard = self.lock.lock().unwrap();
+        let written = serde_json::to_string(entry)
+            .map_err(std::io::Error::other)
+            .and_then(|line| {
+                let mut file = OpenOptions::new().append(true).open(&self.path)?;
+                writeln!(file, "{}", line)
+            });
+        if let Err(err) = written {
+            error!(
+                "Failed to write {} to the audit log {}: {}",
+                entry.action,
+                self.path.display(),
+                err
+            );
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SubmitRequest {
+    subreddit: String,
+    title: String,
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommentRequest {
+    parent: String,
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct VoteRequest {
+    id: String,
+    #[serde(deserialize_with = "vote_direction")]
+    direction: VoteDirection,
+}
+
+fn vote_direction<'de, D>(deserializer: D) -> Result<VoteDirection, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+/// Routes requests to one shared Reddit client
+pub struct Gateway {
+    client: tokio::sync::Mutex<RedditClient>,
+    api_key: String,
+    audit: AuditLog,
+}
+
+impl Gateway {
+    /// Create a gateway; fails if the audit log can't be opened
+    pub fn new(
+        client: RedditClient,
+        api_key: String,
+        audit_log: PathBuf,
+    ) -> Result<Self, RedditClientError> {
+        Ok(Self {
+            client: tokio::sync::Mutex::new(client.bypassing_cache()),
+            api_key,
+            audit: AuditLog::open(audit_log)?,
+        })
+    }
+
+    /// Answer one request
+    pub async fn handle(&self, request: &ApiRequest) -> ApiResponse {
+        let segments: Vec<&str> = request
+            .path
+            .trim_matches('/')
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        if let ("GET", ["health"]) = (request.method.as_str(), segments.as_slice()) {
+            return ApiResponse::json(200, &json!({ "status": "ok" }));
+        }
+        if !request
+            .api_key()
+            .is_some_and(|key| keys_match(key, &self.api_key))
+        {
+            return ApiResponse::error(401, "Missing or wrong API key");
+        }
+
+        match (request.method.as_str(), segments.as_slice()) {
+            ("GET", ["r", subreddit, "new"]) => self.new_posts(subreddit, request).await,
+            ("GET", ["inbox"]) => self.inbox(request).await,
+            ("GET", ["feed", "r", subreddit]) => {
+                self.feed(FeedSource::Subreddit(subreddit.to_string()), request)
+                    .await
+            }
+            ("GET", ["feed", "user", username]) => {
+                self.feed(FeedSource::User(username.to_string()), request)
+                    .await
+            }
+            ("GET", ["feed", "search"]) => match request.query("q") {
+                Some(query) if !query.is_empty() => {
+                    let source = FeedSource::Search {
+                        query: query.to_string(),
+                        subreddit: request.query("subreddit").map(str::to_string),
+                    };
+                    self.feed(source, request).await
+                }
+                _ => ApiResponse::error(400, "q is required"),
+            },
+            ("POST", ["submit"]) => self.write("submit", request, Self::submit).await,
+            ("POST", ["comment"]) => self.write("comment", request, Self::comment).await,
+            ("POST", ["vote"]) => self.write("vote", request, Self::vote).await,
+            (
+                _,
+                ["health"]
+                | ["r", _, "new"]
+                | ["inbox"]
+                | ["feed", "r" | "user", _]
+                | ["feed", "search"]
+                | ["submit"]
+                | ["comment"]
+                | ["vote"],
+            ) => ApiResponse::error(405, "Method not allowed"),
+            _ => ApiResponse::error(404, "Not found"),
+        }
+    }
+
+    /// The shared client, with a fresh access token if it has tokens at all
+    async fn client(&self) -> Result<RedditClient, RedditClientError> {
+        let mut client = self.client.lock().await;
+        let has_tokens = client.access_token.is_some()
+            || client
+                .token_storage
+                .as_ref()
+                .is_some_and(|storage| storage.has_refresh_token());
+        if has_tokens {
+            client.ensure_access_token().await?;
+        }
+        Ok(client.clone())
+    }
+
+    async fn new_posts(&self, subreddit: &str, request: &ApiRequest) -> ApiResponse {
+        let limit = match parse_limit(request) {
+            Ok(limit) => limit,
+            Err(response) => return response,
+        };
+
+        let result = match self.client().await {
+            Ok(client) => {
+                client
+                    .fetch_new_posts_after(subreddit, limit as i32, request.query("after"))
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(listing) => {
+                let posts: Vec<_> = listing
+                    .data
+                    .children
+                    .into_iter()
+                    .map(|entity| entity.data)
+                    .collect();
+                ApiResponse::ok(&json!({
+                    "subreddit": subreddit,
+                    "after": listing.data.after,
+                    "posts": posts,
+                }))
+            }
+            Err(err) => ApiResponse::upstream(&err),
+        }
+    }
+
+    async fn inbox(&self, request: &ApiRequest) -> ApiResponse {
+        let folder = match request
+            .query("folder")
+            .unwrap_or("inbox")
+            .parse::<InboxFolder>()
+        {
+            Ok(folder) => folder,
+            Err(err) => return ApiResponse::error(400, &err),
+        };
+        let mut params = match parse_limit(request) {
+            Ok(limit) => ListingParams::with_limit(limit),
+            Err(response) => return response,
+        };
+        params.after = request.query("after").map(str::to_string);
+
+        let result = match self.client().await {
+            Ok(client) => client.fetch_inbox(folder, &params).await,
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(listing) => ApiResponse::ok(&json!({
+                "folder": folder.as_str(),
+                "after": listing.data.after,
+                "items": listing.data.children,
+            })),
+            Err(err) => ApiResponse::upstream(&err),
+        }
+    }
+
+    async fn feed(&self, source: FeedSource, request: &ApiRequest) -> ApiResponse {
+        let format = match request.query("format").map(str::parse::<FeedFormat>) {
+            None => FeedFormat::default(),
+            Some(Ok(format)) => format,
+            Some(Err(err)) => return ApiResponse::error(400, &err),
+        };
+        let limit = match parse_limit(request) {
+            Ok(limit) => limit,
+            Err(response) => return response,
+        };
+
+        let result = match self.client().await {
+            Ok(client) => source.fetch(&client, limit).await,
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(posts) => ApiResponse {
+                status: 200,
+                content_type: format.content_type(),
+                body: render(&source, &posts, format),
+            },
+            Err(err) => ApiResponse::upstream(&err),
+        }
+    }
+
+    /// Run a write and record it in the audit log
+    async fn write<'a, T, F, Fut>(
+        &'a self,
+        action: &str,
+        request: &ApiRequest,
+        perform: F,
+    ) -> ApiResponse
+    where
+        T: DeserializeOwned,
+        F: FnOnce(&'a Self, T) -> Fut,
+        Fut: Future<Output = Result<Value, RedditClientError>>,
+    {
+        let body: Value = match serde_json::from_str(&request.body) {
+            Ok(body) => body,
+            Err(err) => return ApiResponse::error(400, &format!("Invalid JSON body: {}", err)),
+        };
+        let parsed: T = match serde_json::from_value(body.clone()) {
+            Ok(parsed) => parsed,
+            Err(err) => return ApiResponse::error(400, &format!("Invalid {}: {}", action, err)),
+        };
+
+        let outcome = perform(self, parsed).await;
+        self.audit.record(&AuditEntry {
+            time: Utc::now(),
+            client: request.remote.as_deref(),
+            action,
+            request: &body,
+            ok: outcome.is_ok(),
+            result: outcome.as_ref().ok(),
+            error: outcome.as_ref().err().map(|err| err.to_string()),
+        });
+
+        match outcome {
+            Ok(result) => {
+                info!("{} through the gateway: {}", action, result);
+                ApiResponse::ok(&result)
+            }
+            Err(err) => ApiResponse::upstream(&err),
+        }
+    }
+
+    async fn submit(&self, request: SubmitRequest) -> Result<Value, RedditClientError> {
+        let url = self
+            .client()
+            .await?
+            .create_post(&request.subreddit, &request.title, &request.text)
+            .await?;
+        Ok(json!({ "url": url }))
+    }
+
+    async fn comment(&self, request: CommentRequest) -> Result<Value, RedditClientError> {
+        let result = self
+            .client()
+            .await?
+            .create_comment(&request.parent, &request.text)
+            .await?;
+        Ok(json!({ "result": result }))
+    }
+
+    async fn vote(&self, request: VoteRequest) -> Result<Value, RedditClientError> {
+        self.client()
+            .await?
+            .vote(&request.id, request.direction)
+            .await?;
+        Ok(json!({ "id": request.id, "direction": request.direction.to_string() }))
+    }
+}
+
+/// Serve the gateway on `bind` until `shutdown` completes
+pub async fn serve(
+    gateway: Arc<Gateway>,
+    bind: &str,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), RedditClientError> {
+    let server = Arc::new(Server::http(bind).map_err(|e| {
+        RedditClientError::ApiError(format!("Failed to listen on {}: {}", bind, e))
+    })?);
+
+    // tiny_http blocks, so accept connections on a thread and answer them on tokio.
+    // Bodies are read per request, so a slow client only holds up its own.
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(64);
+    let listener = {
+        let server = Arc::clone(&server);
+        std::thread::spawn(move || {
+            for request in server.incoming_requests() {
+                if sender.blocking_send(request).is_err() {
+                    break;
+                }
+            }
+        })
+    };
+
+    tokio::pin!(shutdown);
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => {
+                info!("Shutdown requested, stopping the gateway");
+                break;
+            }
+            received = receiver.recv() => {
+                let Some(request) = received else { break };
+                let gateway = Arc::clone(&gateway);
+                tokio::spawn(async move {
+                    let read = tokio::task::spawn_blocking(move || {
+                        let mut request = request;
+                        let api_request = read_request(&mut request);
+                        (api_request, request)
+                    })
+                    .await;
+                    let Ok((api_request, request)) = read else { return };
+
+                    let response = match &api_request {
+                        Ok(api_request) => gateway.handle(api_request).await,
+                        Err(response) => response.clone(),
+                    };
+                    debug!(
+                        "{} {} -> {}",
+                        request.method(),
+                        request.url(),
+                        response.status
+                    );
+                    let _ = tokio::task::spawn_blocking(move || respond(request, response)).await;
+                });
+            }
+        }
+    }
+
+    server.unblock();
+    let _ = listener.join();
+    Ok(())
+}
+
+/// Read a request and its body, which blocks until the client has sent it
+fn read_request(request: &mut tiny_http::Request) -> Result<ApiRequest, ApiResponse> {
+    let too_large = || {
+        ApiResponse::error(
+            413,
+            &format!("Request body is larger than {} bytes", MAX_BODY_BYTES),
+        )
+    };
+    if request
+        .body_length()
+        .is_some_and(|length| length as u64 > MAX_BODY_BYTES)
+    {
+        return Err(too_large());
+    }
+
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+    let mut body = Vec::new();
+    // One byte more than allowed tells a body at the limit from one over it
+    if let Err(err) = request
+        .as_reader()
+        .take(MAX_BODY_BYTES + 1)
+        .read_to_end(&mut body)
+    {
+        debug!("Failed to read request body: {}", err);
+        return Err(ApiResponse::error(400, "Failed to read the request body"));
+    }
+    if body.len() as u64 > MAX_BODY_BYTES {
+        return Err(too_large());
+    }
+    let body = String::from_utf8(body)
+        .map_err(|_| ApiResponse::error(400, "Request body is not valid UTF-8"))?;
+
+    Ok(ApiRequest {
+        method: request.method().as_str().to_uppercase(),
+        path: path.to_string(),
+        query: url::form_urlencoded::parse(query.as_bytes())
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect(),
+        headers: request
+            .headers()
+            .iter()
+            .map(|header| {
+                (
+                    header.field.as_str().as_str().to_lowercase(),
+                    header.value.as_str().to_string(),
+                )
+            })
+            .collect(),
+        body,
+        remote: request.remote_addr().map(|addr| addr.to_string()),
+    })
+}
+
+fn respond(request: tiny_http::Request, response: ApiResponse) {
+    let content_type = Header::from_bytes("Content-Type", response.content_type)
+        .expect("content types are valid header values");
+    let reply = tiny_http::Response::from_string(response.body)
+        .with_status_code(response.status)
+        .with_header(content_type);
+    if let Err(err) = request.respond(reply) {
+        debug!("Failed to send response: {}", err);
+    }
+}
+
+fn parse_limit(request: &ApiRequest) -> Result<u32, ApiResponse> {
+    match request.query("limit") {
+        None => Ok(DEFAULT_LIMIT),
+        Some(limit) => match limit.parse::<u32>() {
+            Ok(limit) if (1..=100).contains(&limit) => Ok(limit),
+            _ => Err(ApiResponse::error(
+                400,
+                "limit must be a number from 1 to 100",
+            )),
+        },
+    }
+}
+
+/// Compare API keys without stopping at the first difference
+fn keys_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn audit_error(path: &Path, err: std::io::Error) -> RedditClientError {
+    RedditClientError::ApiError(format!(
+        "Failed to open the audit log {}: {}",
+        path.display(),
+        err
+    ))
+}
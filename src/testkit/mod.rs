@@ -0,0 +1,810 @@
+//! A fake Reddit server for integration tests, enabled with the `testkit` feature.
+//!
+//! `FakeReddit::start` runs an HTTP server on a local port that implements
+//! just enough of Reddit for bots built on this crate: the OAuth token
+//! endpoint, submitting posts and comments, subreddit `new` listings and
+//! comment threads. State lives in memory and can be seeded and inspected
+//! from the test, and errors can be scripted for the next request to an
+//! endpoint.
+//!
+//! ```no_run
+//! use redrust::testkit::{Endpoint, FakeReddit, ScriptedError};
+//!
+//! # async fn example() -> Result<(), redrust::RedditClientError> {
+//! let reddit = FakeReddit::start();
+//! reddit.add_post("rust", "Existing post", "Hello");
+//!
+//! let client = reddit.client();
+//! let url = client.create_post("rust", "New post", "Body").await?;
+//! assert_eq!(reddit.posts().len(), 2);
+//!
+//! reddit.fail_next(Endpoint::Submit, ScriptedError::RateLimit);
+//! assert!(client.create_post("rust", "Too soon", "Body").await.is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::{RedditClient, TokenStorage};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use tiny_http::{Header, Method, Request, Response, Server};
+use url::Url;
+
+/// The account the fake server treats every valid token as
+pub const FAKE_USERNAME: &str = "testkit_user";
+
+/// Client ID used by clients from `FakeReddit::client`
+pub const FAKE_CLIENT_ID: &str = "testkit";
+
+/// An endpoint of the fake server, for scripting errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endpoint {
+    /// `POST /api/v1/access_token`
+    AccessToken,
+    /// `POST /api/submit`
+    Submit,
+    /// `POST /api/comment`
+    Comment,
+    /// `GET /r/{sub}/new.json`
+    New,
+    /// `GET /comments/{id}`
+    Comments,
+}
+
+/// An error the fake server returns instead of handling a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptedError {
+    /// HTTP 429 with rate limit headers saying no requests are left
+    TooManyRequests,
+    /// Reddit's USER_REQUIRED form error; HTTP 403 on endpoints that aren't form submissions
+    UserRequired,
+    /// Reddit's RATELIMIT form error ("you are doing that too much");
+    /// HTTP 429 on endpoints that aren't form submissions
+    RateLimit,
+}
+
+/// A post stored by the fake server
+#[derive(Debug, Clone)]
+pub struct FakePost {
+    pub id: String,
+    pub subreddit: String,
+    pub title: String,
+    pub author: String,
+    pub selftext: String,
+    /// Link target; `None` for text posts
+    pub url: Option<String>,
+    pub score: i32,
+    pub num_comments: i32,
+    pub created_utc: f64,
+}
+
+impl FakePost {
+    pub fn fullname(&self) -> String {
+        format!("t3_{}", self.id)
+    }
+
+    fn permalink(&self) -> String {
+        format!(
+            "/r/{}/comments/{}/{}/",
+            self.subreddit,
+            self.id,
+            slug(&self.title)
+        )
+    }
+}
+
+/// A comment stored by the fake server
+#[derive(Debug, Clone)]
+pub struct FakeComment {
+    pub id: String,
+    /// Fullname of the post the comment is in
+    pub link_id: String,
+    /// Fullname of the post or comment replied to
+    pub parent_id: String,
+    pub author: String,
+    pub body: String,
+    pub score: i32,
+    pub created_utc: f64,
+}
+
+impl FakeComment {
+    pub fn fullname(&self) -> String {
+        format!("t1_{}", self.id)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    posts: Vec<FakePost>,
+    comments: Vec<FakeComment>,
+    failures: Vec<(Endpoint, ScriptedError)>,
+    tokens: HashSet<String>,
+    requests: Vec<String>,
+    next_id: u64,
+}
+
+impl State {
+    /// Reddit IDs are base 36; start high enough that they look like real ones
+    fn next_id(&mut self) -> String {
+        self.next_id += 1;
+        let mut n = self.next_id + 36u64.pow(5);
+        let mut id = Vec::new();
+        while n > 0 {
+            id.push(std::char::from_digit((n % 36) as u32, 36).unwrap());
+            n /= 36;
+        }
+        id.iter().rev().collect()
+    }
+
+    fn issue_token(&mut self) -> String {
+        let token = format!("testkit-token-{}", self.tokens.len() + 1);
+        self.tokens.insert(token.clone());
+        token
+    }
+
+    fn take_failure(&mut self, endpoint: Endpoint) -> Option<ScriptedError> {
+        let index = self
+            .failures
+            .iter()
+            .position(|(failing, _)| *failing == endpoint)?;
+        Some(self.failures.remove(index).1)
+    }
+
+    fn add_post(&mut self, post: FakePost) -> String {
+        let fullname = post.fullname();
+        self.posts.push(post);
+        fullname
+    }
+}
+
+/// An in-process fake Reddit server; stops when dropped
+pub struct FakeReddit {
+    url: Url,
+    state: Arc<Mutex<State>>,
+    server: Arc<Server>,
+    handle: Option<JoinHandle<()>>,
+    /// Where clients from `client` save refreshed tokens; removed on drop
+    token_dir: PathBuf,
+}
+
+impl FakeReddit {
+    /// Start a server on a free local port.
+    ///
+    /// Panics if no port can be bound, since there is nothing a test can do about it.
+    pub fn start() -> Self {
+        let server =
+            Arc::new(Server::http("127.0.0.1:0").expect("failed to start the fake Reddit server"));
+        let port = server
+            .server_addr()
+            .to_ip()
+            .expect("fake Reddit server is not listening on TCP")
+            .port();
+        let url = Url::parse(&format!("http://127.0.0.1:{}", port)).unwrap();
+        let token_dir =
+            std::env::temp_dir().join(format!("redrust-testkit-{}-{}", std::process::id(), port));
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let handle = {
+            let server = Arc::clone(&server);
+            let state = Arc::clone(&state);
+            std::thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle_request(&state, request);
+                }
+            })
+        };
+
+        Self {
+            url,
+            state,
+            server,
+            handle: Some(handle),
+            token_dir,
+        }
+    }
+
+    /// Where the server is listening
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// A client pointed at this server and logged in as `FAKE_USERNAME`.
+    ///
+    /// Its token is valid for an hour. Refreshing it works, and saves the new
+    /// token in a temporary directory that is removed with the server, never
+    /// in ~/.redrust.
+    pub fn client(&self) -> RedditClient {
+        let token = self.state.lock().unwrap().issue_token();
+        let mut storage = TokenStorage::new(FAKE_CLIENT_ID);
+        storage.access_token = Some(token.clone());
+        storage.refresh_token = Some("testkit-refresh-token".to_string());
+        storage.token_expires_at = Some(chrono::Utc::now().timestamp() as u64 + 3600);
+
+        let mut client = self
+            .anonymous_client()
+            .with_token_dir(self.token_dir.clone());
+        client.access_token = Some(token);
+        client.token_storage = Some(storage);
+        client
+    }
+
+    /// A client pointed at this server without any tokens
+    pub fn anonymous_client(&self) -> RedditClient {
+        RedditClient::new().with_base_url(self.url.clone())
+    }
+
+    /// Store a text post by `FAKE_USERNAME` and return its fullname
+    pub fn add_post(&self, subreddit: &str, title: &str, selftext: &str) -> String {
+        let mut state = self.state.lock().unwrap();
+        let post = FakePost {
+            id: state.next_id(),
+            subreddit: subreddit.trim_start_matches("r/").to_string(),
+            title: title.to_string(),
+            author: FAKE_USERNAME.to_string(),
+            selftext: selftext.to_string(),
+            url: None,
+            score: 1,
+            num_comments: 0,
+            created_utc: now(),
+        };
+        state.add_post(post)
+    }
+
+    /// Store a comment by `FAKE_USERNAME` and return its fullname.
+    ///
+    /// Returns `None` if the parent post or comment doesn't exist.
+    pub fn add_comment(&self, parent_id: &str, body: &str) -> Option<String> {
+        let mut state = self.state.lock().unwrap();
+        create_comment(&mut state, parent_id, body).map(|comment| comment.fullname())
+    }
+
+    /// Answer the next request to `endpoint` with `error`.
+    ///
+    /// Errors are queued, so scripting the same endpoint twice fails its next two requests.
+    pub fn fail_next(&self, endpoint: Endpoint, error: ScriptedError) {
+        self.state.lock().unwrap().failures.push((endpoint, error));
+    }
+
+    /// Posts in the order they were created
+    pub fn posts(&self) -> Vec<FakePost> {
+        self.state.lock().unwrap().posts.clone()
+    }
+
+    /// Comments in the order they were created
+    pub fn comments(&self) -> Vec<FakeComment> {
+        self.state.lock().unwrap().comments.clone()
+    }
+
+    /// Every request received, as "METHOD /path?query"
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for FakeReddit {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        let _ = std::fs::remove_dir_all(&self.token_dir);
+    }
+}
+
+/// What a handler answers with
+struct Reply {
+    status: u16,
+    headers: Vec<(&'static str, String)>,
+    body: Value,
+}
+
+impl Reply {
+    fn json(body: Value) -> Self {
+        Self::status(200, body)
+    }
+
+    fn status(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// Reddit's JSON error body for an HTTP error
+    fn http_error(status: u16, message: &str) -> Self {
+        Self::status(status, json!({ "message": message, "error": status }))
+    }
+}
+
+/// The parts of a request the handlers look at
+struct Incoming {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    form: Vec<(String, String)>,
+    bearer: Option<String>,
+}
+
+impl Incoming {
+    fn query(&self, key: &str) -> Option<&str> {
+        find(&self.query, key)
+    }
+
+    fn form(&self, key: &str) -> Option<&str> {
+        find(&self.form, key)
+    }
+
+    /// Whether the caller asked for `api_type=json` errors rather than jQuery ones
+    fn wants_json(&self) -> bool {
+        self.form("api_type") == Some("json")
+    }
+}
+
+fn find<'a>(pairs: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    pairs
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.as_str())
+}
+
+fn handle_request(state: &Mutex<State>, mut request: Request) {
+    let url = Url::parse(&format!("http://localhost{}", request.url()))
+        .expect("request paths always form a valid URL");
+    let mut body = String::new();
+    let _ = request.as_reader().read_to_string(&mut body);
+
+    let incoming = Incoming {
+        method: request.method().clone(),
+        path: url.path().trim_end_matches(".json").to_string(),
+        query: url
+            .query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect(),
+        form: url::form_urlencoded::parse(body.as_bytes())
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect(),
+        bearer: request
+            .headers()
+            .iter()
+            .find(|header| header.field.equiv("Authorization"))
+            .and_then(|header| header.value.as_str().strip_prefix("Bearer "))
+            .map(str::to_string),
+    };
+
+    let reply = {
+        let mut state = state.lock().unwrap();
+        state
+            .requests
+            .push(format!("{} {}", incoming.method, request.url()));
+        route(&mut state, &incoming)
+    };
+
+    let mut response = Response::from_string(reply.body.to_string()).with_status_code(reply.status);
+    response.add_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+    for (name, value) in reply.headers {
+        response.add_header(Header::from_bytes(name, value).unwrap());
+    }
+    let _ = request.respond(response);
+}
+
+fn route(state: &mut State, incoming: &Incoming) -> Reply {
+    let segments: Vec<&str> = incoming.path.trim_matches('/').split('/').collect();
+    let endpoint = match (&incoming.method, segments.as_slice()) {
+        (Method::Post, ["api", "v1", "access_token"]) => Endpoint::AccessToken,
+        (Method::Post, ["api", "submit"]) => Endpoint::Submit,
+        (Method::Post, ["api", "comment"]) => Endpoint::Comment,
+        (Method::Get, ["r", _, "new"]) => Endpoint::New,
+        (Method::Get, ["comments", _, ..]) => Endpoint::Comments,
+        _ => return Reply::http_error(404, "Not Found"),
+    };
+
+    if let Some(error) = state.take_failure(endpoint) {
+        return scripted_error(endpoint, error, incoming);
+    }
+
+    // Everything except the token endpoint needs a token we issued, if one is sent,
+    // and writes need one at all
+    let authorized = incoming
+        .bearer
+        .as_ref()
+        .is_some_and(|token| state.tokens.contains(token));
+    let is_write = matches!(endpoint, Endpoint::Submit | Endpoint::Comment);
+    if endpoint != Endpoint::AccessToken && (incoming.bearer.is_some() || is_write) && !authorized {
+        return Reply::http_error(401, "Unauthorized");
+    }
+
+    match endpoint {
+        Endpoint::AccessToken => access_token(state, incoming),
+        Endpoint::Submit => submit(state, incoming),
+        Endpoint::Comment => comment(state, incoming),
+        Endpoint::New => new_listing(state, incoming, segments[1]),
+        Endpoint::Comments => comment_thread(state, segments[1]),
+    }
+}
+
+fn scripted_error(endpoint: Endpoint, error: ScriptedError, incoming: &Incoming) -> Reply {
+    let is_form = matches!(endpoint, Endpoint::Submit | Endpoint::Comment);
+    match (error, is_form) {
+        (ScriptedError::UserRequired, true) => form_error(
+            incoming,
+            "USER_REQUIRED",
+            "Please log in to do that.",
+            None,
+        ),
+        (ScriptedError::RateLimit, true) => form_error(
+            incoming,
+            "RATELIMIT",
+            "Looks like you've been doing that a lot. Take a break for 5 minutes before trying again.",
+            Some("ratelimit"),
+        ),
+        (ScriptedError::UserRequired, false) => Reply::http_error(403, "Forbidden"),
+        (ScriptedError::TooManyRequests, _) | (ScriptedError::RateLimit, false) => {
+            let mut reply = Reply::http_error(429, "Too Many Requests");
+            reply.headers = vec![
+                ("x-ratelimit-remaining", "0".to_string()),
+                ("x-ratelimit-used", "100".to_string()),
+                ("x-ratelimit-reset", "1".to_string()),
+            ];
+            reply
+        }
+    }
+}
+
+/// A form error in the format the caller asked for: `api_type=json` or jQuery
+fn form_error(incoming: &Incoming, code: &str, message: &str, field: Option<&str>) -> Reply {
+    if incoming.wants_json() {
+        return Reply::json(json!({ "json": { "errors": [[code, message, field]] } }));
+    }
+
+    let selector = match field {
+        Some(field) => format!(".error.{}.field-{}", code, field),
+        None => format!(".error.{}", code),
+    };
+    Reply::json(json!({
+        "jquery": [
+            [0, 1, "call", ["body"]],
+            [1, 2, "attr", "find"],
+            [2, 3, "call", [selector]],
+            [3, 4, "attr", "text"],
+            [4, 5, "call", [message]],
+        ],
+        "success": false,
+    }))
+}
+
+fn access_token(state: &mut State, incoming: &Incoming) -> Reply {
+    let refresh_token = match incoming.form("grant_type") {
+        // User grants hand out a refresh token along with the access token
+        Some("authorization_code") | Some("password") => Some("testkit-refresh-token"),
+        Some("refresh_token")
+        | Some("client_credentials")
+        | Some("https://oauth.reddit.com/grants/installed_client") => None,
+        _ => return Reply::status(400, json!({ "error": "unsupported_grant_type" })),
+    };
+
+    let mut body = json!({
+        "access_token": state.issue_token(),
+        "token_type": "bearer",
+        "expires_in": 3600,
+        "scope": "*",
+    });
+    if let Some(refresh_token) = refresh_token {
+        body["refresh_token"] = json!(refresh_token);
+    }
+    Reply::json(body)
+}
+
+fn submit(state: &mut State, incoming: &Incoming) -> Reply {
+    let subreddit = match incoming.form("sr").filter(|sr| !sr.is_empty()) {
+        Some(sr) => sr.trim_start_matches("r/").to_string(),
+        None => {
+            return form_error(
+                incoming,
+                "NO_SR_NAME",
+                "please enter a subreddit",
+                Some("sr"),
+            )
+        }
+    };
+    let title = match incoming.form("title").filter(|title| !title.is_empty()) {
+        Some(title) => title.to_string(),
+        None => return form_error(incoming, "NO_TEXT", "we need something here", Some("title")),
+    };
+
+    let post = FakePost {
+        id: state.next_id(),
+        subreddit,
+        title,
+        author: FAKE_USERNAME.to_string(),
+        selftext: incoming.form("text").unwrap_or_default().to_string(),
+        url: (incoming.form("kind") == Some("link"))
+            .then(|| incoming.form("url").unwrap_or_default().to_string()),
+        score: 1,
+        num_comments: 0,
+        created_utc: now(),
+    };
+    let data = json!({
+        "url": format!("https://www.reddit.com{}", post.permalink()),
+        "drafts_count": 0,
+        "id": post.id,
+        "name": post.fullname(),
+    });
+    state.add_post(post);
+
+    Reply::json(json!({ "json": { "errors": [], "data": data } }))
+}
+
+fn comment(state: &mut State, incoming: &Incoming) -> Reply {
+    let parent_id = incoming.form("thing_id").unwrap_or_default();
+    let text = incoming.form("text").unwrap_or_default();
+    if text.is_empty() {
+        return form_error(incoming, "NO_TEXT", "we need something here", Some("text"));
+    }
+
+    match create_comment(state, parent_id, text) {
+        Some(comment) => {
+            let data = comment_json(&comment, state);
+            Reply::json(json!({
+                "json": {
+                    "errors": [],
+                    "data": { "things": [{ "kind": "t1", "data": data }] },
+                }
+            }))
+        }
+        None => form_error(
+            incoming,
+            "NO_THING_ID",
+            "that comment or post doesn't exist",
+            Some("parent"),
+        ),
+    }
+}
+
+/// Store a reply to a post or comment, counting it on the post
+fn create_comment(state: &mut State, parent_id: &str, body: &str) -> Option<FakeComment> {
+    let link_id = if parent_id.starts_with("t3_") {
+        state
+            .posts
+            .iter()
+            .any(|post| post.fullname() == parent_id)
+            .then(|| parent_id.to_string())?
+    } else {
+        state
+            .comments
+            .iter()
+            .find(|comment| comment.fullname() == parent_id)
+            .map(|comment| comment.link_id.clone())?
+    };
+
+    let comment = FakeComment {
+        id: state.next_id(),
+        link_id: link_id.clone(),
+        parent_id: parent_id.to_string(),
+        author: FAKE_USERNAME.to_string(),
+        body: body.to_string(),
+        score: 1,
+        created_utc: now(),
+    };
+    if let Some(post) = state
+        .posts
+        .iter_mut()
+        .find(|post| post.fullname() == link_id)
+    {
+        post.num_comments += 1;
+    }
+    state.comments.push(comment.clone());
+    Some(comment)
+}
+
+fn new_listing(state: &State, incoming: &Incoming, subreddit: &str) -> Reply {
+    let limit = incoming
+        .query("limit")
+        .and_then(|limit| limit.parse::<usize>().ok())
+        .unwrap_or(25)
+        .clamp(1, 100);
+
+    // Newest first, picking up after the `after` fullname
+    let mut posts = state
+        .posts
+        .iter()
+        .rev()
+        .filter(|post| post.subreddit.eq_ignore_ascii_case(subreddit))
+        .peekable();
+    if let Some(after) = incoming.query("after") {
+        for post in posts.by_ref() {
+            if post.fullname() == after {
+                break;
+            }
+        }
+    }
+    let page: Vec<&FakePost> = posts.by_ref().take(limit).collect();
+    let after = match posts.peek() {
+        Some(_) => page.last().map(|post| post.fullname()),
+        None => None,
+    };
+
+    Reply::json(listing(
+        page.iter()
+            .map(|post| json!({ "kind": "t3", "data": post_json(post) }))
+            .collect(),
+        after,
+    ))
+}
+
+fn comment_thread(state: &State, id: &str) -> Reply {
+    let fullname = format!("t3_{}", id);
+    let Some(post) = state.posts.iter().find(|post| post.fullname() == fullname) else {
+        return Reply::http_error(404, "Not Found");
+    };
+
+    Reply::json(json!([
+        listing(vec![json!({ "kind": "t3", "data": post_json(post) })], None),
+        listing(replies_to(state, &fullname), None),
+    ]))
+}
+
+/// The comment tree under a post or comment, oldest first like Reddit's "old" sort
+fn replies_to(state: &State, parent_id: &str) -> Vec<Value> {
+    state
+        .comments
+        .iter()
+        .filter(|comment| comment.parent_id == parent_id)
+        .map(|comment| json!({ "kind": "t1", "data": comment_json(comment, state) }))
+        .collect()
+}
+
+fn listing(children: Vec<Value>, after: Option<String>) -> Value {
+    json!({
+        "kind": "Listing",
+        "data": {
+            "after": after,
+            "dist": children.len(),
+            "modhash": null,
+            "geo_filter": null,
+            "children": children,
+            "before": null,
+        }
+    })
+}
+
+/// A post with every field `RedditPostData` requires
+fn post_json(post: &FakePost) -> Value {
+    let permalink = post.permalink();
+    let is_self = post.url.is_none();
+    // Split in two to stay under json!'s recursion limit
+    let mut data = json!({
+        "id": post.id,
+        "name": post.fullname(),
+        "title": post.title,
+        "author": post.author,
+        "author_fullname": format!("t2_{}", post.author),
+        "permalink": permalink,
+        "url": post.url.clone().unwrap_or_else(|| format!("https://www.reddit.com{}", permalink)),
+        "domain": if is_self { format!("self.{}", post.subreddit) } else { domain(post.url.as_deref()) },
+        "created_utc": post.created_utc,
+        "is_self": is_self,
+        "selftext": post.selftext,
+        "selftext_html": (!post.selftext.is_empty()).then(|| format!(
+            "<!-- SC_OFF --><div class=\"md\"><p>{}</p></div><!-- SC_ON -->",
+            escape_html(&post.selftext)
+        )),
+        "is_video": false,
+        "is_original_content": false,
+        "is_reddit_media_domain": false,
+        "is_meta": false,
+        "is_crosspostable": true,
+        "thumbnail": if is_self { "self" } else { "default" },
+        "thumbnail_width": null,
+        "thumbnail_height": null,
+        "secure_media": null,
+        "secure_media_embed": {},
+        "media": null,
+        "media_embed": {},
+    });
+    let rest = json!({
+        "score": post.score,
+        "upvote_ratio": 1.0,
+        "ups": post.score,
+        "downs": 0,
+        "num_comments": post.num_comments,
+        "num_crossposts": 0,
+        "total_awards_received": 0,
+        "subreddit": post.subreddit,
+        "subreddit_id": format!("t5_{}", post.subreddit.to_lowercase()),
+        "subreddit_subscribers": 1,
+        "subreddit_type": "public",
+        "subreddit_name_prefixed": format!("r/{}", post.subreddit),
+        "archived": false,
+        "locked": false,
+        "hidden": false,
+        "removed_by_category": null,
+        "removed_by": null,
+        "stickied": false,
+        "pinned": false,
+        "spoiler": false,
+        "over_18": false,
+        "num_reports": null,
+        "user_reports": [],
+        "mod_reports": [],
+        "hide_score": false,
+        "contest_mode": false,
+        "edited": false,
+        "distinguished": null,
+        "link_flair_text": null,
+        "link_flair_type": "text",
+        "link_flair_background_color": null,
+        "link_flair_text_color": null,
+        "author_flair_text": null,
+        "author_flair_type": "text",
+        "author_flair_background_color": null,
+        "author_flair_text_color": null,
+        "likes": null,
+    });
+    if let (Some(data), Value::Object(rest)) = (data.as_object_mut(), rest) {
+        data.extend(rest);
+    }
+    data
+}
+
+/// A comment and, recursively, its replies
+fn comment_json(comment: &FakeComment, state: &State) -> Value {
+    let post = state
+        .posts
+        .iter()
+        .find(|post| post.fullname() == comment.link_id);
+    let replies = replies_to(state, &comment.fullname());
+    json!({
+        "id": comment.id,
+        "name": comment.fullname(),
+        "author": comment.author,
+        "author_fullname": format!("t2_{}", comment.author),
+        "body": comment.body,
+        "body_html": format!("<div class=\"md\"><p>{}</p></div>", escape_html(&comment.body)),
+        "created_utc": comment.created_utc,
+        "permalink": post.map(|post| format!("{}{}/", post.permalink(), comment.id)).unwrap_or_default(),
+        "link_id": comment.link_id,
+        "parent_id": comment.parent_id,
+        "subreddit": post.map(|post| post.subreddit.clone()).unwrap_or_default(),
+        "subreddit_name_prefixed": post.map(|post| format!("r/{}", post.subreddit)).unwrap_or_default(),
+        "score": comment.score,
+        "edited": false,
+        "likes": null,
+        "replies": if replies.is_empty() { json!("") } else { listing(replies, None) },
+    })
+}
+
+fn domain(url: Option<&str>) -> String {
+    url.and_then(|url| Url::parse(url).ok())
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// The title part of a permalink, as Reddit builds it
+fn slug(title: &str) -> String {
+    let slug: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    slug.split('_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+        .chars()
+        .take(50)
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn now() -> f64 {
+    chrono::Utc::now().timestamp() as f64
+}
@@ -0,0 +1,512 @@
+//! Interactive terminal UI for browsing subreddits and threads
+//!
+//! `redrust tui` shows a subreddit's new posts with the same [T]/[V]/[I]/[G]/[L]
+//! type indicators as `posts --brief`, opens a post's comment tree, votes on
+//! the selected post or comment, and posts replies from a compose pane.
+//!
+//! Voting and replying use the stored OAuth tokens from `browser-create`;
+//! without them the UI is read-only.
+
+mod ui;
+
+use crate::client::{RedditClient, RedditClientError, VoteDirection};
+use crate::models::comment::{CommentData, CommentThread};
+use crate::models::RedditPostData;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::widgets::ListState;
+use ratatui::DefaultTerminal;
+use std::collections::HashMap;
+
+/// Which screen is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum View {
+    /// The subreddit's post list
+    Posts,
+    /// A post and its comment tree
+    Thread,
+}
+
+/// Text being typed in the compose pane or the subreddit prompt
+#[derive(Debug, Clone)]
+enum Input {
+    /// A reply to the post or comment with this fullname
+    Reply {
+        parent: String,
+        label: String,
+        text: String,
+    },
+    /// A new subreddit to browse
+    Subreddit(String),
+}
+
+/// A row of the thread view: the post itself, then each comment with its depth
+#[derive(Debug, Clone, Copy)]
+enum ThreadRow<'a> {
+    Post(&'a RedditPostData),
+    Comment(usize, &'a CommentData),
+}
+
+impl ThreadRow<'_> {
+    fn fullname(&self) -> String {
+        match self {
+            ThreadRow::Post(post) => format!("t3_{}", post.id),
+            ThreadRow::Comment(_, comment) => comment.name.clone(),
+        }
+    }
+
+    fn author(&self) -> &str {
+        match self {
+            ThreadRow::Post(post) => &post.author,
+            ThreadRow::Comment(_, comment) => &comment.author,
+        }
+    }
+
+    fn likes(&self) -> Option<bool> {
+        match self {
+            ThreadRow::Post(post) => likes(&post.additional_fields),
+            ThreadRow::Comment(_, comment) => likes(&comment.additional_fields),
+        }
+    }
+}
+
+/// State of the terminal UI
+struct App {
+    client: RedditClient,
+    /// Subreddit being browsed; `None` for the public frontpage
+    subreddit: Option<String>,
+    count: i32,
+    /// Whether stored tokens allow voting and replying
+    logged_in: bool,
+    view: View,
+    posts: Vec<RedditPostData>,
+    post_state: ListState,
+    thread: Option<CommentThread>,
+    thread_state: ListState,
+    /// Votes cast in this session, by fullname
+    votes: HashMap<String, VoteDirection>,
+    input: Option<Input>,
+    status: String,
+    quit: bool,
+}
+
+/// Run the terminal UI until the user quits
+///
+/// # Arguments
+/// * `client` - Client with stored tokens loaded, e.g. from `AppConfig::create_client`
+/// * `subreddit` - Subreddit to open, or `None` for the public frontpage
+/// * `count` - Number of posts to load
+pub async fn run(
+    client: RedditClient,
+    subreddit: Option<String>,
+    count: i32,
+) -> Result<(), RedditClientError> {
+    // Reloading a listing or thread should show what changed, not the cached copy
+    let mut client = client.bypassing_cache();
+    // A token refresh here is what lets us vote and reply; browsing works either way
+    let logged_in = client.ensure_access_token().await.is_ok();
+
+    let mut app = App {
+        client,
+        subreddit,
+        count,
+        logged_in,
+        view: View::Posts,
+        posts: Vec::new(),
+        post_state: ListState::default(),
+        thread: None,
+        thread_state: ListState::default(),
+        votes: HashMap::new(),
+        input: None,
+        status: String::new(),
+        quit: false,
+    };
+
+    // Log lines on stderr would draw over the UI
+    let log_level = log::max_level();
+    log::set_max_level(log::LevelFilter::Off);
+
+    let mut terminal = ratatui::init();
+    let result = app.run(&mut terminal).await;
+    ratatui::restore();
+
+    log::set_max_level(log_level);
+    result
+}
+
+impl App {
+    async fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<(), RedditClientError> {
+        self.load_posts(terminal).await?;
+
+        while !self.quit {
+            self.draw(terminal)?;
+            if let Event::Key(key) = event::read().map_err(terminal_error)? {
+                if key.kind == KeyEventKind::Press {
+                    self.handle_key(key, terminal).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, terminal: &mut DefaultTerminal) -> Result<(), RedditClientError> {
+        terminal
+            .draw(|frame| ui::draw(frame, self))
+            .map_err(terminal_error)?;
+        Ok(())
+    }
+
+    /// Show a status message straight away, before a slow request
+    fn show_status(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        status: String,
+    ) -> Result<(), RedditClientError> {
+        self.status = status;
+        self.draw(terminal)
+    }
+
+    fn location(&self) -> String {
+        match &self.subreddit {
+            Some(subreddit) => format!("r/{}", subreddit.trim_start_matches("r/")),
+            None => "the frontpage".to_string(),
+        }
+    }
+
+    async fn load_posts(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), RedditClientError> {
+        self.show_status(terminal, format!("Loading {}...", self.location()))?;
+
+        let response = match &self.subreddit {
+            Some(subreddit) => self.client.fetch_new_posts(subreddit, self.count).await,
+            None => self.client.fetch_public_new_posts(self.count).await,
+        };
+        match response {
+            Ok(response) => {
+                self.posts = response
+                    .data
+                    .children
+                    .into_iter()
+                    .map(|entity| entity.data)
+                    .collect();
+                self.post_state
+                    .select(if self.posts.is_empty() { None } else { Some(0) });
+                self.status = format!("{} posts from {}", self.posts.len(), self.location());
+            }
+            Err(err) => self.status = format!("Failed to load {}: {}", self.location(), err),
+        }
+        Ok(())
+    }
+
+    async fn open_thread(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), RedditClientError> {
+        let Some(post) = self.post_state.selected().and_then(|i| self.posts.get(i)) else {
+            return Ok(());
+        };
+        let post_id = post.id.clone();
+        let keep_selection = self.view == View::Thread;
+        self.show_status(terminal, "Loading comments...".to_string())?;
+
+        match self.client.fetch_comments(&post_id, None, None).await {
+            Ok(thread) => {
+                self.status = format!("{} comments", thread.post.num_comments);
+                self.thread = Some(thread);
+                self.view = View::Thread;
+                let rows = self.thread_rows().len();
+                let selected = if keep_selection {
+                    self.thread_state.selected().unwrap_or(0).min(rows - 1)
+                } else {
+                    0
+                };
+                self.thread_state.select(Some(selected));
+            }
+            Err(err) => self.status = format!("Failed to load comments: {}", err),
+        }
+        Ok(())
+    }
+
+    /// The rows of the thread view
+    fn thread_rows(&self) -> Vec<ThreadRow<'_>> {
+        let Some(thread) = &self.thread else {
+            return Vec::new();
+        };
+        let mut rows = vec![ThreadRow::Post(&thread.post)];
+        rows.extend(
+            thread
+                .flatten()
+                .into_iter()
+                .map(|(depth, comment)| ThreadRow::Comment(depth, comment)),
+        );
+        rows
+    }
+
+    /// Fullname and author of the selected post or comment
+    fn selected_target(&self) -> Option<(String, String, Option<bool>)> {
+        match self.view {
+            View::Posts => self
+                .post_state
+                .selected()
+                .and_then(|i| self.posts.get(i))
+                .map(|post| {
+                    (
+                        format!("t3_{}", post.id),
+                        post.author.clone(),
+                        likes(&post.additional_fields),
+                    )
+                }),
+            View::Thread => {
+                let selected = self.thread_state.selected()?;
+                self.thread_rows()
+                    .get(selected)
+                    .map(|row| (row.fullname(), row.author().to_string(), row.likes()))
+            }
+        }
+    }
+
+    /// Vote on the selected item; voting the same way again clears the vote
+    async fn vote(
+        &mut self,
+        direction: VoteDirection,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), RedditClientError> {
+        if !self.logged_in {
+            self.status =
+                "Voting needs stored tokens; authenticate with browser-create first".to_string();
+            return Ok(());
+        }
+        let Some((fullname, _, likes)) = self.selected_target() else {
+            return Ok(());
+        };
+
+        let direction = if self.current_vote(&fullname, likes) == Some(direction) {
+            VoteDirection::Clear
+        } else {
+            direction
+        };
+        self.show_status(terminal, format!("Voting {} on {}...", direction, fullname))?;
+
+        let result = match self.client.ensure_access_token().await {
+            Ok(_) => self.client.vote(&fullname, direction).await,
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(()) => {
+                self.status = format!("Voted {} on {}", direction, fullname);
+                self.votes.insert(fullname, direction);
+            }
+            Err(err) => self.status = format!("Vote failed: {}", err),
+        }
+        Ok(())
+    }
+
+    /// Open the compose pane for a reply to the selected item
+    fn start_reply(&mut self) {
+        if !self.logged_in {
+            self.status =
+                "Replying needs stored tokens; authenticate with browser-create first".to_string();
+            return;
+        }
+        if let Some((parent, author, _)) = self.selected_target() {
+            self.input = Some(Input::Reply {
+                label: format!("Reply to u/{} ({})", author, parent),
+                parent,
+                text: String::new(),
+            });
+        }
+    }
+
+    async fn send_reply(
+        &mut self,
+        parent: String,
+        text: String,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), RedditClientError> {
+        if text.trim().is_empty() {
+            self.status = "Reply is empty; nothing sent".to_string();
+            return Ok(());
+        }
+        self.show_status(terminal, format!("Replying to {}...", parent))?;
+
+        let result = match self.client.ensure_access_token().await {
+            Ok(_) => self.client.create_comment(&parent, &text).await,
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(url) => {
+                if self.view == View::Thread {
+                    self.open_thread(terminal).await?;
+                }
+                self.status = format!("Reply posted: {}", url);
+            }
+            Err(err) => {
+                // Keep the draft so it isn't lost
+                self.input = Some(Input::Reply {
+                    label: format!("Reply to {} (failed, Ctrl-S to retry)", parent),
+                    parent,
+                    text,
+                });
+                self.status = format!("Reply failed: {}", err);
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), RedditClientError> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return Ok(());
+        }
+
+        if let Some(input) = self.input.take() {
+            return self.handle_input_key(input, key, terminal).await;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::PageDown => self.move_selection(10),
+            KeyCode::PageUp => self.move_selection(-10),
+            KeyCode::Char('u') => self.vote(VoteDirection::Up, terminal).await?,
+            KeyCode::Char('d') => self.vote(VoteDirection::Down, terminal).await?,
+            KeyCode::Char('c') => self.start_reply(),
+            KeyCode::Char('r') => match self.view {
+                View::Posts => self.load_posts(terminal).await?,
+                View::Thread => self.open_thread(terminal).await?,
+            },
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') if self.view == View::Posts => {
+                self.open_thread(terminal).await?
+            }
+            KeyCode::Esc | KeyCode::Left | KeyCode::Char('h') if self.view == View::Thread => {
+                self.view = View::Posts;
+                self.thread = None;
+                self.status.clear();
+            }
+            KeyCode::Char('/') if self.view == View::Posts => {
+                self.input = Some(Input::Subreddit(String::new()));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_input_key(
+        &mut self,
+        input: Input,
+        key: KeyEvent,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), RedditClientError> {
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        match input {
+            Input::Reply {
+                parent,
+                label,
+                mut text,
+            } => match key.code {
+                KeyCode::Esc => self.status = "Reply discarded".to_string(),
+                KeyCode::Char('s') if control => self.send_reply(parent, text, terminal).await?,
+                code => {
+                    match code {
+                        KeyCode::Enter => text.push('\n'),
+                        KeyCode::Backspace => {
+                            text.pop();
+                        }
+                        KeyCode::Char(c) if !control => text.push(c),
+                        _ => {}
+                    }
+                    self.input = Some(Input::Reply {
+                        parent,
+                        label,
+                        text,
+                    });
+                }
+            },
+            Input::Subreddit(mut name) => match key.code {
+                KeyCode::Esc => {}
+                KeyCode::Enter => {
+                    let name = name.trim().trim_start_matches("r/").to_string();
+                    self.subreddit = if name.is_empty() { None } else { Some(name) };
+                    self.load_posts(terminal).await?;
+                }
+                code => {
+                    match code {
+                        KeyCode::Backspace => {
+                            name.pop();
+                        }
+                        KeyCode::Char(c) if !control && !c.is_whitespace() => name.push(c),
+                        _ => {}
+                    }
+                    self.input = Some(Input::Subreddit(name));
+                }
+            },
+        }
+        Ok(())
+    }
+
+    fn move_selection(&mut self, by: isize) {
+        let (len, state) = match self.view {
+            View::Posts => (self.posts.len(), &mut self.post_state),
+            View::Thread => {
+                let len = self.thread_rows().len();
+                (len, &mut self.thread_state)
+            }
+        };
+        if len == 0 {
+            return;
+        }
+        let selected = state.selected().unwrap_or(0) as isize + by;
+        state.select(Some(selected.clamp(0, len as isize - 1) as usize));
+    }
+
+    /// The score of an item, adjusted for a vote cast in this session
+    fn score(&self, fullname: &str, score: i32, likes: Option<bool>) -> i32 {
+        let before = match likes {
+            Some(true) => 1,
+            Some(false) => -1,
+            None => 0,
+        };
+        match self.votes.get(fullname) {
+            Some(VoteDirection::Up) => score - before + 1,
+            Some(VoteDirection::Down) => score - before - 1,
+            Some(VoteDirection::Clear) => score - before,
+            None => score,
+        }
+    }
+
+    /// The vote on an item: one cast in this session, otherwise Reddit's `likes`
+    fn current_vote(&self, fullname: &str, likes: Option<bool>) -> Option<VoteDirection> {
+        self.votes.get(fullname).copied().or(match likes {
+            Some(true) => Some(VoteDirection::Up),
+            Some(false) => Some(VoteDirection::Down),
+            None => None,
+        })
+    }
+
+    /// The vote marker for an item: ▲, ▼ or a space
+    fn vote_marker(&self, fullname: &str, likes: Option<bool>) -> &'static str {
+        match self.current_vote(fullname, likes) {
+            Some(VoteDirection::Up) => "▲",
+            Some(VoteDirection::Down) => "▼",
+            _ => " ",
+        }
+    }
+}
+
+/// Reddit's `likes` field: `true` for an upvote, `false` for a downvote
+fn likes(additional_fields: &HashMap<String, serde_json::Value>) -> Option<bool> {
+    additional_fields
+        .get("likes")
+        .and_then(|value| value.as_bool())
+}
+
+fn terminal_error(err: std::io::Error) -> RedditClientError {
+    RedditClientError::ApiError(format!("Terminal error: {}", err))
+}
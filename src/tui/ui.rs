@@ -0,0 +1,205 @@
+use super::{likes, App, Input, ThreadRow, View};
+use crate::output::time::time_display;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+
+const HIGHLIGHT: Style = Style::new()
+    .bg(Color::DarkGray)
+    .add_modifier(Modifier::BOLD);
+
+/// Draw the whole screen
+pub(super) fn draw(frame: &mut Frame, app: &mut App) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(3),
+        Constraint::Length(2),
+    ])
+    .areas(frame.area());
+
+    let account = if app.logged_in {
+        "logged in".green()
+    } else {
+        "read-only (authenticate with browser-create to vote and reply)".yellow()
+    };
+    frame.render_widget(
+        Line::from(vec![
+            format!(" redrust · {} · ", app.location()).bold(),
+            account,
+        ]),
+        header,
+    );
+
+    match app.view {
+        View::Posts => draw_posts(frame, app, body),
+        View::Thread => draw_thread(frame, app, body),
+    }
+
+    let help = match (&app.input, app.view) {
+        (Some(Input::Reply { .. }), _) => "Ctrl-S send · Enter newline · Esc discard",
+        (Some(Input::Subreddit(_)), _) => "Enter open · Esc cancel (empty for the frontpage)",
+        (None, View::Posts) => {
+            "j/k move · Enter open · u/d vote · c reply · / subreddit · r reload · q quit"
+        }
+        (None, View::Thread) => "j/k move · u/d vote · c reply · r reload · Esc back · q quit",
+    };
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(app.status.clone()),
+            help.dark_gray().into(),
+        ]),
+        footer,
+    );
+}
+
+fn draw_posts(frame: &mut Frame, app: &mut App, area: Rect) {
+    let (list_area, input_area) = match app.input {
+        Some(Input::Subreddit(_)) => {
+            let [list, prompt] =
+                Layout::vertical([Constraint::Min(1), Constraint::Length(3)]).areas(area);
+            (list, Some(prompt))
+        }
+        Some(Input::Reply { .. }) => {
+            let [list, compose] =
+                Layout::vertical([Constraint::Percentage(55), Constraint::Percentage(45)])
+                    .areas(area);
+            (list, Some(compose))
+        }
+        None => (area, None),
+    };
+
+    let display = time_display();
+    let items: Vec<ListItem> = app
+        .posts
+        .iter()
+        .map(|post| {
+            let fullname = format!("t3_{}", post.id);
+            let likes = likes(&post.additional_fields);
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", app.vote_marker(&fullname, likes))),
+                format!("[{}] ", post.type_indicator()).cyan(),
+                format!("[{}] ", display.format_timestamp(post.created_utc, "%H:%M")).dark_gray(),
+                Span::raw(post.title.clone()),
+                format!(
+                    "  {} pts · {} comments · u/{} · r/{}",
+                    app.score(&fullname, post.score, likes),
+                    post.num_comments,
+                    post.author,
+                    post.subreddit
+                )
+                .dark_gray(),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" New posts "))
+        .highlight_style(HIGHLIGHT);
+    frame.render_stateful_widget(list, list_area, &mut app.post_state);
+
+    if let Some(area) = input_area {
+        draw_input(frame, app, area);
+    }
+}
+
+fn draw_thread(frame: &mut Frame, app: &mut App, area: Rect) {
+    // The bottom pane shows the selected item in full, or the reply being written
+    let [tree_area, detail_area] =
+        Layout::vertical([Constraint::Percentage(55), Constraint::Percentage(45)]).areas(area);
+
+    let rows = app.thread_rows();
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| {
+            let fullname = row.fullname();
+            match row {
+                ThreadRow::Post(post) => {
+                    let likes = likes(&post.additional_fields);
+                    ListItem::new(Line::from(vec![
+                        Span::raw(format!("{} ", app.vote_marker(&fullname, likes))),
+                        format!("[{}] ", post.type_indicator()).cyan(),
+                        post.title.clone().bold(),
+                        format!(
+                            "  {} pts · u/{}",
+                            app.score(&fullname, post.score, likes),
+                            post.author
+                        )
+                        .dark_gray(),
+                    ]))
+                }
+                ThreadRow::Comment(depth, comment) => {
+                    let likes = likes(&comment.additional_fields);
+                    ListItem::new(Line::from(vec![
+                        Span::raw(format!("{} ", app.vote_marker(&fullname, likes))),
+                        Span::raw("  ".repeat(*depth)),
+                        format!("u/{} ", comment.author).yellow(),
+                        format!("{} pts ", app.score(&fullname, comment.score, likes)).dark_gray(),
+                        Span::raw(comment.excerpt(200)),
+                    ]))
+                }
+            }
+        })
+        .collect();
+
+    let more = app.thread.as_ref().map_or(0, |thread| thread.more);
+    let title = if more > 0 {
+        format!(" Comments ({} more not loaded) ", more)
+    } else {
+        " Comments ".to_string()
+    };
+    let detail = match app.thread_state.selected().and_then(|i| rows.get(i)) {
+        Some(ThreadRow::Post(post)) => {
+            let mut text = Text::from(post.title.clone().bold());
+            text.push_line(format!("u/{} · r/{}", post.author, post.subreddit).dark_gray());
+            text.push_line("");
+            if post.is_self {
+                text.extend(Text::raw(post.selftext.clone()));
+            } else {
+                text.push_line(post.url.clone());
+            }
+            text
+        }
+        Some(ThreadRow::Comment(_, comment)) => {
+            let mut text = Text::from(format!("u/{}", comment.author).yellow());
+            text.push_line("");
+            text.extend(Text::raw(comment.body.clone()));
+            text
+        }
+        None => Text::default(),
+    };
+    drop(rows);
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(HIGHLIGHT);
+    frame.render_stateful_widget(list, tree_area, &mut app.thread_state);
+
+    if app.input.is_some() {
+        draw_input(frame, app, detail_area);
+    } else {
+        frame.render_widget(
+            Paragraph::new(detail)
+                .wrap(Wrap { trim: false })
+                .block(Block::default().borders(Borders::ALL)),
+            detail_area,
+        );
+    }
+}
+
+/// Draw the compose pane or subreddit prompt, with a cursor after the text
+fn draw_input(frame: &mut Frame, app: &App, area: Rect) {
+    let (title, text) = match &app.input {
+        Some(Input::Reply { label, text, .. }) => (format!(" {} ", label), text.as_str()),
+        Some(Input::Subreddit(name)) => (" Subreddit ".to_string(), name.as_str()),
+        None => return,
+    };
+
+    frame.render_widget(
+        Paragraph::new(format!("{}█", text))
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title(title)),
+        area,
+    );
+}
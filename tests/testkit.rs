@@ -0,0 +1,103 @@
+//! The fake Reddit server, driven through the public client API.
+#![cfg(feature = "testkit")]
+
+use redrust::testkit::{Endpoint, FakeReddit, ScriptedError, FAKE_USERNAME};
+use redrust::RedditClientError;
+
+fn api_error(result: Result<impl std::fmt::Debug, RedditClientError>) -> String {
+    match result {
+        Err(RedditClientError::ApiError(msg)) => msg,
+        other => panic!("expected an API error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn submit_shows_up_in_new_listing() {
+    let reddit = FakeReddit::start();
+    reddit.add_post("rust", "Seeded post", "Hello");
+    let client = reddit.client();
+
+    let url = client
+        .create_post("r/rust", "Submitted post", "Body")
+        .await
+        .unwrap();
+    assert!(url.contains("/r/rust/comments/"), "{}", url);
+
+    let listing = client.fetch_new_posts("rust", 10).await.unwrap();
+    let titles: Vec<_> = listing
+        .data
+        .children
+        .iter()
+        .map(|post| post.data.title.as_str())
+        .collect();
+    assert_eq!(titles, ["Submitted post", "Seeded post"]);
+    assert_eq!(listing.data.children[0].data.author, FAKE_USERNAME);
+    assert_eq!(reddit.posts().len(), 2);
+}
+
+#[tokio::test]
+async fn comment_on_post() {
+    let reddit = FakeReddit::start();
+    let post = reddit.add_post("rust", "Thread", "");
+    let client = reddit.client();
+
+    client.create_comment(&post, "First!").await.unwrap();
+
+    let comments = reddit.comments();
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0].parent_id, post);
+    assert_eq!(comments[0].body, "First!");
+    assert_eq!(reddit.posts()[0].num_comments, 1);
+}
+
+#[tokio::test]
+async fn scripted_ratelimit_fails_one_submit() {
+    let reddit = FakeReddit::start();
+    let client = reddit.client();
+
+    reddit.fail_next(Endpoint::Submit, ScriptedError::RateLimit);
+    let msg = api_error(client.create_post("rust", "Too soon", "Body").await);
+    assert!(msg.contains("RATELIMIT"), "{}", msg);
+    assert!(reddit.posts().is_empty());
+
+    client.create_post("rust", "Later", "Body").await.unwrap();
+    assert_eq!(reddit.posts().len(), 1);
+}
+
+#[tokio::test]
+async fn scripted_user_required_on_submit() {
+    let reddit = FakeReddit::start();
+    let client = reddit.client();
+
+    reddit.fail_next(Endpoint::Submit, ScriptedError::UserRequired);
+    let msg = api_error(client.create_post("rust", "Title", "Body").await);
+    assert!(msg.contains("user authentication"), "{}", msg);
+}
+
+#[tokio::test]
+async fn scripted_429_on_listing() {
+    let reddit = FakeReddit::start();
+    reddit.add_post("rust", "Post", "");
+    let client = reddit.client();
+
+    reddit.fail_next(Endpoint::New, ScriptedError::TooManyRequests);
+    let msg = api_error(client.fetch_new_posts("rust", 10).await);
+    assert!(msg.contains("429"), "{}", msg);
+
+    let listing = client.fetch_new_posts("rust", 10).await.unwrap();
+    assert_eq!(listing.data.children.len(), 1);
+}
+
+#[tokio::test]
+async fn refresh_uses_fake_token_endpoint() {
+    let reddit = FakeReddit::start();
+    let mut client = reddit.client();
+    let before = client.access_token.clone();
+
+    let token = client.refresh_access_token().await.unwrap();
+    assert_ne!(Some(token.clone()), before);
+
+    // The new token is accepted
+    client.access_token = Some(token);
+    client.fetch_new_posts("rust", 1).await.unwrap();
+}